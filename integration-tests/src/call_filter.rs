@@ -33,3 +33,39 @@ fn calling_orml_xcm_extrinsic_should_be_filtered_by_call_filter() {
 		assert!(!basilisk_runtime::BaseFilter::contains(&call));
 	});
 }
+
+#[test]
+fn calling_lbp_trade_extrinsics_should_not_be_filtered_by_call_filter() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		let sell = basilisk_runtime::RuntimeCall::LBP(pallet_lbp::Call::sell {
+			asset_in: 0,
+			asset_out: 1,
+			amount: 1_000,
+			max_limit: 0,
+		});
+		let buy = basilisk_runtime::RuntimeCall::LBP(pallet_lbp::Call::buy {
+			asset_out: 1,
+			asset_in: 0,
+			amount: 1_000,
+			max_limit: u128::MAX,
+		});
+
+		assert!(basilisk_runtime::BaseFilter::contains(&sell));
+		assert!(basilisk_runtime::BaseFilter::contains(&buy));
+	});
+}
+
+#[test]
+fn calling_lbp_pool_management_extrinsics_should_not_be_filtered_by_call_filter() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		let call = basilisk_runtime::RuntimeCall::LBP(pallet_lbp::Call::remove_liquidity {
+			pool_id: AccountId::from(ALICE),
+		});
+
+		assert!(basilisk_runtime::BaseFilter::contains(&call));
+	});
+}
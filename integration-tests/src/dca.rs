@@ -0,0 +1,187 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{Currencies, NamedReserveId, RuntimeOrigin, Tokens, Treasury, DCA, XYK};
+use frame_support::{assert_ok, BoundedVec};
+use hydradx_traits::router::{PoolType, Trade};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pallet_dca::types::{Order, Schedule};
+use primitives::{AssetId, Balance};
+use sp_runtime::Permill;
+use xcm_emulator::TestExt;
+
+const PERIOD: u32 = 100;
+const AMOUNT_PER_TRADE: Balance = UNITS;
+const BUDGET: Balance = 10 * UNITS;
+
+fn create_xyk_pool_with_oracle_entry(asset_a: AssetId, asset_b: AssetId) {
+	go_to_next_block(true, true);
+	go_to_next_block(true, false);
+
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		asset_a,
+		200 * UNITS,
+		asset_b,
+		200 * UNITS,
+	));
+	assert_ok!(XYK::sell(
+		RuntimeOrigin::signed(ALICE.into()),
+		asset_a,
+		asset_b,
+		UNITS,
+		0,
+		false,
+	));
+
+	go_to_next_block(false, true);
+}
+
+fn run_blocks(n: u32) {
+	for _ in 0..n {
+		go_to_next_block(true, true);
+	}
+}
+
+fn sell_schedule(
+	owner: [u8; 32],
+	min_amount_out: Balance,
+	max_retries: Option<u8>,
+) -> Schedule<AccountId, AssetId, u32> {
+	let route: BoundedVec<_, _> = vec![Trade {
+		pool: PoolType::XYK,
+		asset_in: AUSD,
+		asset_out: BSX,
+	}]
+	.try_into()
+	.unwrap();
+
+	Schedule {
+		owner: owner.into(),
+		period: PERIOD,
+		total_amount: BUDGET,
+		max_retries,
+		stability_threshold: None,
+		slippage: Some(Permill::from_percent(5)),
+		order: Order::Sell {
+			asset_in: AUSD,
+			asset_out: BSX,
+			amount_in: AMOUNT_PER_TRADE,
+			min_amount_out,
+			route,
+		},
+	}
+}
+
+fn reserved_budget(who: [u8; 32]) -> Balance {
+	Currencies::reserved_balance_named(&NamedReserveId::get(), AUSD, &who.into())
+}
+
+#[test]
+fn schedule_should_reserve_budget_when_created() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_xyk_pool_with_oracle_entry(AUSD, BSX);
+
+		//Act
+		assert_ok!(DCA::schedule(
+			RuntimeOrigin::signed(BOB.into()),
+			sell_schedule(BOB, 0, None),
+			None
+		));
+
+		//Assert
+		assert_eq!(reserved_budget(BOB), BUDGET);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE - BUDGET
+		);
+		assert!(pallet_dca::Schedules::<basilisk_runtime::Runtime>::get(0).is_some());
+	});
+}
+
+#[test]
+fn schedule_should_execute_trade_through_router_and_take_fee_in_sold_asset() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_xyk_pool_with_oracle_entry(AUSD, BSX);
+
+		let treasury_ausd_balance = Tokens::free_balance(AUSD, &Treasury::account_id());
+		let bob_bsx_balance = Currencies::free_balance(BSX, &AccountId::from(BOB));
+
+		assert_ok!(DCA::schedule(
+			RuntimeOrigin::signed(BOB.into()),
+			sell_schedule(BOB, 0, None),
+			None
+		));
+
+		//Act
+		run_blocks(5);
+
+		//Assert
+		let fee = Tokens::free_balance(AUSD, &Treasury::account_id()) - treasury_ausd_balance;
+		assert!(fee > 0);
+		assert_eq!(reserved_budget(BOB), BUDGET - AMOUNT_PER_TRADE - fee);
+		assert!(Currencies::free_balance(BSX, &AccountId::from(BOB)) > bob_bsx_balance);
+		assert!(pallet_dca::Schedules::<basilisk_runtime::Runtime>::get(0).is_some());
+	});
+}
+
+#[test]
+fn terminate_should_unreserve_remaining_budget() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_xyk_pool_with_oracle_entry(AUSD, BSX);
+
+		assert_ok!(DCA::schedule(
+			RuntimeOrigin::signed(BOB.into()),
+			sell_schedule(BOB, 0, None),
+			None
+		));
+
+		//Act
+		assert_ok!(DCA::terminate(RuntimeOrigin::signed(BOB.into()), 0, None));
+
+		//Assert
+		assert_eq!(reserved_budget(BOB), 0);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE
+		);
+		assert!(pallet_dca::Schedules::<basilisk_runtime::Runtime>::get(0).is_none());
+	});
+}
+
+#[test]
+fn schedule_should_be_terminated_when_trade_fails_more_times_than_allowed() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_xyk_pool_with_oracle_entry(AUSD, BSX);
+
+		let bob_bsx_balance = Currencies::free_balance(BSX, &AccountId::from(BOB));
+
+		// trade can never satisfy the limit so every execution fails
+		assert_ok!(DCA::schedule(
+			RuntimeOrigin::signed(BOB.into()),
+			sell_schedule(BOB, 1_000 * UNITS, Some(1)),
+			None
+		));
+
+		//Act
+		run_blocks(50);
+
+		//Assert
+		assert!(pallet_dca::Schedules::<basilisk_runtime::Runtime>::get(0).is_none());
+		assert_eq!(reserved_budget(BOB), 0);
+		assert_eq!(Currencies::free_balance(BSX, &AccountId::from(BOB)), bob_bsx_balance);
+	});
+}
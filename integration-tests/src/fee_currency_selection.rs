@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{Currencies, FeeCurrencySelection, MultiTransactionPayment, RuntimeOrigin, Tokens, XYK};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchInfo,
+	sp_runtime::{
+		traits::SignedExtension,
+		transaction_validity::{InvalidTransaction, TransactionValidityError},
+	},
+	weights::Weight,
+};
+use orml_traits::currency::MultiCurrency;
+use pallet_transaction_multi_payment::Price;
+use sp_runtime::DispatchError;
+use xcm_emulator::TestExt;
+
+const HITCHHIKER: [u8; 32] = [42u8; 32];
+
+/// Sets up an account which pays fees in AUSD but doesn't hold enough of it and holds enough KSM.
+fn setup_account_with_insufficient_fee_currency() {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		AUSD,
+		100 * UNITS,
+		BSX,
+		100 * UNITS,
+	));
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		KSM,
+		100 * UNITS,
+		BSX,
+		100 * UNITS,
+	));
+	assert_ok!(MultiTransactionPayment::add_currency(
+		RuntimeOrigin::root(),
+		KSM,
+		Price::from(1),
+	));
+
+	assert_ok!(Currencies::transfer(
+		RuntimeOrigin::signed(ALICE.into()),
+		HITCHHIKER.into(),
+		AUSD,
+		2_000_000,
+	));
+	assert_ok!(Currencies::transfer(
+		RuntimeOrigin::signed(ALICE.into()),
+		HITCHHIKER.into(),
+		KSM,
+		50 * UNITS,
+	));
+
+	// fee currency prices are updated from the oracle on block initialization
+	go_to_next_block(true, true);
+	go_to_next_block(true, false);
+
+	assert_eq!(
+		MultiTransactionPayment::get_currency(AccountId::from(HITCHHIKER)),
+		Some(AUSD)
+	);
+}
+
+fn pre_dispatch_remark() -> Result<(), TransactionValidityError> {
+	let call = basilisk_runtime::RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let info = DispatchInfo {
+		weight: Weight::from_parts(106_957_000, 0),
+		..Default::default()
+	};
+
+	pallet_transaction_payment::ChargeTransactionPayment::<basilisk_runtime::Runtime>::from(0)
+		.pre_dispatch(&AccountId::from(HITCHHIKER), &call, &info, 10)
+		.map(|_| ())
+}
+
+#[test]
+fn fee_payment_should_fail_when_account_currency_is_insufficient_and_no_fallback_is_set() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		setup_account_with_insufficient_fee_currency();
+
+		//Act and assert
+		assert_eq!(
+			pre_dispatch_remark(),
+			Err(TransactionValidityError::from(InvalidTransaction::Payment))
+		);
+	});
+}
+
+#[test]
+fn fee_should_be_paid_in_fallback_currency_when_account_currency_is_insufficient() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		setup_account_with_insufficient_fee_currency();
+		assert_ok!(FeeCurrencySelection::set_fallback_currencies(
+			RuntimeOrigin::root(),
+			vec![AUSD, KSM],
+		));
+
+		//Act
+		assert_ok!(pre_dispatch_remark());
+
+		//Assert
+		assert!(Tokens::free_balance(KSM, &AccountId::from(HITCHHIKER)) < 50 * UNITS);
+		assert_eq!(Tokens::free_balance(AUSD, &AccountId::from(HITCHHIKER)), 2_000_000);
+		expect_basilisk_events(vec![pallet_fee_currency_selection::Event::FeePaidInFallbackCurrency {
+			who: HITCHHIKER.into(),
+			currency: KSM,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn fee_payment_in_fallback_currency_should_not_change_account_currency() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		setup_account_with_insufficient_fee_currency();
+		assert_ok!(FeeCurrencySelection::set_fallback_currencies(
+			RuntimeOrigin::root(),
+			vec![KSM],
+		));
+
+		//Act
+		assert_ok!(pre_dispatch_remark());
+
+		//Assert
+		assert_eq!(
+			MultiTransactionPayment::get_currency(AccountId::from(HITCHHIKER)),
+			Some(AUSD)
+		);
+	});
+}
+
+#[test]
+fn set_fallback_currencies_should_fail_when_currency_is_not_accepted() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			FeeCurrencySelection::set_fallback_currencies(RuntimeOrigin::root(), vec![MOVR]),
+			pallet_fee_currency_selection::Error::<basilisk_runtime::Runtime>::NotFeeCurrency
+		);
+	});
+}
+
+#[test]
+fn set_fallback_currencies_should_fail_when_origin_is_not_governance() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			FeeCurrencySelection::set_fallback_currencies(RuntimeOrigin::signed(ALICE.into()), vec![AUSD]),
+			DispatchError::BadOrigin
+		);
+	});
+}
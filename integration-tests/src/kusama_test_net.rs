@@ -636,6 +636,7 @@ pub fn initialize_basilisk_block(target_block: BlockNumber, target_slot: Slot) {
 	basilisk_runtime::MessageQueue::on_initialize(target_block);
 	basilisk_runtime::MultiTransactionPayment::on_initialize(target_block);
 	basilisk_runtime::EmaOracle::on_initialize(target_block);
+	basilisk_runtime::TradeStats::on_initialize(target_block);
 	// assert_ok!(basilisk_runtime::Timestamp::set(
 	// 	basilisk_runtime::RuntimeOrigin::none(),
 	// 	SLOT_DURATION * *target_slot
@@ -643,6 +644,9 @@ pub fn initialize_basilisk_block(target_block: BlockNumber, target_slot: Slot) {
 
 	// basilisk_runtime::AllPalletsWithSystem::on_initialize(target_block);
 	set_validation_data(target_block, target_slot);
+
+	// DCA uses the relay chain parent hash set by the validation data as a source of randomness
+	basilisk_runtime::DCA::on_initialize(target_block);
 }
 
 pub fn finalize_basilisk_block(target_block: BlockNumber) {
@@ -663,6 +667,7 @@ pub fn finalize_basilisk_block(target_block: BlockNumber) {
 	basilisk_runtime::MessageQueue::on_finalize(target_block);
 	basilisk_runtime::MultiTransactionPayment::on_finalize(target_block);
 	basilisk_runtime::EmaOracle::on_finalize(target_block);
+	basilisk_runtime::TradeStats::on_finalize(target_block);
 	basilisk_runtime::System::finalize();
 }
 
@@ -719,6 +724,17 @@ pub fn go_to_next_block(initialize: bool, finalize: bool) {
 	}
 }
 
+/// Whitelists the pair so its trades are tracked by the EMA oracle.
+pub fn whitelist_oracle_pair(source: hydradx_traits::Source, asset_a: AssetId, asset_b: AssetId) {
+	assert_ok!(basilisk_runtime::OracleWhitelist::whitelist_pair(
+		basilisk_runtime::RuntimeOrigin::root(),
+		source,
+		asset_a,
+		asset_b,
+		pallet_oracle_whitelist::PairPriority::Normal,
+	));
+}
+
 pub fn set_validation_data(next_block: u32, slot: Slot) {
 	use basilisk_runtime::RuntimeOrigin;
 	use frame_support::storage::storage_prefix;
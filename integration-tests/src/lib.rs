@@ -2,15 +2,23 @@
 // Disable the integration tests when this feature is enabled.
 #![cfg(not(feature = "runtime-benchmarks"))]
 mod call_filter;
+mod dca;
 mod exchange_asset;
+mod fee_currency_selection;
 mod fees;
 mod kusama_test_net;
+mod limit_orders;
 mod nft;
 mod nft_marketplace;
 mod non_native_fee;
 mod oracle;
+mod oracle_whitelist;
+mod otc;
 mod router;
 mod sessions;
+mod stableswap;
+mod trade_stats;
 mod transact_call_filter;
 mod vesting;
 mod xyk;
+mod xyk_liquidity_mining;
@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{Currencies, LimitOrders, LimitOrdersNamedReserveId, RuntimeOrigin, Tokens, XYK};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use primitives::Balance;
+use xcm_emulator::TestExt;
+
+const ORDER_AMOUNT: Balance = 10 * UNITS;
+
+fn create_ausd_bsx_pool() {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		AUSD,
+		200 * UNITS,
+		BSX,
+		200 * UNITS,
+	));
+}
+
+fn reserved_order_amount(who: [u8; 32]) -> Balance {
+	Currencies::reserved_balance_named(&LimitOrdersNamedReserveId::get(), AUSD, &who.into())
+}
+
+#[test]
+fn limit_order_should_be_filled_through_router_when_spot_price_reaches_limit_price() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_ausd_bsx_pool();
+
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(BOB.into()),
+			AUSD,
+			BSX,
+			ORDER_AMOUNT,
+			9 * UNITS,
+			None
+		));
+		assert_eq!(reserved_order_amount(BOB), ORDER_AMOUNT);
+
+		//Act
+		assert_ok!(LimitOrders::fill_order(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			0,
+			ORDER_AMOUNT
+		));
+
+		//Assert
+		assert!(pallet_limit_orders::Orders::<basilisk_runtime::Runtime>::get(0).is_none());
+		assert_eq!(reserved_order_amount(BOB), 0);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE - ORDER_AMOUNT
+		);
+		assert!(Currencies::free_balance(BSX, &AccountId::from(BOB)) >= BOB_INITIAL_BSX_BALANCE + 9 * UNITS);
+
+		let keeper_fee = <basilisk_runtime::Runtime as pallet_limit_orders::Config>::KeeperFee::get() * ORDER_AMOUNT;
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(CHARLIE)),
+			CHARLIE_INITIAL_AUSD_BALANCE + keeper_fee
+		);
+	});
+}
+
+#[test]
+fn limit_order_should_not_be_filled_when_spot_price_is_below_limit_price() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_ausd_bsx_pool();
+
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(BOB.into()),
+			AUSD,
+			BSX,
+			ORDER_AMOUNT,
+			11 * UNITS,
+			None
+		));
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::fill_order(RuntimeOrigin::signed(CHARLIE.into()), 0, ORDER_AMOUNT),
+			pallet_limit_orders::Error::<basilisk_runtime::Runtime>::LimitPriceNotReached
+		);
+	});
+}
+
+#[test]
+fn limit_order_should_be_filled_partially_when_spot_price_moves_above_limit_price() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_ausd_bsx_pool();
+
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(BOB.into()),
+			AUSD,
+			BSX,
+			ORDER_AMOUNT,
+			11 * UNITS,
+			None
+		));
+
+		// selling BSX to the pool makes AUSD more expensive
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(DAVE.into()),
+			BSX,
+			AUSD,
+			50 * UNITS,
+			0,
+			false,
+		));
+
+		//Act
+		assert_ok!(LimitOrders::fill_order(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			0,
+			ORDER_AMOUNT / 2
+		));
+
+		//Assert
+		let order = pallet_limit_orders::Orders::<basilisk_runtime::Runtime>::get(0).unwrap();
+		assert_eq!(order.amount_in, ORDER_AMOUNT / 2);
+		assert_eq!(order.amount_out, 11 * UNITS / 2);
+		assert_eq!(reserved_order_amount(BOB), ORDER_AMOUNT / 2);
+		assert!(Currencies::free_balance(BSX, &AccountId::from(BOB)) >= BOB_INITIAL_BSX_BALANCE + 11 * UNITS / 2);
+	});
+}
+
+#[test]
+fn limit_order_should_not_be_filled_when_only_spot_price_reaches_limit_price() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_ausd_bsx_pool();
+
+		// spot price of the pool is above the limit price but the price impact of the sell is not
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(BOB.into()),
+			AUSD,
+			BSX,
+			ORDER_AMOUNT,
+			97 * UNITS / 10,
+			None
+		));
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::fill_order(RuntimeOrigin::signed(CHARLIE.into()), 0, ORDER_AMOUNT),
+			pallet_limit_orders::Error::<basilisk_runtime::Runtime>::LimitPriceNotReached
+		);
+	});
+}
@@ -2,7 +2,10 @@
 
 use crate::kusama_test_net::*;
 
-use basilisk_runtime::{Balances, Currencies, MultiTransactionPayment, RuntimeOrigin, Tokens};
+use basilisk_runtime::{
+	Balances, Currencies, EmaOracle, MultiTransactionPayment, OracleWhitelist, Router, RuntimeOrigin, Tokens,
+	XYKOracleSourceIdentifier, XykPaymentAssetSupport, XYK,
+};
 
 use frame_support::{
 	assert_err, assert_ok,
@@ -13,9 +16,17 @@ use frame_support::{
 	},
 	weights::Weight,
 };
-use hydradx_traits::AMM;
+use hydradx_traits::{
+	fee::SwappablePaymentAssetTrader,
+	router::{AssetPair as RouterAssetPair, PoolType},
+	AggregatedPriceOracle,
+	OraclePeriod::LastBlock,
+	AMM,
+};
 use orml_traits::currency::MultiCurrency;
 use pallet_asset_registry::AssetType;
+use pallet_oracle_whitelist::PairPriority;
+use pallet_route_executor::Trade;
 use pallet_transaction_multi_payment::Price;
 use primitives::AssetId;
 use xcm_emulator::TestExt;
@@ -269,3 +280,231 @@ fn fee_currency_should_reset_to_default_when_account_spends_tokens() {
 		assert_eq!(MultiTransactionPayment::get_currency(AccountId::from(HITCHHIKER)), None);
 	});
 }
+
+const ROUTE_TOKEN: AssetId = 42;
+
+// Creates ROUTE_TOKEN/AUSD and AUSD/BSX pools, stores the ROUTE_TOKEN -> AUSD -> BSX route in the router
+// and populates the oracle entries of both pools. ROUTE_TOKEN is an accepted fee currency, so the hops of
+// its route are tracked by the oracle without being whitelisted.
+fn create_fee_payment_route() {
+	assert_ok!(basilisk_runtime::AssetRegistry::register(
+		RuntimeOrigin::root(),
+		b"ROUTE_TOKEN".to_vec(),
+		AssetType::Token,
+		1_000,
+		Some(ROUTE_TOKEN),
+		None,
+		None,
+		None,
+	));
+
+	assert_ok!(Balances::force_set_balance(
+		RuntimeOrigin::root(),
+		ALICE.into(),
+		10_000 * UNITS,
+	));
+	for asset in [AUSD, ROUTE_TOKEN] {
+		assert_ok!(Tokens::set_balance(
+			RuntimeOrigin::root(),
+			ALICE.into(),
+			asset,
+			10_000 * UNITS,
+			0,
+		));
+	}
+	assert_ok!(Tokens::set_balance(
+		RuntimeOrigin::root(),
+		DAVE.into(),
+		ROUTE_TOKEN,
+		1_000 * UNITS,
+		0,
+	));
+
+	assert_ok!(MultiTransactionPayment::add_currency(
+		RuntimeOrigin::root(),
+		ROUTE_TOKEN,
+		Price::from(1),
+	));
+
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		AUSD,
+		1_000 * UNITS,
+		BSX,
+		1_000 * UNITS,
+	));
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		ROUTE_TOKEN,
+		1_000 * UNITS,
+		AUSD,
+		1_000 * UNITS,
+	));
+
+	assert_ok!(Router::force_insert_route(
+		RuntimeOrigin::root(),
+		RouterAssetPair::new(ROUTE_TOKEN, BSX),
+		vec![
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: ROUTE_TOKEN,
+				asset_out: AUSD,
+			},
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: AUSD,
+				asset_out: BSX,
+			},
+		],
+	));
+	assert_ok!(OracleWhitelist::refresh_priority_pairs(RuntimeOrigin::signed(
+		ALICE.into()
+	)));
+
+	assert_ok!(XYK::sell(
+		RuntimeOrigin::signed(ALICE.into()),
+		ROUTE_TOKEN,
+		AUSD,
+		UNITS,
+		0,
+		false,
+	));
+	assert_ok!(XYK::sell(
+		RuntimeOrigin::signed(ALICE.into()),
+		AUSD,
+		BSX,
+		UNITS,
+		0,
+		false,
+	));
+
+	go_to_next_block(true, true);
+	go_to_next_block(true, false);
+}
+
+#[test]
+fn fee_payment_asset_should_be_swapped_through_stored_route_when_direct_pool_does_not_exist() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		assert!(!XykPaymentAssetSupport::is_trade_supported(ROUTE_TOKEN, BSX));
+
+		create_fee_payment_route();
+
+		assert!(!XYK::exists(AssetPair {
+			asset_in: ROUTE_TOKEN,
+			asset_out: BSX,
+		}));
+
+		let dave_route_token_balance = Tokens::free_balance(ROUTE_TOKEN, &AccountId::from(DAVE));
+		let charlie_bsx_balance = Balances::free_balance(AccountId::from(CHARLIE));
+
+		//Act
+		assert!(XykPaymentAssetSupport::is_trade_supported(ROUTE_TOKEN, BSX));
+		let amount_in = XykPaymentAssetSupport::calculate_in_given_out(ROUTE_TOKEN, BSX, UNITS).unwrap();
+
+		assert_ok!(XykPaymentAssetSupport::buy(
+			&AccountId::from(DAVE),
+			ROUTE_TOKEN,
+			BSX,
+			UNITS,
+			u128::MAX,
+			&AccountId::from(CHARLIE),
+		));
+
+		//Assert
+		assert_eq!(
+			Tokens::free_balance(ROUTE_TOKEN, &AccountId::from(DAVE)),
+			dave_route_token_balance - amount_in
+		);
+		assert_eq!(
+			Balances::free_balance(AccountId::from(CHARLIE)),
+			charlie_bsx_balance + UNITS
+		);
+	});
+}
+
+#[test]
+fn fee_payment_asset_swap_through_stored_route_should_fail_when_price_differs_from_oracle_price() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_fee_payment_route();
+
+		// move the spot price of the route away from its oracle price
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			ROUTE_TOKEN,
+			AUSD,
+			300 * UNITS,
+			0,
+			false,
+		));
+
+		//Act and assert
+		assert_err!(
+			XykPaymentAssetSupport::calculate_in_given_out(ROUTE_TOKEN, BSX, UNITS),
+			pallet_route_executor::Error::<basilisk_runtime::Runtime>::TradingLimitReached
+		);
+		assert_err!(
+			XykPaymentAssetSupport::buy(
+				&AccountId::from(DAVE),
+				ROUTE_TOKEN,
+				BSX,
+				UNITS,
+				u128::MAX,
+				&AccountId::from(DAVE),
+			),
+			pallet_route_executor::Error::<basilisk_runtime::Runtime>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn hops_of_stored_fee_payment_route_should_be_tracked_with_priority_without_whitelisting() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Act
+		create_fee_payment_route();
+
+		//Assert
+		assert_eq!(
+			OracleWhitelist::whitelisted_pairs((XYKOracleSourceIdentifier::get(), (AUSD, ROUTE_TOKEN))),
+			None
+		);
+		assert_eq!(
+			OracleWhitelist::pair_priority(XYKOracleSourceIdentifier::get(), ROUTE_TOKEN, AUSD),
+			Some(PairPriority::High)
+		);
+		assert!(EmaOracle::get_price(ROUTE_TOKEN, AUSD, LastBlock, XYKOracleSourceIdentifier::get()).is_ok());
+	});
+}
+
+#[test]
+fn hops_of_stored_fee_payment_route_should_not_be_tracked_with_priority_when_currency_is_removed() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		create_fee_payment_route();
+
+		assert_ok!(MultiTransactionPayment::remove_currency(
+			RuntimeOrigin::root(),
+			ROUTE_TOKEN,
+		));
+
+		//Act
+		assert_ok!(OracleWhitelist::refresh_priority_pairs(RuntimeOrigin::signed(
+			ALICE.into()
+		)));
+
+		//Assert
+		assert_eq!(
+			OracleWhitelist::pair_priority(XYKOracleSourceIdentifier::get(), ROUTE_TOKEN, AUSD),
+			None
+		);
+	});
+}
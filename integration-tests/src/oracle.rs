@@ -2,10 +2,15 @@
 
 use crate::kusama_test_net::*;
 
-use basilisk_runtime::{EmaOracle, RuntimeOrigin, XYKOracleSourceIdentifier, XYK};
+use basilisk_runtime::{oracle_api, EmaOracle, RuntimeOrigin, XYKOracleSourceIdentifier, XYK};
 use frame_support::assert_ok;
-use hydradx_traits::{AggregatedPriceOracle, OraclePeriod::*};
+use hydradx_traits::{
+	router::{PoolType, Trade},
+	AggregatedPriceOracle,
+	OraclePeriod::*,
+};
 use pallet_ema_oracle::OracleError;
+use primitives::AssetId;
 use xcm_emulator::TestExt;
 
 #[test]
@@ -20,6 +25,8 @@ fn xyk_trades_are_ingested_into_oracle() {
 		go_to_next_block(true, true);
 		go_to_next_block(true, false);
 
+		whitelist_oracle_pair(XYKOracleSourceIdentifier::get(), asset_a, asset_b);
+
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE.into()),
 			asset_a,
@@ -65,3 +72,97 @@ fn xyk_trades_are_ingested_into_oracle() {
 		);
 	});
 }
+
+fn trade_xyk_pool_and_finalize_block(asset_a: AssetId, asset_b: AssetId) {
+	go_to_next_block(true, true);
+	go_to_next_block(true, false);
+
+	whitelist_oracle_pair(XYKOracleSourceIdentifier::get(), asset_a, asset_b);
+
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		asset_a,
+		100 * UNITS,
+		asset_b,
+		200 * UNITS,
+	));
+	assert_ok!(XYK::sell(
+		RuntimeOrigin::signed(ALICE.into()),
+		asset_a,
+		asset_b,
+		5 * UNITS,
+		UNITS,
+		false,
+	));
+
+	go_to_next_block(false, true);
+}
+
+#[test]
+fn oracle_api_get_price_should_return_price_tracked_by_oracle() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		trade_xyk_pool_and_finalize_block(1, 2);
+
+		//Act
+		let price = oracle_api::get_price(1, 2, Hour, XYKOracleSourceIdentifier::get()).unwrap();
+
+		//Assert
+		assert_eq!(price.price.n, 105000000000000);
+		assert_eq!(price.price.d, 190504761904760);
+		assert_eq!(price.oracle_age, 0);
+		assert_eq!(
+			oracle_api::get_price(1, 2, TenMinutes, XYKOracleSourceIdentifier::get()),
+			None
+		);
+	});
+}
+
+#[test]
+fn oracle_api_get_route_price_should_return_price_of_route_trades() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		trade_xyk_pool_and_finalize_block(1, 2);
+		let route = vec![Trade {
+			pool: PoolType::XYK,
+			asset_in: 1,
+			asset_out: 2,
+		}];
+
+		//Act
+		let price = oracle_api::get_route_price(route, LastBlock).unwrap();
+
+		//Assert
+		assert_eq!(price.n, 105000000000000);
+		assert_eq!(price.d, 190504761904760);
+	});
+}
+
+#[test]
+fn oracle_api_oracle_entries_should_return_entries_of_all_supported_periods() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		trade_xyk_pool_and_finalize_block(1, 2);
+
+		//Act
+		let entries = oracle_api::oracle_entries();
+
+		//Assert
+		let xyk_entries: Vec<_> = entries
+			.iter()
+			.filter(|entry| {
+				entry.source == XYKOracleSourceIdentifier::get() && (entry.asset_a, entry.asset_b) == (1, 2)
+			})
+			.collect();
+		assert_eq!(xyk_entries.len(), 5);
+		for period in [LastBlock, Short, Hour, Day, Week] {
+			assert!(xyk_entries.iter().any(|entry| entry.period == period));
+		}
+	});
+}
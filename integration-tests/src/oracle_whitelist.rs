@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{
+	Balances, EmaOracle, OracleWhitelist, RuntimeOrigin, XYKLiquidityMining, XYKOracleSourceIdentifier, XYK,
+};
+use frame_support::{assert_noop, assert_ok, traits::Contains};
+use hydradx_traits::{AggregatedPriceOracle, OraclePeriod::*};
+use pallet_ema_oracle::OracleError;
+use pallet_oracle_whitelist::PairPriority;
+use pallet_xyk_liquidity_mining::PriceAdjustmentMode;
+use primitives::AssetId;
+use sp_runtime::{DispatchError, FixedU128, Perquintill};
+use xcm_emulator::TestExt;
+
+fn trade_xyk_pool_and_finalize_block(asset_a: AssetId, asset_b: AssetId) {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		asset_a,
+		100 * UNITS,
+		asset_b,
+		200 * UNITS,
+	));
+	assert_ok!(XYK::sell(
+		RuntimeOrigin::signed(ALICE.into()),
+		asset_a,
+		asset_b,
+		5 * UNITS,
+		UNITS,
+		false,
+	));
+
+	go_to_next_block(false, true);
+}
+
+#[test]
+fn trades_of_pair_should_not_be_ingested_into_oracle_when_pair_is_not_whitelisted() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		go_to_next_block(true, true);
+		go_to_next_block(true, false);
+
+		//Act
+		trade_xyk_pool_and_finalize_block(AUSD, MOVR);
+
+		//Assert
+		assert_eq!(
+			EmaOracle::get_price(AUSD, MOVR, LastBlock, XYKOracleSourceIdentifier::get()),
+			Err(OracleError::NotPresent)
+		);
+		assert_eq!(OracleWhitelist::dropped_entries(), 0);
+	});
+}
+
+#[test]
+fn trades_of_pair_should_be_ingested_into_oracle_when_pair_is_whitelisted() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		go_to_next_block(true, true);
+		go_to_next_block(true, false);
+
+		assert_ok!(OracleWhitelist::whitelist_pair(
+			RuntimeOrigin::root(),
+			XYKOracleSourceIdentifier::get(),
+			MOVR,
+			AUSD,
+			PairPriority::High,
+		));
+
+		//Act
+		trade_xyk_pool_and_finalize_block(AUSD, MOVR);
+
+		//Assert
+		assert!(EmaOracle::get_price(AUSD, MOVR, LastBlock, XYKOracleSourceIdentifier::get()).is_ok());
+	});
+}
+
+#[test]
+fn trades_of_fee_payment_pair_should_be_ingested_into_oracle_without_whitelisting() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		go_to_next_block(true, true);
+		go_to_next_block(true, false);
+
+		//Act
+		trade_xyk_pool_and_finalize_block(AUSD, BSX);
+
+		//Assert
+		assert!(OracleWhitelist::contains(&(
+			XYKOracleSourceIdentifier::get(),
+			BSX,
+			AUSD
+		)));
+		assert_eq!(
+			OracleWhitelist::pair_priority(XYKOracleSourceIdentifier::get(), BSX, AUSD),
+			Some(PairPriority::High)
+		);
+		assert!(EmaOracle::get_price(AUSD, BSX, LastBlock, XYKOracleSourceIdentifier::get()).is_ok());
+	});
+}
+
+#[test]
+fn pair_pricing_farm_in_oracle_mode_should_be_tracked_with_priority_without_whitelisting() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		go_to_next_block(true, true);
+		go_to_next_block(true, false);
+
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ALICE.into(),
+			100_000_000 * UNITS,
+		));
+		assert_ok!(XYKLiquidityMining::create_global_farm(
+			RuntimeOrigin::root(),
+			10_000_000 * UNITS,
+			1_000_000,
+			1,
+			KSM,
+			BSX,
+			ALICE.into(),
+			Perquintill::from_percent(1),
+			1_000,
+			FixedU128::from(1),
+		));
+		assert_eq!(
+			OracleWhitelist::pair_priority(XYKOracleSourceIdentifier::get(), KSM, BSX),
+			None
+		);
+
+		//Act
+		assert_ok!(XYKLiquidityMining::set_price_adjustment_mode(
+			RuntimeOrigin::signed(ALICE.into()),
+			1,
+			PriceAdjustmentMode::Oracle,
+		));
+		trade_xyk_pool_and_finalize_block(KSM, BSX);
+
+		//Assert
+		assert_eq!(
+			OracleWhitelist::pair_priority(XYKOracleSourceIdentifier::get(), BSX, KSM),
+			Some(PairPriority::High)
+		);
+		assert!(EmaOracle::get_price(KSM, BSX, LastBlock, XYKOracleSourceIdentifier::get()).is_ok());
+	});
+}
+
+#[test]
+fn whitelist_pair_should_fail_when_origin_is_not_governance() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			OracleWhitelist::whitelist_pair(
+				RuntimeOrigin::signed(ALICE.into()),
+				XYKOracleSourceIdentifier::get(),
+				AUSD,
+				MOVR,
+				PairPriority::Normal,
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
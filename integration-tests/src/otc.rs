@@ -0,0 +1,160 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{Currencies, ProxyType, RuntimeCall, RuntimeOrigin, Tokens, Treasury, OTC};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Contains, InstanceFilter},
+};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use primitives::Balance;
+use xcm_emulator::TestExt;
+
+const AMOUNT_IN: Balance = 100 * UNITS;
+const AMOUNT_OUT: Balance = 50 * UNITS;
+
+// BOB buys 100 BSX for 50 AUSD
+fn place_bob_order(partially_fillable: bool) {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(BOB.into()),
+		BSX,
+		AUSD,
+		AMOUNT_IN,
+		AMOUNT_OUT,
+		partially_fillable
+	));
+}
+
+#[test]
+fn place_order_should_reserve_amount_out() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Act
+		place_bob_order(false);
+
+		//Assert
+		assert!(pallet_otc::Orders::<basilisk_runtime::Runtime>::get(0).is_some());
+		assert_eq!(Currencies::reserved_balance(AUSD, &AccountId::from(BOB)), AMOUNT_OUT);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE - AMOUNT_OUT
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_swap_assets_between_maker_and_taker() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		place_bob_order(false);
+		let treasury_ausd_balance = Tokens::free_balance(AUSD, &Treasury::account_id());
+
+		//Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(CHARLIE.into()), 0));
+
+		//Assert
+		assert!(pallet_otc::Orders::<basilisk_runtime::Runtime>::get(0).is_none());
+		assert_eq!(Currencies::reserved_balance(AUSD, &AccountId::from(BOB)), 0);
+		assert_eq!(
+			Currencies::free_balance(BSX, &AccountId::from(BOB)),
+			BOB_INITIAL_BSX_BALANCE + AMOUNT_IN
+		);
+		assert_eq!(
+			Currencies::free_balance(BSX, &AccountId::from(CHARLIE)),
+			CHARLIE_INITIAL_BSX_BALANCE - AMOUNT_IN
+		);
+
+		// fee is paid by the taker from the received amount
+		let fee = Tokens::free_balance(AUSD, &Treasury::account_id()) - treasury_ausd_balance;
+		assert!(fee > 0);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(CHARLIE)),
+			CHARLIE_INITIAL_AUSD_BALANCE + AMOUNT_OUT - fee
+		);
+	});
+}
+
+#[test]
+fn partial_fill_order_should_keep_remaining_amount_reserved() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		place_bob_order(true);
+
+		//Act
+		assert_ok!(OTC::partial_fill_order(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			0,
+			AMOUNT_IN * 2 / 5
+		));
+
+		//Assert
+		let order = pallet_otc::Orders::<basilisk_runtime::Runtime>::get(0).unwrap();
+		assert_eq!(order.amount_in, AMOUNT_IN * 3 / 5);
+		assert_eq!(order.amount_out, AMOUNT_OUT * 3 / 5);
+		assert_eq!(
+			Currencies::reserved_balance(AUSD, &AccountId::from(BOB)),
+			AMOUNT_OUT * 3 / 5
+		);
+		assert_eq!(
+			Currencies::free_balance(BSX, &AccountId::from(BOB)),
+			BOB_INITIAL_BSX_BALANCE + AMOUNT_IN * 2 / 5
+		);
+	});
+}
+
+#[test]
+fn partial_fill_order_should_fail_when_order_is_not_partially_fillable() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		place_bob_order(false);
+
+		//Act and assert
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(CHARLIE.into()), 0, AMOUNT_IN * 2 / 5),
+			pallet_otc::Error::<basilisk_runtime::Runtime>::OrderNotPartiallyFillable
+		);
+	});
+}
+
+#[test]
+fn cancel_order_should_unreserve_amount_out() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		place_bob_order(true);
+
+		//Act
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(BOB.into()), 0));
+
+		//Assert
+		assert!(pallet_otc::Orders::<basilisk_runtime::Runtime>::get(0).is_none());
+		assert_eq!(Currencies::reserved_balance(AUSD, &AccountId::from(BOB)), 0);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE
+		);
+	});
+}
+
+#[test]
+fn otc_calls_should_be_allowed_for_exchange_proxy_and_xcm_transact() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		let call = RuntimeCall::OTC(pallet_otc::Call::fill_order { order_id: 0 });
+
+		//Act and assert
+		assert!(ProxyType::Exchange.filter(&call));
+		assert!(basilisk_runtime::xcm::SafeCallFilter::contains(&call));
+	});
+}
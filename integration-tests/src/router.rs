@@ -1365,6 +1365,419 @@ mod lbp_router_tests {
 	}
 }
 
+mod oracle_price_provider_tests {
+	use crate::kusama_test_net::*;
+
+	use basilisk_runtime::{EmaOracle, LBPOracleSourceIdentifier, Router, XYKOracleSourceIdentifier};
+	use xcm_emulator::TestExt;
+
+	use frame_support::assert_ok;
+	use hydradx_traits::{router::PoolType, AggregatedPriceOracle, OraclePeriod::*, PriceOracle};
+	use pallet_route_executor::Trade;
+	use sp_runtime::{FixedPointNumber, FixedU128};
+
+	use crate::router::*;
+
+	type OraclePriceProvider = <basilisk_runtime::Runtime as pallet_route_executor::Config>::OraclePriceProvider;
+
+	#[test]
+	fn lbp_trades_executed_by_router_should_be_ingested_into_oracle() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+			whitelist_oracle_pair(LBPOracleSourceIdentifier::get(), BSX, NEW_BOOTSTRAPPED_TOKEN);
+
+			let trades = vec![Trade {
+				pool: PoolType::LBP,
+				asset_in: BSX,
+				asset_out: NEW_BOOTSTRAPPED_TOKEN,
+			}];
+
+			start_lbp_campaign();
+
+			assert_ok!(Router::sell(
+				RuntimeOrigin::signed(TRADER.into()),
+				BSX,
+				NEW_BOOTSTRAPPED_TOKEN,
+				10 * UNITS,
+				0,
+				trades.clone()
+			));
+
+			//Act
+			go_to_next_block(false, true);
+
+			//Assert
+			let (lbp_price, _) =
+				EmaOracle::get_price(BSX, NEW_BOOTSTRAPPED_TOKEN, LastBlock, LBPOracleSourceIdentifier::get()).unwrap();
+
+			assert!(lbp_price.n > 0 && lbp_price.d > 0);
+			assert!(OraclePriceProvider::price(&trades, LastBlock).is_some());
+		});
+	}
+
+	#[test]
+	fn lbp_buys_executed_by_router_should_be_ingested_into_oracle() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+			whitelist_oracle_pair(LBPOracleSourceIdentifier::get(), BSX, NEW_BOOTSTRAPPED_TOKEN);
+
+			let trades = vec![Trade {
+				pool: PoolType::LBP,
+				asset_in: BSX,
+				asset_out: NEW_BOOTSTRAPPED_TOKEN,
+			}];
+
+			start_lbp_campaign();
+
+			assert_ok!(Router::buy(
+				RuntimeOrigin::signed(TRADER.into()),
+				BSX,
+				NEW_BOOTSTRAPPED_TOKEN,
+				UNITS,
+				u128::MAX,
+				trades.clone()
+			));
+
+			//Act
+			go_to_next_block(false, true);
+
+			//Assert
+			assert!(
+				EmaOracle::get_price(BSX, NEW_BOOTSTRAPPED_TOKEN, LastBlock, LBPOracleSourceIdentifier::get()).is_ok()
+			);
+			assert!(OraclePriceProvider::price(&trades, LastBlock).is_some());
+		});
+	}
+
+	#[test]
+	fn direct_lbp_trades_should_not_be_ingested_into_oracle() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+			whitelist_oracle_pair(LBPOracleSourceIdentifier::get(), BSX, NEW_BOOTSTRAPPED_TOKEN);
+
+			let trades = vec![Trade {
+				pool: PoolType::LBP,
+				asset_in: BSX,
+				asset_out: NEW_BOOTSTRAPPED_TOKEN,
+			}];
+
+			start_lbp_campaign();
+
+			assert_ok!(basilisk_runtime::LBP::sell(
+				RuntimeOrigin::signed(TRADER.into()),
+				BSX,
+				NEW_BOOTSTRAPPED_TOKEN,
+				10 * UNITS,
+				0
+			));
+
+			//Act
+			go_to_next_block(false, true);
+
+			//Assert
+			//NOTE: the oracle is fed by the router only, pallet_lbp has no trade hook.
+			assert!(
+				EmaOracle::get_price(BSX, NEW_BOOTSTRAPPED_TOKEN, LastBlock, LBPOracleSourceIdentifier::get()).is_err()
+			);
+			assert_eq!(OraclePriceProvider::price(&trades, LastBlock), None);
+		});
+	}
+
+	#[test]
+	fn price_should_compose_xyk_and_lbp_hops() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_xyk_pool(AUSD, BSX);
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+			whitelist_oracle_pair(LBPOracleSourceIdentifier::get(), BSX, NEW_BOOTSTRAPPED_TOKEN);
+
+			let trades = vec![
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: AUSD,
+					asset_out: BSX,
+				},
+				Trade {
+					pool: PoolType::LBP,
+					asset_in: BSX,
+					asset_out: NEW_BOOTSTRAPPED_TOKEN,
+				},
+			];
+
+			start_lbp_campaign();
+
+			assert_ok!(Router::sell(
+				RuntimeOrigin::signed(TRADER.into()),
+				AUSD,
+				NEW_BOOTSTRAPPED_TOKEN,
+				10 * UNITS,
+				0,
+				trades.clone()
+			));
+
+			go_to_next_block(false, true);
+
+			//Act
+			let price = OraclePriceProvider::price(&trades, LastBlock).unwrap();
+
+			//Assert
+			let (xyk_price, _) = EmaOracle::get_price(AUSD, BSX, LastBlock, XYKOracleSourceIdentifier::get()).unwrap();
+			let (lbp_price, _) =
+				EmaOracle::get_price(BSX, NEW_BOOTSTRAPPED_TOKEN, LastBlock, LBPOracleSourceIdentifier::get()).unwrap();
+
+			let expected = FixedU128::checked_from_rational(xyk_price.n, xyk_price.d)
+				.unwrap()
+				.saturating_mul(FixedU128::checked_from_rational(lbp_price.n, lbp_price.d).unwrap());
+			let actual = FixedU128::checked_from_rational(price.n, price.d).unwrap();
+
+			let tolerance = FixedU128::from_rational(1, 1_000_000_000);
+			assert!(
+				actual.max(expected) - actual.min(expected) <= tolerance,
+				"price {:?} is not as expected {:?}",
+				actual,
+				expected
+			);
+		});
+	}
+
+	#[test]
+	fn price_should_be_none_when_lbp_hop_has_no_oracle_entry() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_xyk_pool(AUSD, BSX);
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+
+			let trades = vec![
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: AUSD,
+					asset_out: BSX,
+				},
+				Trade {
+					pool: PoolType::LBP,
+					asset_in: BSX,
+					asset_out: NEW_BOOTSTRAPPED_TOKEN,
+				},
+			];
+
+			start_lbp_campaign();
+
+			//Act and assert
+			assert_eq!(OraclePriceProvider::price(&trades, LastBlock), None);
+		});
+	}
+}
+
+mod router_api_tests {
+	use crate::kusama_test_net::*;
+
+	use basilisk_runtime::router_api;
+	use xcm_emulator::TestExt;
+
+	use hydradx_traits::router::PoolType;
+	use pallet_route_executor::Trade;
+	use sp_runtime::FixedU128;
+
+	use crate::router::*;
+
+	fn different_pools_route() -> Vec<Trade<AssetId>> {
+		vec![
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: AUSD,
+				asset_out: BSX,
+			},
+			Trade {
+				pool: PoolType::LBP,
+				asset_in: BSX,
+				asset_out: NEW_BOOTSTRAPPED_TOKEN,
+			},
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: NEW_BOOTSTRAPPED_TOKEN,
+				asset_out: KSM,
+			},
+		]
+	}
+
+	#[test]
+	fn quote_sell_should_return_amount_out_of_router_sell() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_xyk_pool(AUSD, BSX);
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+			create_xyk_pool(NEW_BOOTSTRAPPED_TOKEN, KSM);
+
+			start_lbp_campaign();
+
+			//Act
+			let quote = router_api::quote_sell(different_pools_route(), 10 * UNITS).unwrap();
+
+			//Assert
+			assert_eq!(quote.route, different_pools_route());
+			assert_eq!(quote.amount_in, 10 * UNITS);
+			assert_eq!(quote.amount_out, 1208552547698);
+			assert_eq!(quote.trades.len(), 3);
+			assert_eq!(quote.trades[0].amount_in, 10 * UNITS);
+			assert_eq!(quote.trades[0].amount_out, quote.trades[1].amount_in);
+			assert_eq!(quote.trades[1].amount_out, quote.trades[2].amount_in);
+			assert_eq!(quote.trades[2].amount_out, 1208552547698);
+			assert!(quote.price_impact > FixedU128::from(0));
+		});
+	}
+
+	#[test]
+	fn quote_buy_should_return_amount_in_of_router_buy() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_xyk_pool(AUSD, BSX);
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+			create_xyk_pool(NEW_BOOTSTRAPPED_TOKEN, KSM);
+
+			start_lbp_campaign();
+
+			//Act
+			let quote = router_api::quote_buy(different_pools_route(), UNITS).unwrap();
+
+			//Assert
+			assert_eq!(quote.amount_in, 8049719643050);
+			assert_eq!(quote.amount_out, UNITS);
+			assert_eq!(quote.trades[0].amount_in, 8049719643050);
+			assert_eq!(quote.trades[0].amount_out, quote.trades[1].amount_in);
+			assert_eq!(quote.trades[1].amount_out, quote.trades[2].amount_in);
+			assert_eq!(quote.trades[2].amount_out, UNITS);
+		});
+	}
+
+	#[test]
+	fn quote_sell_should_return_none_when_route_contains_non_existing_pool() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_xyk_pool(AUSD, BSX);
+
+			//Act and assert
+			assert_eq!(router_api::quote_sell(different_pools_route(), 10 * UNITS), None);
+		});
+	}
+
+	#[test]
+	fn best_sell_route_should_find_route_across_xyk_and_lbp_pools() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_xyk_pool(AUSD, BSX);
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+			create_xyk_pool(NEW_BOOTSTRAPPED_TOKEN, KSM);
+
+			start_lbp_campaign();
+
+			//Act
+			let quote = router_api::best_sell_route(AUSD, KSM, 10 * UNITS, 3).unwrap();
+
+			//Assert
+			assert_eq!(quote.route, different_pools_route());
+			assert_eq!(quote.amount_out, 1208552547698);
+		});
+	}
+
+	#[test]
+	fn best_buy_route_should_find_route_across_xyk_and_lbp_pools() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_xyk_pool(AUSD, BSX);
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+			create_xyk_pool(NEW_BOOTSTRAPPED_TOKEN, KSM);
+
+			start_lbp_campaign();
+
+			//Act
+			let quote = router_api::best_buy_route(AUSD, KSM, UNITS, 3).unwrap();
+
+			//Assert
+			assert_eq!(quote.route, different_pools_route());
+			assert_eq!(quote.amount_in, 8049719643050);
+		});
+	}
+
+	#[test]
+	fn best_sell_route_should_return_none_when_route_needs_more_hops_than_allowed() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_xyk_pool(AUSD, BSX);
+			create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+			create_xyk_pool(NEW_BOOTSTRAPPED_TOKEN, KSM);
+
+			start_lbp_campaign();
+
+			//Act and assert
+			assert_eq!(router_api::best_sell_route(AUSD, KSM, 10 * UNITS, 2), None);
+		});
+	}
+
+	#[test]
+	fn best_sell_route_should_return_route_with_biggest_amount_out() {
+		TestNet::reset();
+
+		Basilisk::execute_with(|| {
+			//Arrange
+			create_xyk_pool(AUSD, BSX);
+			create_xyk_pool(BSX, KSM);
+			create_xyk_pool(AUSD, KSM);
+
+			let direct_route = vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: AUSD,
+				asset_out: KSM,
+			}];
+			let indirect_route = vec![
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: AUSD,
+					asset_out: BSX,
+				},
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: BSX,
+					asset_out: KSM,
+				},
+			];
+
+			let direct_amount_out = router_api::quote_sell(direct_route, 10 * UNITS).unwrap().amount_out;
+			let indirect_amount_out = router_api::quote_sell(indirect_route, 10 * UNITS).unwrap().amount_out;
+
+			//Act
+			let quote = router_api::best_sell_route(AUSD, KSM, 10 * UNITS, 3).unwrap();
+
+			//Assert
+			assert_eq!(quote.amount_out, direct_amount_out.max(indirect_amount_out));
+		});
+	}
+}
+
 fn create_xyk_pool(asset_a: u32, asset_b: u32) {
 	assert_ok!(XYK::create_pool(
 		RuntimeOrigin::signed(ALICE.into()),
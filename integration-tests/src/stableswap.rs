@@ -0,0 +1,236 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{
+	AssetRegistry, Currencies, EmaOracle, Router, RuntimeOrigin, Stableswap, StableswapOracleSourceIdentifier,
+};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::{
+	registry::Inspect,
+	router::{PoolType, Trade},
+	AggregatedPriceOracle, AssetKind, OraclePeriod, PriceOracle,
+};
+use orml_traits::MultiCurrency;
+use pallet_asset_registry::traits::CreateRegistry;
+use pallet_stableswap::types::AssetAmount;
+use primitives::{AssetId, Balance};
+use sp_runtime::Permill;
+use xcm_emulator::TestExt;
+
+const POOL_LIQUIDITY: Balance = 200 * UNITS;
+
+fn create_ausd_ksm_pool() -> AssetId {
+	for asset in [AUSD, KSM] {
+		assert_ok!(AssetRegistry::set_metadata(
+			RuntimeOrigin::root(),
+			asset,
+			b"TKN".to_vec(),
+			12
+		));
+	}
+
+	let pool_id = <AssetRegistry as CreateRegistry<AssetId, Balance>>::create_asset(
+		b"AUSD-KSM",
+		AssetKind::StableSwap,
+		UNITS / 1_000,
+	)
+	.unwrap();
+
+	assert_ok!(Stableswap::create_pool(
+		RuntimeOrigin::root(),
+		pool_id,
+		vec![AUSD, KSM],
+		100,
+		Permill::from_rational(4u32, 10_000u32),
+	));
+	assert_ok!(Stableswap::add_liquidity(
+		RuntimeOrigin::signed(ALICE.into()),
+		pool_id,
+		vec![
+			AssetAmount::new(AUSD, POOL_LIQUIDITY),
+			AssetAmount::new(KSM, POOL_LIQUIDITY)
+		],
+	));
+
+	pool_id
+}
+
+#[test]
+fn share_asset_of_stableswap_pool_should_be_registered_as_stableswap_asset() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Act
+		let pool_id = create_ausd_ksm_pool();
+
+		//Assert
+		assert_eq!(AssetRegistry::asset_type(pool_id), Some(AssetKind::StableSwap));
+		assert!(pallet_stableswap::Pools::<basilisk_runtime::Runtime>::get(pool_id).is_some());
+		assert!(Currencies::free_balance(pool_id, &AccountId::from(ALICE)) > 0);
+	});
+}
+
+#[test]
+fn stableswap_pool_should_be_created_with_share_asset_registered_by_register_call() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		for asset in [AUSD, KSM] {
+			assert_ok!(AssetRegistry::set_metadata(
+				RuntimeOrigin::root(),
+				asset,
+				b"TKN".to_vec(),
+				12
+			));
+		}
+
+		assert_ok!(AssetRegistry::register(
+			RuntimeOrigin::root(),
+			b"AUSD-KSM".to_vec(),
+			pallet_asset_registry::AssetType::StableSwap,
+			UNITS / 1_000,
+			None,
+			None,
+			None,
+			None,
+		));
+		let pool_id = AssetRegistry::asset_ids(AssetRegistry::to_bounded_name(b"AUSD-KSM".to_vec()).unwrap()).unwrap();
+
+		//Act
+		assert_ok!(Stableswap::create_pool(
+			RuntimeOrigin::root(),
+			pool_id,
+			vec![AUSD, KSM],
+			100,
+			Permill::from_rational(4u32, 10_000u32),
+		));
+
+		//Assert
+		assert_eq!(AssetRegistry::asset_type(pool_id), Some(AssetKind::StableSwap));
+		assert!(pallet_stableswap::Pools::<basilisk_runtime::Runtime>::get(pool_id).is_some());
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_share_asset_is_not_registered() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		for asset in [AUSD, KSM] {
+			assert_ok!(AssetRegistry::set_metadata(
+				RuntimeOrigin::root(),
+				asset,
+				b"TKN".to_vec(),
+				12
+			));
+		}
+
+		let share_asset: AssetId = 1_000_000;
+		assert!(!AssetRegistry::exists(share_asset));
+
+		//Act and assert
+		assert_noop!(
+			Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				share_asset,
+				vec![AUSD, KSM],
+				100,
+				Permill::from_rational(4u32, 10_000u32),
+			),
+			pallet_stableswap::Error::<basilisk_runtime::Runtime>::ShareAssetNotRegistered
+		);
+		assert!(pallet_stableswap::Pools::<basilisk_runtime::Runtime>::get(share_asset).is_none());
+	});
+}
+
+#[test]
+fn router_sell_through_stableswap_pool_should_have_low_slippage() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		let pool_id = create_ausd_ksm_pool();
+		let amount_in = 10 * UNITS;
+
+		//Act
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(BOB.into()),
+			AUSD,
+			KSM,
+			amount_in,
+			0,
+			vec![Trade {
+				pool: PoolType::Stableswap(pool_id),
+				asset_in: AUSD,
+				asset_out: KSM,
+			}]
+		));
+
+		//Assert
+		let received = Currencies::free_balance(KSM, &AccountId::from(BOB));
+		assert!(received > amount_in * 99 / 100);
+		assert!(received < amount_in);
+		assert_eq!(
+			Currencies::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE - amount_in
+		);
+	});
+}
+
+#[test]
+fn stableswap_trades_should_be_ingested_into_oracle() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		go_to_next_block(true, true);
+		go_to_next_block(true, false);
+
+		let pool_id = create_ausd_ksm_pool();
+		for asset in [AUSD, KSM] {
+			whitelist_oracle_pair(StableswapOracleSourceIdentifier::get(), asset, pool_id);
+		}
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(BOB.into()),
+			AUSD,
+			KSM,
+			10 * UNITS,
+			0,
+			vec![Trade {
+				pool: PoolType::Stableswap(pool_id),
+				asset_in: AUSD,
+				asset_out: KSM,
+			}]
+		));
+
+		//Act
+		go_to_next_block(false, true);
+
+		//Assert
+		for asset in [AUSD, KSM] {
+			assert!(EmaOracle::get_price(
+				asset,
+				pool_id,
+				OraclePeriod::LastBlock,
+				StableswapOracleSourceIdentifier::get()
+			)
+			.is_ok());
+		}
+
+		let route = vec![Trade {
+			pool: PoolType::Stableswap(pool_id),
+			asset_in: AUSD,
+			asset_out: KSM,
+		}];
+		assert!(
+			<basilisk_runtime::Runtime as pallet_route_executor::Config>::OraclePriceProvider::price(
+				&route,
+				OraclePeriod::LastBlock
+			)
+			.is_some()
+		);
+	});
+}
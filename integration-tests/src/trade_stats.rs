@@ -0,0 +1,118 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{RuntimeOrigin, TradeStats, XYK};
+use frame_support::assert_ok;
+use primitives::constants::time::HOURS;
+use xcm_emulator::TestExt;
+
+fn create_ausd_bsx_pool() {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		AUSD,
+		200 * UNITS,
+		BSX,
+		200 * UNITS,
+	));
+}
+
+fn sell_ausd_and_go_to_next_block() {
+	assert_ok!(XYK::sell(
+		RuntimeOrigin::signed(BOB.into()),
+		AUSD,
+		BSX,
+		10 * UNITS,
+		0,
+		false,
+	));
+
+	go_to_next_block(false, true);
+	go_to_next_block(true, false);
+}
+
+#[test]
+fn xyk_swaps_should_be_recorded_in_trade_stats_when_executed() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		go_to_next_block(true, true);
+		go_to_next_block(true, false);
+
+		create_ausd_bsx_pool();
+
+		//Act
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(BOB.into()),
+			AUSD,
+			BSX,
+			10 * UNITS,
+			0,
+			false,
+		));
+		assert_ok!(XYK::buy(
+			RuntimeOrigin::signed(BOB.into()),
+			AUSD,
+			BSX,
+			5 * UNITS,
+			u128::MAX,
+			false,
+		));
+
+		//Assert
+		let stats = TradeStats::pair_stats(AUSD, BSX, HOURS);
+		assert_eq!(stats.swaps, 2);
+		assert_eq!(stats.volume_a, 15 * UNITS);
+		assert!(stats.volume_b > 0);
+		assert!(stats.fee_a + stats.fee_b > 0);
+
+		let inverted = TradeStats::pair_stats(BSX, AUSD, HOURS);
+		assert_eq!(inverted.volume_a, stats.volume_b);
+		assert_eq!(inverted.fee_a, stats.fee_b);
+	});
+}
+
+#[test]
+fn trade_stats_should_accumulate_swaps_of_multiple_blocks() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		go_to_next_block(true, true);
+		go_to_next_block(true, false);
+		create_ausd_bsx_pool();
+
+		sell_ausd_and_go_to_next_block();
+
+		//Act
+		sell_ausd_and_go_to_next_block();
+
+		//Assert
+		let stats = TradeStats::pair_stats(AUSD, BSX, HOURS);
+		assert_eq!(stats.swaps, 2);
+		assert_eq!(stats.volume_a, 20 * UNITS);
+	});
+}
+
+#[test]
+fn trade_stats_should_record_every_swap_of_the_block() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		go_to_next_block(true, true);
+		go_to_next_block(true, false);
+		create_ausd_bsx_pool();
+
+		//Act
+		for _ in 0..100 {
+			assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB.into()), AUSD, BSX, UNITS, 0, false,));
+		}
+
+		//Assert
+		let stats = TradeStats::pair_stats(AUSD, BSX, HOURS);
+		assert_eq!(stats.swaps, 100);
+		assert_eq!(stats.volume_a, 100 * UNITS);
+	});
+}
@@ -0,0 +1,403 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{
+	Balances, CompoundingPeriod, Currencies, Runtime, RuntimeOrigin, System, Tokens, XYKLiquidityMining,
+	XYKOracleSourceIdentifier, XYKWarehouseLM, XYK,
+};
+use frame_support::{assert_ok, traits::Get};
+use hydradx_traits::{
+	liquidity_mining::{GlobalFarmId, YieldFarmId},
+	AMM,
+};
+use orml_traits::MultiCurrency;
+use pallet_xyk::types::AssetPair;
+use pallet_xyk_liquidity_mining::IncreaseDepositShares;
+use primitives::{AccountId, Balance, CollectionId as DepositId};
+use sp_runtime::{FixedU128, Perquintill};
+use xcm_emulator::TestExt;
+
+const GLOBAL_FARM_ID: GlobalFarmId = 1;
+const YIELD_FARM_ID: YieldFarmId = 2;
+const BOB_DEPOSIT_ID: DepositId = 1;
+const CHARLIE_DEPOSIT_ID: DepositId = 2;
+
+const BSX_AUSD: AssetPair = AssetPair {
+	asset_in: BSX,
+	asset_out: AUSD,
+};
+
+fn pool_id() -> AccountId {
+	XYK::get_pair_id(BSX_AUSD)
+}
+
+fn share_token() -> primitives::AssetId {
+	XYK::get_share_token(BSX_AUSD)
+}
+
+fn go_to_next_period() {
+	go_to_next_block(false, true);
+	go_to_next_block(true, false);
+}
+
+fn deposit_rewards(deposit_id: DepositId) -> Balance {
+	XYKLiquidityMining::deposit_valuation(deposit_id)
+		.unwrap()
+		.rewards
+		.iter()
+		.map(|(_, _, _, claimed)| claimed)
+		.sum()
+}
+
+fn add_liquidity_and_deposit(who: [u8; 32], amount: Balance) {
+	let share_token = share_token();
+	let shares_before = Currencies::free_balance(share_token, &who.into());
+
+	assert_ok!(XYK::add_liquidity(
+		RuntimeOrigin::signed(who.into()),
+		BSX,
+		AUSD,
+		amount,
+		amount * 2,
+	));
+	let shares = Currencies::free_balance(share_token, &who.into()) - shares_before;
+
+	assert_ok!(XYKLiquidityMining::deposit_shares(
+		RuntimeOrigin::signed(who.into()),
+		GLOBAL_FARM_ID,
+		YIELD_FARM_ID,
+		BSX_AUSD,
+		shares,
+	));
+}
+
+/// Creates BSX/AUSD pool tracked by the oracle and farm paying BSX rewards for it. BOB and
+/// CHARLIE have deposits with the same amount of shares.
+fn init_farm() {
+	go_to_next_block(true, true);
+	go_to_next_block(true, false);
+
+	whitelist_oracle_pair(XYKOracleSourceIdentifier::get(), BSX, AUSD);
+
+	assert_ok!(Balances::force_set_balance(
+		RuntimeOrigin::root(),
+		ALICE.into(),
+		100_000_000 * UNITS,
+	));
+	assert_ok!(Tokens::set_balance(
+		RuntimeOrigin::root(),
+		ALICE.into(),
+		AUSD,
+		10_000_000 * UNITS,
+		0,
+	));
+
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		BSX,
+		1_000_000 * UNITS,
+		AUSD,
+		1_000_000 * UNITS,
+	));
+
+	assert_ok!(XYKLiquidityMining::create_global_farm(
+		RuntimeOrigin::root(),
+		10_000_000 * UNITS,
+		1_000_000,
+		1,
+		BSX,
+		BSX,
+		ALICE.into(),
+		Perquintill::from_percent(1),
+		1_000,
+		FixedU128::from(1),
+	));
+	assert_ok!(XYKLiquidityMining::create_yield_farm(
+		RuntimeOrigin::signed(ALICE.into()),
+		GLOBAL_FARM_ID,
+		BSX_AUSD,
+		FixedU128::from(1),
+		None,
+	));
+
+	add_liquidity_and_deposit(BOB, 100 * UNITS);
+	add_liquidity_and_deposit(CHARLIE, 100 * UNITS);
+
+	go_to_next_period();
+}
+
+fn assert_yield_farm_totals() {
+	let yield_farm = warehouse_liquidity_mining::YieldFarm::<Runtime, warehouse_liquidity_mining::Instance1>::get((
+		pool_id(),
+		GLOBAL_FARM_ID,
+		YIELD_FARM_ID,
+	))
+	.unwrap();
+
+	let (total_shares, total_valued_shares, entries_count) = warehouse_liquidity_mining::Deposit::<
+		Runtime,
+		warehouse_liquidity_mining::Instance1,
+	>::iter_values()
+	.fold((0, 0, 0), |(shares, valued_shares, count), deposit| {
+		let entry = deposit
+			.yield_farm_entries
+			.iter()
+			.find(|entry| entry.yield_farm_id == YIELD_FARM_ID)
+			.unwrap();
+
+		(shares + deposit.shares, valued_shares + entry.valued_shares, count + 1)
+	});
+
+	assert_eq!(yield_farm.total_shares, total_shares);
+	assert_eq!(yield_farm.total_valued_shares, total_valued_shares);
+	assert_eq!(yield_farm.entries_count, entries_count);
+}
+
+#[test]
+fn increase_deposit_shares_should_preserve_earned_rewards() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		init_farm();
+		go_to_next_period();
+
+		//NOTE: CHARLIE's deposit syncs the yield farm in the current period.
+		add_liquidity_and_deposit(CHARLIE, 10 * UNITS);
+		let rewards_before = deposit_rewards(BOB_DEPOSIT_ID);
+		assert!(rewards_before > 0);
+
+		//Act
+		assert_ok!(XYKWarehouseLM::increase_deposit_shares(
+			BOB_DEPOSIT_ID,
+			pool_id(),
+			100 * UNITS,
+			|asset, amm_pool_id, shares| Ok(
+				Currencies::free_balance(asset, &amm_pool_id) * shares / Currencies::total_issuance(share_token())
+			),
+		));
+
+		//Assert
+		let rewards_after = deposit_rewards(BOB_DEPOSIT_ID);
+		assert!(rewards_after <= rewards_before);
+		assert!(rewards_before - rewards_after <= 1_000);
+
+		assert_yield_farm_totals();
+
+		//Rewards of the next period are calculated with the increased shares.
+		let charlie_rewards = deposit_rewards(CHARLIE_DEPOSIT_ID);
+		go_to_next_period();
+		assert!(
+			deposit_rewards(BOB_DEPOSIT_ID) - rewards_after > deposit_rewards(CHARLIE_DEPOSIT_ID) - charlie_rewards
+		);
+	});
+}
+
+#[test]
+fn compound_rewards_should_add_shares_to_deposit() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		init_farm();
+
+		assert_ok!(XYKLiquidityMining::enable_auto_compounding(
+			RuntimeOrigin::signed(BOB.into()),
+			BOB_DEPOSIT_ID,
+			BSX_AUSD,
+		));
+		go_to_next_period();
+
+		let shares_before =
+			warehouse_liquidity_mining::Deposit::<Runtime, warehouse_liquidity_mining::Instance1>::get(BOB_DEPOSIT_ID)
+				.unwrap()
+				.shares;
+
+		//NOTE: compounding is allowed once per `CompoundingPeriod` of parachain blocks.
+		System::set_block_number(System::block_number() + CompoundingPeriod::get());
+
+		//Act
+		assert_ok!(XYKLiquidityMining::compound_rewards(
+			RuntimeOrigin::signed(DAVE.into()),
+			BOB_DEPOSIT_ID,
+		));
+
+		//Assert
+		let deposit =
+			warehouse_liquidity_mining::Deposit::<Runtime, warehouse_liquidity_mining::Instance1>::get(BOB_DEPOSIT_ID)
+				.unwrap();
+		assert!(deposit.shares > shares_before);
+
+		assert_yield_farm_totals();
+	});
+}
+
+#[test]
+fn split_deposit_should_preserve_earned_rewards() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		init_farm();
+		go_to_next_period();
+
+		let rewards_before = deposit_rewards(BOB_DEPOSIT_ID);
+		assert!(rewards_before > 0);
+
+		//Act
+		assert_ok!(XYKLiquidityMining::split_deposit(
+			RuntimeOrigin::signed(BOB.into()),
+			BOB_DEPOSIT_ID,
+			30 * UNITS,
+		));
+
+		//Assert
+		let new_deposit_id = CHARLIE_DEPOSIT_ID + 1;
+		let rewards_after = deposit_rewards(BOB_DEPOSIT_ID) + deposit_rewards(new_deposit_id);
+		assert!(rewards_after <= rewards_before);
+		assert!(rewards_before - rewards_after <= 1_000);
+
+		assert_yield_farm_totals();
+
+		//BOB's deposits earn the same as CHARLIE's deposit with the same shares.
+		let charlie_rewards = deposit_rewards(CHARLIE_DEPOSIT_ID);
+		go_to_next_period();
+		let bob_earned = deposit_rewards(BOB_DEPOSIT_ID) + deposit_rewards(new_deposit_id) - rewards_after;
+		let charlie_earned = deposit_rewards(CHARLIE_DEPOSIT_ID) - charlie_rewards;
+		assert!(bob_earned <= charlie_earned);
+		assert!(charlie_earned - bob_earned <= 1_000);
+	});
+}
+
+#[test]
+fn merge_deposits_should_preserve_earned_rewards() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		init_farm();
+		go_to_next_period();
+
+		assert_ok!(XYKLiquidityMining::split_deposit(
+			RuntimeOrigin::signed(BOB.into()),
+			BOB_DEPOSIT_ID,
+			30 * UNITS,
+		));
+		let new_deposit_id = CHARLIE_DEPOSIT_ID + 1;
+
+		//NOTE: claim from one of the deposits so the merged entries have different state.
+		go_to_next_period();
+		assert_ok!(XYKLiquidityMining::claim_rewards(
+			RuntimeOrigin::signed(BOB.into()),
+			new_deposit_id,
+			YIELD_FARM_ID,
+		));
+		go_to_next_period();
+
+		let rewards_before = deposit_rewards(BOB_DEPOSIT_ID) + deposit_rewards(new_deposit_id);
+		assert!(rewards_before > 0);
+
+		//Act
+		assert_ok!(XYKLiquidityMining::merge_deposits(
+			RuntimeOrigin::signed(BOB.into()),
+			BOB_DEPOSIT_ID,
+			new_deposit_id,
+		));
+
+		//Assert
+		assert!(
+			warehouse_liquidity_mining::Deposit::<Runtime, warehouse_liquidity_mining::Instance1>::get(new_deposit_id)
+				.is_none()
+		);
+
+		let rewards_after = deposit_rewards(BOB_DEPOSIT_ID);
+		assert!(rewards_after <= rewards_before);
+		assert!(rewards_before - rewards_after <= 1_000);
+
+		assert_yield_farm_totals();
+	});
+}
+
+#[test]
+fn emergency_withdraw_should_keep_farm_totals_consistent() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		init_farm();
+		go_to_next_period();
+
+		assert!(deposit_rewards(BOB_DEPOSIT_ID) > 0);
+		let bob_bsx_balance = Currencies::free_balance(BSX, &BOB.into());
+		let bob_shares_balance = Currencies::free_balance(share_token(), &BOB.into());
+		let bob_deposit_shares =
+			warehouse_liquidity_mining::Deposit::<Runtime, warehouse_liquidity_mining::Instance1>::get(BOB_DEPOSIT_ID)
+				.unwrap()
+				.shares;
+
+		//Act
+		assert_ok!(XYKLiquidityMining::emergency_withdraw(
+			RuntimeOrigin::signed(BOB.into()),
+			BOB_DEPOSIT_ID,
+		));
+
+		//Assert
+		assert!(
+			warehouse_liquidity_mining::Deposit::<Runtime, warehouse_liquidity_mining::Instance1>::get(BOB_DEPOSIT_ID)
+				.is_none()
+		);
+		assert_eq!(
+			Currencies::free_balance(share_token(), &BOB.into()),
+			bob_shares_balance + bob_deposit_shares
+		);
+		//NOTE: rewards are forfeited.
+		assert_eq!(Currencies::free_balance(BSX, &BOB.into()), bob_bsx_balance);
+
+		assert_yield_farm_totals();
+
+		//Remaining deposit keeps earning rewards.
+		let charlie_rewards = deposit_rewards(CHARLIE_DEPOSIT_ID);
+		go_to_next_period();
+		assert!(deposit_rewards(CHARLIE_DEPOSIT_ID) > charlie_rewards);
+	});
+}
+
+#[test]
+fn emergency_withdraw_should_work_when_global_farm_is_missing() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		init_farm();
+		go_to_next_period();
+
+		//NOTE: missing global farm breaks the regular withdrawal.
+		warehouse_liquidity_mining::GlobalFarm::<Runtime, warehouse_liquidity_mining::Instance1>::remove(
+			GLOBAL_FARM_ID,
+		);
+		let bob_shares_balance = Currencies::free_balance(share_token(), &BOB.into());
+		let bob_deposit_shares =
+			warehouse_liquidity_mining::Deposit::<Runtime, warehouse_liquidity_mining::Instance1>::get(BOB_DEPOSIT_ID)
+				.unwrap()
+				.shares;
+
+		//Act
+		assert_ok!(XYKLiquidityMining::emergency_withdraw(
+			RuntimeOrigin::signed(BOB.into()),
+			BOB_DEPOSIT_ID,
+		));
+
+		//Assert
+		assert!(
+			warehouse_liquidity_mining::Deposit::<Runtime, warehouse_liquidity_mining::Instance1>::get(BOB_DEPOSIT_ID)
+				.is_none()
+		);
+		assert_eq!(
+			Currencies::free_balance(share_token(), &BOB.into()),
+			bob_shares_balance + bob_deposit_shares
+		);
+
+		assert_yield_farm_totals();
+	});
+}
@@ -7,7 +7,7 @@
 
 use std::sync::Arc;
 
-use basilisk_runtime::{opaque::Block, AccountId, Balance, Index};
+use basilisk_runtime::{opaque::Block, AccountId, AssetId, Balance, BlockNumber, Index};
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
@@ -34,11 +34,15 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: basilisk_router_rpc::RouterRuntimeApi<Block, AssetId, Balance>,
+	C::Api: basilisk_ema_oracle_rpc::EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use basilisk_ema_oracle_rpc::{EmaOracle, EmaOracleApiServer};
+	use basilisk_router_rpc::{Router, RouterApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
@@ -48,6 +52,8 @@ where
 
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Router::new(client.clone()).into_rpc())?;
+	module.merge(EmaOracle::new(client.clone()).into_rpc())?;
 	module.merge(StateMigration::new(client, backend).into_rpc())?;
 
 	// Extend this RPC with a custom API by using the following syntax.
@@ -0,0 +1,93 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{traits::AccountFeeCurrency, Config, Event, Pallet};
+
+use frame_support::{dispatch::DispatchInfo, traits::IsType};
+use pallet_transaction_payment::OnChargeTransaction;
+use primitives::Balance;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, Zero},
+	transaction_validity::TransactionValidityError,
+};
+use sp_std::marker::PhantomData;
+
+/// Transaction fee charging which selects the fallback fee currency of the account before the fee
+/// is withdrawn by `OCT`.
+///
+/// Fallback currency is set as the account's currency only while `OCT` withdraws the fee, account's
+/// currency is restored right after. `OCT` has to keep the currency of the withdrawn fee in its
+/// `LiquidityInfo` so the fee is corrected and refunded in the same currency, as `TransferFees` does.
+pub struct FallbackFeeCurrency<T, OCT>(PhantomData<(T, OCT)>);
+
+impl<T, OCT> OnChargeTransaction<T> for FallbackFeeCurrency<T, OCT>
+where
+	T: Config + pallet_transaction_payment::Config,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	OCT: OnChargeTransaction<T>,
+	OCT::Balance: IsType<Balance>,
+{
+	type Balance = OCT::Balance;
+	type LiquidityInfo = OCT::LiquidityInfo;
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		if fee.is_zero() {
+			return OCT::withdraw_fee(who, call, dispatch_info, fee, tip);
+		}
+
+		let (fallback_currency, checked) = Pallet::<T>::select_fee_currency(who, fee.into());
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			Pallet::<T>::select_fee_currency_weight(checked),
+			dispatch_info.class,
+		);
+
+		let Some(currency) = fallback_currency else {
+			return OCT::withdraw_fee(who, call, dispatch_info, fee, tip);
+		};
+
+		let account_currency = T::AccountCurrency::get(who);
+		T::AccountCurrency::set(who, currency);
+		let liquidity_info = OCT::withdraw_fee(who, call, dispatch_info, fee, tip);
+		T::AccountCurrency::set(who, account_currency);
+
+		if liquidity_info.is_ok() {
+			Pallet::<T>::deposit_event(Event::FeePaidInFallbackCurrency {
+				who: who.clone(),
+				currency,
+			});
+		}
+
+		liquidity_info
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		OCT::correct_and_deposit_fee(who, dispatch_info, post_info, corrected_fee, tip, already_withdrawn)
+	}
+}
@@ -0,0 +1,206 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Fee currency selection pallet
+//!
+//! ## Overview
+//!
+//! Transaction fees are paid in the currency set by the account. When the account doesn't hold
+//! enough of this currency, the transaction fails. This is the case e.g. for the new accounts
+//! funded via XCM which never set their fee currency.
+//!
+//! This pallet provides a fallback for such accounts. When the account can't pay the fee in its
+//! currency, the fee of the transaction is paid in the first currency of the governance-ordered
+//! `FallbackCurrencies` list the account holds enough of. Account's fee currency is not changed.
+//! Amount of the fallback currency needed to pay the fee is calculated with the price provided by
+//! `NativePriceOracle`, the same price the fee is converted with when it's withdrawn.
+//!
+//! Selection is done by `FallbackFeeCurrency` which wraps the transaction payment's
+//! `OnChargeTransaction` implementation. Weight of the selection is registered as an extra weight
+//! of the block.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*};
+use frame_system::pallet_prelude::*;
+use hydradx_traits::{fee::InspectTransactionFeeCurrency, NativePriceOracle};
+use orml_traits::MultiCurrency;
+use primitives::{AssetId, Balance, Price};
+use sp_runtime::{traits::Saturating, FixedPointNumber};
+use sp_std::vec::Vec;
+
+pub use charge::FallbackFeeCurrency;
+pub use traits::AccountFeeCurrency;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod tests;
+
+pub mod charge;
+pub mod traits;
+pub mod weights;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to set the fallback currencies.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Multi currency mechanism.
+		type Currencies: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+
+		/// Fee currency of the accounts.
+		type AccountCurrency: AccountFeeCurrency<Self::AccountId>;
+
+		/// Accepted fee currencies.
+		type PaymentAssetSupport: InspectTransactionFeeCurrency<AssetId>;
+
+		/// Price of the fee currencies in the native asset used to convert the fee.
+		type NativePriceOracle: NativePriceOracle<AssetId, Price>;
+
+		/// Native asset id. Fees are calculated in the native asset.
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// Max number of the fallback currencies.
+		#[pallet::constant]
+		type MaxFallbackCurrencies: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Currencies used to pay the fee when the account can't pay it in its currency, in order of
+	/// preference.
+	#[pallet::storage]
+	#[pallet::getter(fn fallback_currencies)]
+	pub type FallbackCurrencies<T: Config> = StorageValue<_, BoundedVec<AssetId, T::MaxFallbackCurrencies>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Fallback currencies were set.
+		FallbackCurrenciesSet { currencies: Vec<AssetId> },
+		/// Fee of the transaction was paid in the fallback currency.
+		FeePaidInFallbackCurrency { who: T::AccountId, currency: AssetId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Currency is not accepted as the fee currency.
+		NotFeeCurrency,
+		/// Currency is in the list more than once.
+		DuplicateCurrency,
+		/// Number of the currencies exceeds `MaxFallbackCurrencies`.
+		TooManyCurrencies,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the fallback currencies. Currencies are tried in the given order.
+		///
+		/// Parameters:
+		/// - `origin`: `UpdateOrigin`
+		/// - `currencies`: accepted fee currencies in order of preference
+		///
+		/// Emits `FallbackCurrenciesSet` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fallback_currencies(currencies.len() as u32))]
+		pub fn set_fallback_currencies(origin: OriginFor<T>, currencies: Vec<AssetId>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			for (idx, currency) in currencies.iter().enumerate() {
+				ensure!(
+					T::PaymentAssetSupport::is_transaction_fee_currency(*currency),
+					Error::<T>::NotFeeCurrency
+				);
+				ensure!(!currencies[..idx].contains(currency), Error::<T>::DuplicateCurrency);
+			}
+
+			let fallback_currencies: BoundedVec<AssetId, T::MaxFallbackCurrencies> = currencies
+				.clone()
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyCurrencies)?;
+
+			FallbackCurrencies::<T>::put(fallback_currencies);
+
+			Self::deposit_event(Event::FallbackCurrenciesSet { currencies });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Select the first fallback currency the account holds enough of when the account can't pay
+	/// the `fee` in its currency. Account's fee currency is not changed.
+	///
+	/// Account's currency is kept when the fee can't be converted to it, the payment then fails or
+	/// succeeds the same way as without the fallback.
+	///
+	/// Returns selected currency and the number of the checked fallback currencies.
+	pub fn select_fee_currency(who: &T::AccountId, fee: Balance) -> (Option<AssetId>, u32) {
+		let account_currency = T::AccountCurrency::get(who);
+		if Self::can_pay_fee(who, account_currency, fee).unwrap_or(true) {
+			return (None, 0);
+		}
+
+		let mut checked: u32 = 0;
+		let currency = Self::fallback_currencies()
+			.into_iter()
+			.filter(|currency| *currency != account_currency)
+			.find(|currency| {
+				checked.saturating_inc();
+				Self::can_pay_fee(who, *currency, fee).unwrap_or(false)
+			});
+
+		(currency, checked)
+	}
+
+	/// Weight of the fee currency selection which checked `n` fallback currencies.
+	pub fn select_fee_currency_weight(n: u32) -> Weight {
+		<T as Config>::WeightInfo::select_fee_currency(n)
+	}
+
+	/// Amount of the `currency` needed to pay the `fee` in the native asset or `None` if the fee
+	/// can't be converted to the `currency`.
+	pub fn fee_amount(currency: AssetId, fee: Balance) -> Option<Balance> {
+		if currency == T::NativeAssetId::get() {
+			return Some(fee);
+		}
+
+		T::NativePriceOracle::price(currency)?.checked_mul_int(fee)
+	}
+
+	fn can_pay_fee(who: &T::AccountId, currency: AssetId, fee: Balance) -> Option<bool> {
+		let amount = Self::fee_amount(currency, fee)?;
+
+		Some(T::Currencies::ensure_can_withdraw(currency, who, amount).is_ok())
+	}
+}
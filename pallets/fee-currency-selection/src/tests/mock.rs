@@ -0,0 +1,209 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_fee_currency_selection;
+use crate::*;
+
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Everything, Nothing},
+};
+use frame_system::{self as system, EnsureRoot};
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, FixedU128,
+};
+use std::{cell::RefCell, collections::HashMap};
+
+pub type AccountId = u64;
+pub type Amount = i128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub const BSX: AssetId = 0;
+pub const KSM: AssetId = 1;
+pub const AUSD: AssetId = 2;
+pub const DOT: AssetId = 3;
+pub const MOVR: AssetId = 4;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		FeeCurrencySelection: pallet_fee_currency_selection,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		1_000
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ConstU32<10>;
+	type DustRemovalWhitelist = Nothing;
+	type MaxReserves = ConstU32<10>;
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const NativeAssetId: AssetId = BSX;
+	pub const MaxFallbackCurrencies: u32 = 3;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type Currencies = Tokens;
+	type AccountCurrency = DummyAccountCurrency;
+	type PaymentAssetSupport = DummyPaymentAssetSupport;
+	type NativePriceOracle = DummyPaymentAssetSupport;
+	type NativeAssetId = NativeAssetId;
+	type MaxFallbackCurrencies = MaxFallbackCurrencies;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	pub static ACCOUNT_CURRENCY: RefCell<HashMap<AccountId, AssetId>> = RefCell::new(HashMap::default());
+}
+
+pub fn account_currency(who: AccountId) -> AssetId {
+	DummyAccountCurrency::get(&who)
+}
+
+/// Dummy account currency, native asset is used when no currency is set.
+pub struct DummyAccountCurrency;
+
+impl AccountFeeCurrency<AccountId> for DummyAccountCurrency {
+	fn get(who: &AccountId) -> AssetId {
+		ACCOUNT_CURRENCY.with(|v| v.borrow().get(who).copied().unwrap_or(BSX))
+	}
+
+	fn set(who: &AccountId, currency: AssetId) {
+		ACCOUNT_CURRENCY.with(|v| v.borrow_mut().insert(*who, currency));
+	}
+}
+
+/// Dummy payment asset support. BSX, KSM, AUSD and DOT are accepted fee currencies. KSM and AUSD
+/// have fixed prices 1 BSX = 0.5 KSM and 1 BSX = 2 AUSD, DOT has no price.
+pub struct DummyPaymentAssetSupport;
+
+impl InspectTransactionFeeCurrency<AssetId> for DummyPaymentAssetSupport {
+	fn is_transaction_fee_currency(asset: AssetId) -> bool {
+		matches!(asset, BSX | KSM | AUSD | DOT)
+	}
+}
+
+impl NativePriceOracle<AssetId, Price> for DummyPaymentAssetSupport {
+	fn price(currency: AssetId) -> Option<Price> {
+		match currency {
+			BSX => Some(FixedU128::from(1)),
+			KSM => Some(FixedU128::from_rational(1, 2)),
+			AUSD => Some(FixedU128::from(2)),
+			_ => None,
+		}
+	}
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	fallback_currencies: Vec<AssetId>,
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_fallback_currencies(mut self, currencies: Vec<AssetId>) -> Self {
+		self.fallback_currencies = currencies;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		ACCOUNT_CURRENCY.with(|v| v.borrow_mut().clear());
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			FallbackCurrencies::<Test>::put(BoundedVec::truncate_from(self.fallback_currencies));
+		});
+		ext
+	}
+}
@@ -0,0 +1,153 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+const FEE: Balance = 10 * ONE;
+
+#[test]
+fn select_fee_currency_should_keep_account_currency_when_account_can_pay_fee() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 100 * ONE), (ALICE, KSM, 100 * ONE)])
+		.with_fallback_currencies(vec![KSM])
+		.build()
+		.execute_with(|| {
+			//Act
+			let selected = FeeCurrencySelection::select_fee_currency(&ALICE, FEE);
+
+			//Assert
+			assert_eq!(selected, (None, 0));
+			assert_eq!(account_currency(ALICE), BSX);
+		});
+}
+
+#[test]
+fn select_fee_currency_should_select_first_fallback_currency_account_can_pay_fee_with() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, BSX, ONE),
+			(ALICE, DOT, 100 * ONE),
+			(ALICE, AUSD, 100 * ONE),
+			(ALICE, KSM, 100 * ONE),
+		])
+		.with_fallback_currencies(vec![DOT, AUSD, KSM])
+		.build()
+		.execute_with(|| {
+			//Act
+			let selected = FeeCurrencySelection::select_fee_currency(&ALICE, FEE);
+
+			//Assert
+			assert_eq!(selected, (Some(AUSD), 2));
+		});
+}
+
+#[test]
+fn select_fee_currency_should_skip_fallback_currency_when_balance_is_not_enough() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, AUSD, 19 * ONE), (ALICE, KSM, 10 * ONE)])
+		.with_fallback_currencies(vec![AUSD, KSM])
+		.build()
+		.execute_with(|| {
+			//Act
+			let selected = FeeCurrencySelection::select_fee_currency(&ALICE, FEE);
+
+			//Assert
+			assert_eq!(selected, (Some(KSM), 2));
+		});
+}
+
+#[test]
+fn select_fee_currency_should_select_native_asset_when_it_is_fallback_currency() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 100 * ONE), (ALICE, AUSD, ONE)])
+		.with_fallback_currencies(vec![BSX])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			DummyAccountCurrency::set(&ALICE, AUSD);
+
+			//Act
+			let selected = FeeCurrencySelection::select_fee_currency(&ALICE, FEE);
+
+			//Assert
+			assert_eq!(selected, (Some(BSX), 1));
+		});
+}
+
+#[test]
+fn select_fee_currency_should_keep_account_currency_when_no_fallback_currency_can_pay_fee() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, ONE), (ALICE, AUSD, ONE), (ALICE, MOVR, 100 * ONE)])
+		.with_fallback_currencies(vec![AUSD, KSM])
+		.build()
+		.execute_with(|| {
+			//Act
+			let selected = FeeCurrencySelection::select_fee_currency(&ALICE, FEE);
+
+			//Assert
+			assert_eq!(selected, (None, 2));
+		});
+}
+
+#[test]
+fn select_fee_currency_should_keep_account_currency_when_fee_cant_be_converted_to_it() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, DOT, ONE), (ALICE, KSM, 100 * ONE)])
+		.with_fallback_currencies(vec![KSM])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			DummyAccountCurrency::set(&ALICE, DOT);
+
+			//Act
+			let selected = FeeCurrencySelection::select_fee_currency(&ALICE, FEE);
+
+			//Assert
+			assert_eq!(selected, (None, 0));
+		});
+}
+
+#[test]
+fn select_fee_currency_should_not_change_account_currency() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, AUSD, ONE), (ALICE, KSM, 100 * ONE)])
+		.with_fallback_currencies(vec![KSM])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			DummyAccountCurrency::set(&ALICE, AUSD);
+
+			//Act
+			let selected = FeeCurrencySelection::select_fee_currency(&ALICE, FEE);
+
+			//Assert
+			assert_eq!(selected, (Some(KSM), 1));
+			assert_eq!(account_currency(ALICE), AUSD);
+			assert!(System::events().is_empty());
+		});
+}
+
+#[test]
+fn fee_amount_should_convert_fee_with_native_price_of_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_eq!(FeeCurrencySelection::fee_amount(BSX, FEE), Some(FEE));
+		assert_eq!(FeeCurrencySelection::fee_amount(KSM, FEE), Some(5 * ONE));
+		assert_eq!(FeeCurrencySelection::fee_amount(AUSD, FEE), Some(20 * ONE));
+		assert_eq!(FeeCurrencySelection::fee_amount(DOT, FEE), None);
+	});
+}
@@ -0,0 +1,98 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn set_fallback_currencies_should_store_currencies_in_given_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(FeeCurrencySelection::set_fallback_currencies(
+			Origin::root(),
+			vec![KSM, BSX, AUSD]
+		));
+
+		//Assert
+		assert_eq!(
+			FeeCurrencySelection::fallback_currencies().into_inner(),
+			vec![KSM, BSX, AUSD]
+		);
+
+		assert_last_event!(Event::FallbackCurrenciesSet {
+			currencies: vec![KSM, BSX, AUSD],
+		}
+		.into());
+	});
+}
+
+#[test]
+fn set_fallback_currencies_should_clear_currencies_when_list_is_empty() {
+	ExtBuilder::default()
+		.with_fallback_currencies(vec![KSM])
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(FeeCurrencySelection::set_fallback_currencies(Origin::root(), vec![]));
+
+			//Assert
+			assert!(FeeCurrencySelection::fallback_currencies().is_empty());
+		});
+}
+
+#[test]
+fn set_fallback_currencies_should_fail_when_currency_is_not_fee_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			FeeCurrencySelection::set_fallback_currencies(Origin::root(), vec![KSM, MOVR]),
+			Error::<Test>::NotFeeCurrency
+		);
+	});
+}
+
+#[test]
+fn set_fallback_currencies_should_fail_when_currency_is_duplicated() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			FeeCurrencySelection::set_fallback_currencies(Origin::root(), vec![KSM, AUSD, KSM]),
+			Error::<Test>::DuplicateCurrency
+		);
+	});
+}
+
+#[test]
+fn set_fallback_currencies_should_fail_when_there_are_too_many_currencies() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			FeeCurrencySelection::set_fallback_currencies(Origin::root(), vec![BSX, KSM, AUSD, DOT]),
+			Error::<Test>::TooManyCurrencies
+		);
+	});
+}
+
+#[test]
+fn set_fallback_currencies_should_fail_when_origin_is_not_update_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			FeeCurrencySelection::set_fallback_currencies(Origin::signed(BOB), vec![KSM]),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
@@ -0,0 +1,27 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use primitives::AssetId;
+
+/// Fee currency of the accounts.
+pub trait AccountFeeCurrency<AccountId> {
+	/// Return currency the account pays the fees in.
+	fn get(who: &AccountId) -> AssetId;
+
+	/// Set currency the account pays the fees in.
+	fn set(who: &AccountId, currency: AssetId);
+}
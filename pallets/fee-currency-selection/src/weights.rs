@@ -0,0 +1,58 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_fee_currency_selection.
+pub trait WeightInfo {
+	fn set_fallback_currencies(n: u32, ) -> Weight;
+	fn select_fee_currency(n: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_fee_currency_selection`.
+impl WeightInfo for () {
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:10 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `FeeCurrencySelection::FallbackCurrencies` (r:0 w:1)
+	/// Proof: `FeeCurrencySelection::FallbackCurrencies` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 10]`.
+	fn set_fallback_currencies(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (81 ±0)`
+		//  Estimated: `990 + n * (2503 ±0)`
+		// Minimum execution time: 8_531_000 picoseconds.
+		Weight::from_parts(9_012_374, 990)
+			// Standard Error: 4_218
+			.saturating_add(Weight::from_parts(3_874_102, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2503).saturating_mul(n.into()))
+	}
+	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencyPrice` (r:11 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencyPrice` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:11 w:0)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `FeeCurrencySelection::FallbackCurrencies` (r:1 w:0)
+	/// Proof: `FeeCurrencySelection::FallbackCurrencies` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn select_fee_currency(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1_054 + n * (132 ±0)`
+		//  Estimated: `6_156 + n * (2583 ±0)`
+		// Minimum execution time: 22_418_000 picoseconds.
+		Weight::from_parts(23_106_000, 6156)
+			// Standard Error: 9_312
+			.saturating_add(Weight::from_parts(11_254_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2583).saturating_mul(n.into()))
+	}
+}
@@ -0,0 +1,376 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Limit orders pallet
+//!
+//! ## Overview
+//!
+//! This pallet allows users to place limit orders to sell an asset for at least the limit price.
+//! Amount of the order is reserved when the order is placed.
+//!
+//! Orders are not matched against each other but settled through the router. Anyone (e.g. a keeper
+//! bot) can fill an order once selling the filled amount along the route of the assets yields at
+//! least the limit price of the order. Order is filled by selling the reserved amount along the same
+//! route with trade limit derived from the limit price, so the owner never receives less than the
+//! limit price. Keeper is paid `KeeperFee` portion of the filled amount for filling the order.
+//!
+//! Only the limit price is guaranteed to the owner. Keeper chooses when and in which transaction
+//! order the fill is executed, so it can capture any surplus above the limit price, e.g. by
+//! trading in the pools of the route before the fill and back after it.
+//!
+//! Orders can be filled partially. Orders with expiry can't be filled after the expiry block and can
+//! be cancelled by anyone. Active orders can be cancelled only by the owner. Cancellation unreserves
+//! the remaining amount of the order.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*};
+use frame_system::{ensure_signed, pallet_prelude::*};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use primitives::{AssetId, Balance, NamedReserveIdentifier};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{Saturating, Zero},
+	ArithmeticError, Permill, Rounding,
+};
+
+pub use traits::OrderSettlement;
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod tests;
+
+pub mod traits;
+pub mod types;
+pub mod weights;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+pub type OrderOf<T> = Order<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Multi currency used to reserve the amounts of the orders.
+		type Currency: NamedMultiReservableCurrency<
+			Self::AccountId,
+			ReserveIdentifier = NamedReserveIdentifier,
+			CurrencyId = AssetId,
+			Balance = Balance,
+		>;
+
+		/// Quotes and execution of the trades along the routes of the assets.
+		type Settlement: OrderSettlement<Self::AccountId, AssetId, Balance>;
+
+		/// Portion of the filled amount paid to the account filling the order.
+		#[pallet::constant]
+		type KeeperFee: Get<Permill>;
+
+		/// Minimal amount of the order. Partial fills can't leave less than this amount in the order.
+		#[pallet::constant]
+		type MinOrderAmount: Get<Balance>;
+
+		/// Named reserve identifier used to reserve the amounts of the orders.
+		#[pallet::constant]
+		type NamedReserveId: Get<NamedReserveIdentifier>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Id of the next placed order.
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// Active orders.
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, OrderOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Limit order was placed.
+		OrderPlaced {
+			order_id: OrderId,
+			owner: T::AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			expires: Option<BlockNumberFor<T>>,
+		},
+		/// Limit order was filled, fully or partially.
+		OrderFilled {
+			order_id: OrderId,
+			who: T::AccountId,
+			amount_in: Balance,
+			amount_out: Balance,
+			keeper_fee: Balance,
+		},
+		/// Limit order was completely filled and removed.
+		OrderCompleted { order_id: OrderId },
+		/// Limit order was cancelled and the remaining amount was unreserved.
+		OrderCancelled {
+			order_id: OrderId,
+			who: T::AccountId,
+			amount_in: Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Assets of the order are the same.
+		SameAssets,
+		/// Amount of the order is lower than `MinOrderAmount`.
+		OrderAmountTooLow,
+		/// Limit amount of the order is zero.
+		InvalidLimit,
+		/// Expiry block of the order is in the past.
+		InvalidExpiry,
+		/// Order doesn't exist.
+		OrderNotFound,
+		/// Order has expired and can't be filled anymore.
+		OrderExpired,
+		/// Fill amount is zero or bigger than the remaining amount of the order.
+		InvalidFillAmount,
+		/// Partial fill would leave less than `MinOrderAmount` in the order.
+		RemainingAmountTooLow,
+		/// Sell of the assets along their route can't be calculated.
+		PriceNotAvailable,
+		/// Sell of the assets along their route doesn't reach the limit price of the order.
+		LimitPriceNotReached,
+		/// Active order can be cancelled only by its owner.
+		NotOrderOwner,
+		/// Reserved balance of the owner is lower than the remaining amount of the order.
+		InsufficientReservedBalance,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Place limit order to sell `amount_in` of `asset_in` for at least `amount_out` of `asset_out`.
+		///
+		/// `amount_in` is reserved until the order is filled or cancelled.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the order
+		/// - `asset_in`: asset to sell
+		/// - `asset_out`: asset to buy
+		/// - `amount_in`: amount of `asset_in` to sell
+		/// - `amount_out`: minimal amount of `asset_out` to receive for the whole `amount_in`
+		/// - `expires`: block from which the order can't be filled anymore
+		///
+		/// Emits `OrderPlaced` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order())]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			expires: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(asset_in != asset_out, Error::<T>::SameAssets);
+			ensure!(amount_in >= T::MinOrderAmount::get(), Error::<T>::OrderAmountTooLow);
+			ensure!(!amount_out.is_zero(), Error::<T>::InvalidLimit);
+			if let Some(expires) = expires {
+				ensure!(
+					expires > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidExpiry
+				);
+			}
+
+			T::Currency::reserve_named(&T::NamedReserveId::get(), asset_in, &who, amount_in)?;
+
+			let order_id = NextOrderId::<T>::try_mutate(|next_id| -> Result<OrderId, DispatchError> {
+				let current_id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+
+			Orders::<T>::insert(
+				order_id,
+				Order {
+					owner: who.clone(),
+					asset_in,
+					asset_out,
+					amount_in,
+					amount_out,
+					expires,
+				},
+			);
+
+			Self::deposit_event(Event::OrderPlaced {
+				order_id,
+				owner: who,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				expires,
+			});
+
+			Ok(())
+		}
+
+		/// Fill `amount_in` of the limit order.
+		///
+		/// Can be called by anyone once selling `amount_in` without the keeper fee along the route of
+		/// the assets yields at least the limit price of the order. `KeeperFee` portion of `amount_in`
+		/// is paid to the caller and the rest is sold along the route on behalf of the owner. Trade
+		/// limit is derived from the limit price of the order, so the owner receives at least the
+		/// limit price for the whole `amount_in`.
+		///
+		/// Order is removed when it's completely filled.
+		///
+		/// Parameters:
+		/// - `origin`: account filling the order
+		/// - `order_id`: id of the order to fill
+		/// - `amount_in`: amount of `asset_in` of the order to fill
+		///
+		/// Emits `OrderFilled` event when successful and `OrderCompleted` event when the order was
+		/// completely filled.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::fill_order()
+			.saturating_add(T::Settlement::calculate_sell_weight())
+			.saturating_add(T::Settlement::sell_weight()))]
+		pub fn fill_order(origin: OriginFor<T>, order_id: OrderId, amount_in: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Orders::<T>::try_mutate_exists(order_id, |maybe_order| -> DispatchResult {
+				let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
+
+				ensure!(!Self::is_expired(order), Error::<T>::OrderExpired);
+				ensure!(
+					!amount_in.is_zero() && amount_in <= order.amount_in,
+					Error::<T>::InvalidFillAmount
+				);
+
+				let remaining_amount_in = order.amount_in.saturating_sub(amount_in);
+				ensure!(
+					remaining_amount_in.is_zero() || remaining_amount_in >= T::MinOrderAmount::get(),
+					Error::<T>::RemainingAmountTooLow
+				);
+
+				// owner receives at least the limit price for the whole filled amount, including keeper fee
+				let min_amount_out =
+					multiply_by_rational_with_rounding(amount_in, order.amount_out, order.amount_in, Rounding::Up)
+						.ok_or(ArithmeticError::Overflow)?;
+
+				let keeper_fee = T::KeeperFee::get().mul_floor(amount_in);
+				let amount_to_sell = amount_in.saturating_sub(keeper_fee);
+
+				let expected_amount_out =
+					T::Settlement::calculate_sell(order.asset_in, order.asset_out, amount_to_sell)
+						.ok_or(Error::<T>::PriceNotAvailable)?;
+				ensure!(expected_amount_out >= min_amount_out, Error::<T>::LimitPriceNotReached);
+
+				let not_unreserved =
+					T::Currency::unreserve_named(&T::NamedReserveId::get(), order.asset_in, &order.owner, amount_in);
+				ensure!(not_unreserved.is_zero(), Error::<T>::InsufficientReservedBalance);
+
+				if !keeper_fee.is_zero() {
+					T::Currency::transfer(order.asset_in, &order.owner, &who, keeper_fee)?;
+				}
+
+				let amount_out = T::Settlement::sell(
+					&order.owner,
+					order.asset_in,
+					order.asset_out,
+					amount_to_sell,
+					min_amount_out,
+				)?;
+
+				order.amount_in = remaining_amount_in;
+				order.amount_out = order.amount_out.saturating_sub(min_amount_out);
+
+				Self::deposit_event(Event::OrderFilled {
+					order_id,
+					who,
+					amount_in,
+					amount_out,
+					keeper_fee,
+				});
+
+				if order.amount_in.is_zero() {
+					*maybe_order = None;
+					Self::deposit_event(Event::OrderCompleted { order_id });
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Cancel the limit order and unreserve its remaining amount.
+		///
+		/// Active order can be cancelled only by its owner. Expired order can be cancelled by anyone.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the order or anyone if the order has expired
+		/// - `order_id`: id of the order to cancel
+		///
+		/// Emits `OrderCancelled` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let order = Orders::<T>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(
+				who == order.owner || Self::is_expired(&order),
+				Error::<T>::NotOrderOwner
+			);
+
+			let not_unreserved =
+				T::Currency::unreserve_named(&T::NamedReserveId::get(), order.asset_in, &order.owner, order.amount_in);
+			ensure!(not_unreserved.is_zero(), Error::<T>::InsufficientReservedBalance);
+
+			Orders::<T>::remove(order_id);
+
+			Self::deposit_event(Event::OrderCancelled {
+				order_id,
+				who,
+				amount_in: order.amount_in,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn is_expired(order: &OrderOf<T>) -> bool {
+		order
+			.expires
+			.is_some_and(|expires| frame_system::Pallet::<T>::block_number() >= expires)
+	}
+}
@@ -0,0 +1,135 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn cancel_order_should_unreserve_amount_and_remove_order_when_called_by_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LimitOrders::place_order(
+			Origin::signed(ALICE),
+			KSM,
+			AUSD,
+			100 * ONE,
+			1_200 * ONE,
+			None
+		));
+
+		//Act
+		assert_ok!(LimitOrders::cancel_order(Origin::signed(ALICE), 0));
+
+		//Assert
+		assert_eq!(LimitOrders::orders(0), None);
+		assert_eq!(reserved_balance(ALICE, KSM), 0);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 1_000 * ONE);
+
+		assert_last_event!(Event::OrderCancelled {
+			order_id: 0,
+			who: ALICE,
+			amount_in: 100 * ONE,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn cancel_order_should_unreserve_only_remaining_amount_when_order_was_filled_partially() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LimitOrders::place_order(
+			Origin::signed(ALICE),
+			KSM,
+			AUSD,
+			100 * ONE,
+			1_200 * ONE,
+			None
+		));
+		set_spot_price(Some((13, 1)));
+		assert_ok!(LimitOrders::fill_order(Origin::signed(KEEPER), 0, 40 * ONE));
+
+		//Act
+		assert_ok!(LimitOrders::cancel_order(Origin::signed(ALICE), 0));
+
+		//Assert
+		assert_eq!(reserved_balance(ALICE, KSM), 0);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 960 * ONE);
+
+		assert_last_event!(Event::OrderCancelled {
+			order_id: 0,
+			who: ALICE,
+			amount_in: 60 * ONE,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn cancel_order_should_work_for_anyone_when_order_has_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LimitOrders::place_order(
+			Origin::signed(ALICE),
+			KSM,
+			AUSD,
+			100 * ONE,
+			1_200 * ONE,
+			Some(10)
+		));
+		System::set_block_number(10);
+
+		//Act
+		assert_ok!(LimitOrders::cancel_order(Origin::signed(KEEPER), 0));
+
+		//Assert
+		assert_eq!(LimitOrders::orders(0), None);
+		assert_eq!(reserved_balance(ALICE, KSM), 0);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 1_000 * ONE);
+	});
+}
+
+#[test]
+fn cancel_order_should_fail_when_called_by_not_owner_and_order_is_active() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LimitOrders::place_order(
+			Origin::signed(ALICE),
+			KSM,
+			AUSD,
+			100 * ONE,
+			1_200 * ONE,
+			Some(10)
+		));
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::cancel_order(Origin::signed(BOB), 0),
+			Error::<Test>::NotOrderOwner
+		);
+	});
+}
+
+#[test]
+fn cancel_order_should_fail_when_order_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			LimitOrders::cancel_order(Origin::signed(ALICE), 0),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
@@ -0,0 +1,223 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn place_alice_order(expires: Option<u64>) {
+	// sell 100 KSM for at least 1 200 AUSD - limit price is 12 AUSD per KSM
+	assert_ok!(LimitOrders::place_order(
+		Origin::signed(ALICE),
+		KSM,
+		AUSD,
+		100 * ONE,
+		1_200 * ONE,
+		expires
+	));
+}
+
+#[test]
+fn fill_order_should_sell_whole_amount_and_remove_order_when_limit_price_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		place_alice_order(None);
+		set_spot_price(Some((13, 1)));
+
+		//Act
+		assert_ok!(LimitOrders::fill_order(Origin::signed(KEEPER), 0, 100 * ONE));
+
+		//Assert
+		assert_eq!(LimitOrders::orders(0), None);
+		assert_eq!(reserved_balance(ALICE, KSM), 0);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 900 * ONE);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_287 * ONE);
+		assert_eq!(Tokens::free_balance(KSM, &KEEPER), ONE);
+
+		assert!(has_event(
+			Event::OrderFilled {
+				order_id: 0,
+				who: KEEPER,
+				amount_in: 100 * ONE,
+				amount_out: 1_287 * ONE,
+				keeper_fee: ONE,
+			}
+			.into()
+		));
+		assert_last_event!(Event::OrderCompleted { order_id: 0 }.into());
+	});
+}
+
+#[test]
+fn fill_order_should_keep_remaining_amount_reserved_when_order_is_filled_partially() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		place_alice_order(Some(10));
+		set_spot_price(Some((13, 1)));
+
+		//Act
+		assert_ok!(LimitOrders::fill_order(Origin::signed(KEEPER), 0, 40 * ONE));
+
+		//Assert
+		assert_eq!(
+			LimitOrders::orders(0).unwrap(),
+			Order {
+				owner: ALICE,
+				asset_in: KSM,
+				asset_out: AUSD,
+				amount_in: 60 * ONE,
+				amount_out: 720 * ONE,
+				expires: Some(10),
+			}
+		);
+		assert_eq!(reserved_balance(ALICE, KSM), 60 * ONE);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 900 * ONE);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 514_800_000_000_000);
+		assert_eq!(Tokens::free_balance(KSM, &KEEPER), 400_000_000_000);
+
+		assert_last_event!(Event::OrderFilled {
+			order_id: 0,
+			who: KEEPER,
+			amount_in: 40 * ONE,
+			amount_out: 514_800_000_000_000,
+			keeper_fee: 400_000_000_000,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn fill_order_should_allow_to_fill_rest_of_partially_filled_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		place_alice_order(None);
+		set_spot_price(Some((13, 1)));
+		assert_ok!(LimitOrders::fill_order(Origin::signed(KEEPER), 0, 40 * ONE));
+
+		//Act
+		assert_ok!(LimitOrders::fill_order(Origin::signed(BOB), 0, 60 * ONE));
+
+		//Assert
+		assert_eq!(LimitOrders::orders(0), None);
+		assert_eq!(reserved_balance(ALICE, KSM), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_287 * ONE);
+		assert_eq!(Tokens::free_balance(KSM, &BOB), 1_000 * ONE + 600_000_000_000);
+	});
+}
+
+#[test]
+fn fill_order_should_fail_when_limit_price_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		place_alice_order(None);
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::fill_order(Origin::signed(KEEPER), 0, 100 * ONE),
+			Error::<Test>::LimitPriceNotReached
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_fail_when_limit_price_is_reached_only_without_keeper_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		place_alice_order(None);
+		set_spot_price(Some((12, 1)));
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::fill_order(Origin::signed(KEEPER), 0, 100 * ONE),
+			Error::<Test>::LimitPriceNotReached
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_fail_when_sell_can_not_be_calculated() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		place_alice_order(None);
+		set_spot_price(None);
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::fill_order(Origin::signed(KEEPER), 0, 100 * ONE),
+			Error::<Test>::PriceNotAvailable
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_fail_when_order_has_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		place_alice_order(Some(10));
+		set_spot_price(Some((13, 1)));
+		System::set_block_number(10);
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::fill_order(Origin::signed(KEEPER), 0, 100 * ONE),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_fail_when_order_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			LimitOrders::fill_order(Origin::signed(KEEPER), 0, 100 * ONE),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_fail_when_fill_amount_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		place_alice_order(None);
+		set_spot_price(Some((13, 1)));
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::fill_order(Origin::signed(KEEPER), 0, 0),
+			Error::<Test>::InvalidFillAmount
+		);
+		assert_noop!(
+			LimitOrders::fill_order(Origin::signed(KEEPER), 0, 100 * ONE + 1),
+			Error::<Test>::InvalidFillAmount
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_fail_when_remaining_amount_would_be_lower_than_min_order_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		place_alice_order(None);
+		set_spot_price(Some((13, 1)));
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::fill_order(Origin::signed(KEEPER), 0, 100 * ONE - ONE / 2),
+			Error::<Test>::RemainingAmountTooLow
+		);
+	});
+}
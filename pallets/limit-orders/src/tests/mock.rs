@@ -0,0 +1,228 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_limit_orders;
+use crate::*;
+
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Everything, Nothing},
+	weights::Weight,
+};
+use frame_system as system;
+use orml_traits::parameter_type_with_key;
+use primitives::Amount;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError,
+};
+use std::cell::RefCell;
+
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const KEEPER: AccountId = 3;
+pub const POOL: AccountId = 100;
+
+pub const BSX: AssetId = 0;
+pub const KSM: AssetId = 1;
+pub const AUSD: AssetId = 2;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		LimitOrders: pallet_limit_orders,
+		Tokens: orml_tokens,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		1
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ConstU32<1>;
+	type DustRemovalWhitelist = Nothing;
+	type MaxReserves = ConstU32<100>;
+	type ReserveIdentifier = NamedReserveIdentifier;
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const KeeperFee: Permill = Permill::from_percent(1);
+	pub const MinOrderAmount: Balance = ONE;
+	pub const NamedReserveId: NamedReserveIdentifier = *b"limitord";
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type Settlement = DummyPool;
+	type KeeperFee = KeeperFee;
+	type MinOrderAmount = MinOrderAmount;
+	type NamedReserveId = NamedReserveId;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	pub static SPOT_PRICE: RefCell<Option<(Balance, Balance)>> = const { RefCell::new(None) };
+}
+
+pub fn set_spot_price(price: Option<(Balance, Balance)>) {
+	SPOT_PRICE.with(|v| *v.borrow_mut() = price);
+}
+
+/// Dummy pool of all assets which trades at the spot price.
+pub struct DummyPool;
+
+impl DummyPool {
+	fn spot_price() -> Option<(Balance, Balance)> {
+		SPOT_PRICE.with(|v| *v.borrow())
+	}
+}
+
+impl OrderSettlement<AccountId, AssetId, Balance> for DummyPool {
+	fn calculate_sell(_asset_in: AssetId, _asset_out: AssetId, amount_in: Balance) -> Option<Balance> {
+		Self::spot_price().map(|(n, d)| amount_in * n / d)
+	}
+
+	fn sell(
+		who: &AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	) -> Result<Balance, DispatchError> {
+		let amount_out = Self::calculate_sell(asset_in, asset_out, amount_in).ok_or("Dummy pool price not set")?;
+		frame_support::ensure!(amount_out >= min_amount_out, "Dummy trading limit reached");
+
+		Tokens::transfer(asset_in, who, &POOL, amount_in)?;
+		Tokens::transfer(asset_out, &POOL, who, amount_out)?;
+
+		Ok(amount_out)
+	}
+
+	fn calculate_sell_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn sell_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	spot_price: Option<(Balance, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(ALICE, KSM, 1_000 * ONE),
+				(BOB, KSM, 1_000 * ONE),
+				(POOL, KSM, 1_000_000 * ONE),
+				(POOL, AUSD, 1_000_000 * ONE),
+			],
+			spot_price: Some((10, 1)),
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_spot_price(mut self, price: Option<(Balance, Balance)>) -> Self {
+		self.spot_price = price;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		set_spot_price(self.spot_price);
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub fn reserved_balance(who: AccountId, asset: AssetId) -> Balance {
+	Tokens::reserved_balance_named(&NamedReserveId::get(), asset, &who)
+}
@@ -0,0 +1,149 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn place_order_should_reserve_amount_and_store_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(LimitOrders::place_order(
+			Origin::signed(ALICE),
+			KSM,
+			AUSD,
+			100 * ONE,
+			1_200 * ONE,
+			Some(10)
+		));
+
+		//Assert
+		assert_eq!(
+			LimitOrders::orders(0).unwrap(),
+			Order {
+				owner: ALICE,
+				asset_in: KSM,
+				asset_out: AUSD,
+				amount_in: 100 * ONE,
+				amount_out: 1_200 * ONE,
+				expires: Some(10),
+			}
+		);
+		assert_eq!(LimitOrders::next_order_id(), 1);
+		assert_eq!(reserved_balance(ALICE, KSM), 100 * ONE);
+		assert_eq!(Tokens::free_balance(KSM, &ALICE), 900 * ONE);
+
+		assert_last_event!(Event::OrderPlaced {
+			order_id: 0,
+			owner: ALICE,
+			asset_in: KSM,
+			asset_out: AUSD,
+			amount_in: 100 * ONE,
+			amount_out: 1_200 * ONE,
+			expires: Some(10),
+		}
+		.into());
+	});
+}
+
+#[test]
+fn place_order_should_assign_new_id_to_each_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LimitOrders::place_order(
+			Origin::signed(ALICE),
+			KSM,
+			AUSD,
+			100 * ONE,
+			1_200 * ONE,
+			None
+		));
+
+		//Act
+		assert_ok!(LimitOrders::place_order(
+			Origin::signed(BOB),
+			KSM,
+			AUSD,
+			50 * ONE,
+			600 * ONE,
+			None
+		));
+
+		//Assert
+		assert_eq!(LimitOrders::orders(1).unwrap().owner, BOB);
+		assert_eq!(LimitOrders::next_order_id(), 2);
+		assert_eq!(reserved_balance(ALICE, KSM), 100 * ONE);
+		assert_eq!(reserved_balance(BOB, KSM), 50 * ONE);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_assets_are_the_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			LimitOrders::place_order(Origin::signed(ALICE), KSM, KSM, 100 * ONE, 100 * ONE, None),
+			Error::<Test>::SameAssets
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_amount_is_lower_than_min_order_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			LimitOrders::place_order(Origin::signed(ALICE), KSM, AUSD, ONE - 1, ONE, None),
+			Error::<Test>::OrderAmountTooLow
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_limit_amount_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			LimitOrders::place_order(Origin::signed(ALICE), KSM, AUSD, 100 * ONE, 0, None),
+			Error::<Test>::InvalidLimit
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_expiry_is_not_in_the_future() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		System::set_block_number(10);
+
+		//Act and assert
+		assert_noop!(
+			LimitOrders::place_order(Origin::signed(ALICE), KSM, AUSD, 100 * ONE, 1_200 * ONE, Some(10)),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_balance_is_too_low() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			LimitOrders::place_order(Origin::signed(ALICE), KSM, AUSD, 1_001 * ONE, 1_200 * ONE, None),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
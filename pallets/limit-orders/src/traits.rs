@@ -0,0 +1,41 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::weights::Weight;
+use sp_runtime::DispatchError;
+
+/// Quotes and trades used to settle the limit orders.
+pub trait OrderSettlement<AccountId, AssetId, Balance> {
+	/// Return amount of `asset_out` received for selling `amount_in` of `asset_in` along the same
+	/// route which is used by `sell`.
+	fn calculate_sell(asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Option<Balance>;
+
+	/// Sell `amount_in` of `asset_in` for `asset_out` and return received amount of `asset_out`.
+	fn sell(
+		who: &AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	) -> Result<Balance, DispatchError>;
+
+	/// Weight of the worst case `calculate_sell`.
+	fn calculate_sell_weight() -> Weight;
+
+	/// Weight of the worst case `sell`.
+	fn sell_weight() -> Weight;
+}
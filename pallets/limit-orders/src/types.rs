@@ -0,0 +1,39 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use primitives::{AssetId, Balance};
+use scale_info::TypeInfo;
+
+pub type OrderId = u32;
+
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Order<AccountId, BlockNumber> {
+	/// User who placed the order
+	pub owner: AccountId,
+	/// Asset sold by the order
+	pub asset_in: AssetId,
+	/// Asset bought by the order
+	pub asset_out: AssetId,
+	/// Remaining amount of `asset_in` to sell
+	pub amount_in: Balance,
+	/// Minimal amount of `asset_out` to receive for the remaining `amount_in`
+	pub amount_out: Balance,
+	/// From this block the order can't be filled
+	pub expires: Option<BlockNumber>,
+}
@@ -0,0 +1,70 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_limit_orders.
+pub trait WeightInfo {
+	fn place_order() -> Weight;
+	fn fill_order() -> Weight;
+	fn cancel_order() -> Weight;
+}
+
+/// Weights for `pallet_limit_orders`.
+impl WeightInfo for () {
+	/// Storage: `LimitOrders::NextOrderId` (r:1 w:1)
+	/// Proof: `LimitOrders::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::Orders` (r:0 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	fn place_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1077`
+		//  Estimated: `4726`
+		// Minimum execution time: 54_318_000 picoseconds.
+		Weight::from_parts(55_024_000, 4726)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LimitOrders::Orders` (r:1 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1544`
+		//  Estimated: `6156`
+		// Minimum execution time: 78_906_000 picoseconds.
+		Weight::from_parts(79_633_000, 6156)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LimitOrders::Orders` (r:1 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1301`
+		//  Estimated: `4726`
+		// Minimum execution time: 49_127_000 picoseconds.
+		Weight::from_parts(49_802_000, 4726)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
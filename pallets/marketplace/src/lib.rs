@@ -450,12 +450,15 @@ impl<T: Config> Pallet<T> {
 		let token_id = (collection_id, item_id);
 
 		Prices::<T>::try_mutate(collection_id, item_id, |price| -> DispatchResult {
+			// Listing is removed on every sale so it can't be bought from the new owner for the old price
+			let listed_price = price.take();
+
 			let mut price = if is_offer {
 				Offers::<T>::get(token_id, buyer.clone())
 					.map(|o| o.amount)
 					.ok_or(Error::<T>::UnknownOffer)?
 			} else {
-				price.take().ok_or(Error::<T>::NotForSale)?
+				listed_price.ok_or(Error::<T>::NotForSale)?
 			};
 
 			// Settle royalty if set
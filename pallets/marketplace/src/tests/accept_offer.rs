@@ -298,3 +298,38 @@ fn buy_should_set_price_to_none_when_offer_accepted() {
 			assert_eq!(Market::prices(COLLECTION_ID_0, ITEM_ID_0), None);
 		});
 }
+
+#[test]
+fn accept_offer_should_remove_listing_when_token_is_listed() {
+	// arrange
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 2_000_000 * UNITS), (DAVE, 2_000_000 * UNITS)])
+		.with_minted_nft((ALICE, COLLECTION_ID_0, ITEM_ID_0))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Market::set_price(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				ITEM_ID_0,
+				Some(100 * UNITS)
+			));
+			assert_ok!(Market::make_offer(
+				Origin::signed(DAVE),
+				COLLECTION_ID_0,
+				ITEM_ID_0,
+				50 * UNITS,
+				1000000
+			));
+
+			// Act
+			assert_ok!(Market::accept_offer(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				ITEM_ID_0,
+				DAVE
+			));
+
+			// Assert
+			assert_eq!(Market::prices(COLLECTION_ID_0, ITEM_ID_0), None);
+		});
+}
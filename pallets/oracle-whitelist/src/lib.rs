@@ -0,0 +1,345 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Oracle whitelist pallet
+//!
+//! ## Overview
+//!
+//! This pallet manages the pairs tracked by the EMA oracle. Oracle accumulates at most
+//! `MaxEntries` unique entries in a block and entries which don't fit are dropped, so the tracked
+//! pairs are limited to the pairs whitelisted by the governance.
+//!
+//! Whitelisted pairs have a priority. `ReservedPriorityEntries` of the oracle's entries are
+//! reserved for the pairs with `High` priority, so the pairs used for fee payment or farm pricing
+//! are not crowded out by the regular pairs. Pairs provided by `PriorityPairs` are tracked with
+//! `High` priority without being whitelisted.
+//!
+//! Pairs which are expensive to derive from the chain state, e.g. the hops of the stored routes,
+//! are provided by `DerivedPriorityPairs`. They are derived by `refresh_priority_pairs` and stored
+//! in `StoredPriorityPairs`, so checking them is a single storage read. Anyone can refresh them
+//! when the chain state they are derived from changes.
+//!
+//! The whitelist check (`Contains`) has no side effects. Entries which are dropped because there is
+//! no room left for them in the oracle are counted in `DroppedEntries` and reported by the
+//! `EntryDropped` event by `note_entry`, which is called by the oracle ingestion adapter before the
+//! entry is handed over to the oracle.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, traits::Contains};
+use frame_system::pallet_prelude::*;
+use hydradx_traits::Source;
+use primitives::AssetId;
+
+pub use traits::{DerivedPriorityPairs, OracleAccumulator};
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod tests;
+
+pub mod traits;
+pub mod types;
+pub mod weights;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to manage the whitelist.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Pairs tracked with `High` priority without being whitelisted. Check of every pair has to be
+		/// a single storage read.
+		type PriorityPairs: Contains<(Source, AssetId, AssetId)>;
+
+		/// Pairs tracked with `High` priority without being whitelisted, which are stored by
+		/// `refresh_priority_pairs`.
+		type DerivedPriorityPairs: DerivedPriorityPairs;
+
+		/// Entries accumulated by the oracle in the current block.
+		type Accumulator: OracleAccumulator;
+
+		/// Max number of the unique entries accumulated by the oracle in one block.
+		#[pallet::constant]
+		type MaxEntries: Get<u32>;
+
+		/// Number of the oracle's entries reserved for the pairs with `High` priority.
+		#[pallet::constant]
+		type ReservedPriorityEntries: Get<u32>;
+
+		/// Max number of the whitelisted pairs.
+		#[pallet::constant]
+		type MaxWhitelistedPairs: Get<u32>;
+
+		/// Max number of the stored derived priority pairs.
+		#[pallet::constant]
+		type MaxDerivedPriorityPairs: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Pairs tracked by the oracle and their priority. Assets of the pair are ordered by the asset id.
+	#[pallet::storage]
+	#[pallet::getter(fn whitelisted_pairs)]
+	pub type WhitelistedPairs<T: Config> =
+		CountedStorageMap<_, Blake2_128Concat, (Source, (AssetId, AssetId)), PairPriority, OptionQuery>;
+
+	/// Derived priority pairs stored by `refresh_priority_pairs`. Assets of the pair are ordered by the
+	/// asset id.
+	#[pallet::storage]
+	#[pallet::getter(fn stored_priority_pairs)]
+	pub type StoredPriorityPairs<T: Config> =
+		StorageValue<_, BoundedBTreeSet<(Source, (AssetId, AssetId)), T::MaxDerivedPriorityPairs>, ValueQuery>;
+
+	/// Number of the oracle entries dropped because there was no room left for them.
+	#[pallet::storage]
+	#[pallet::getter(fn dropped_entries)]
+	pub type DroppedEntries<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Pair was whitelisted or its priority was changed.
+		PairWhitelisted {
+			source: Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			priority: PairPriority,
+		},
+		/// Pair was removed from the whitelist.
+		PairRemoved {
+			source: Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+		},
+		/// Derived priority pairs were refreshed.
+		PriorityPairsRefreshed { count: u32 },
+		/// Oracle entry of the whitelisted pair was dropped because there was no room left for it.
+		EntryDropped {
+			source: Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Assets of the pair are the same.
+		SameAssets,
+		/// Whitelist is full.
+		TooManyPairs,
+		/// Pair is not whitelisted.
+		PairNotWhitelisted,
+		/// Derived priority pairs can't be derived or don't fit into `MaxDerivedPriorityPairs`.
+		TooManyPriorityPairs,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Whitelist the pair for the oracle or change the priority of the whitelisted pair.
+		///
+		/// Parameters:
+		/// - `origin`: `UpdateOrigin`
+		/// - `source`: source of the oracle entries of the pair
+		/// - `asset_a`, `asset_b`: assets of the pair in any order
+		/// - `priority`: priority of the pair
+		///
+		/// Emits `PairWhitelisted` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::whitelist_pair())]
+		pub fn whitelist_pair(
+			origin: OriginFor<T>,
+			source: Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			priority: PairPriority,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::SameAssets);
+
+			let key = (source, ordered_pair(asset_a, asset_b));
+			ensure!(
+				WhitelistedPairs::<T>::contains_key(key)
+					|| WhitelistedPairs::<T>::count() < T::MaxWhitelistedPairs::get(),
+				Error::<T>::TooManyPairs
+			);
+
+			WhitelistedPairs::<T>::insert(key, priority);
+
+			Self::deposit_event(Event::PairWhitelisted {
+				source,
+				asset_a,
+				asset_b,
+				priority,
+			});
+
+			Ok(())
+		}
+
+		/// Remove the pair from the whitelist. Oracle entries of the pair are kept but not updated.
+		///
+		/// Parameters:
+		/// - `origin`: `UpdateOrigin`
+		/// - `source`: source of the oracle entries of the pair
+		/// - `asset_a`, `asset_b`: assets of the pair in any order
+		///
+		/// Emits `PairRemoved` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_pair())]
+		pub fn remove_pair(origin: OriginFor<T>, source: Source, asset_a: AssetId, asset_b: AssetId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let key = (source, ordered_pair(asset_a, asset_b));
+			ensure!(WhitelistedPairs::<T>::contains_key(key), Error::<T>::PairNotWhitelisted);
+
+			WhitelistedPairs::<T>::remove(key);
+
+			Self::deposit_event(Event::PairRemoved {
+				source,
+				asset_a,
+				asset_b,
+			});
+
+			Ok(())
+		}
+
+		/// Derive the priority pairs provided by `DerivedPriorityPairs` and store them. Previously
+		/// stored pairs are replaced.
+		///
+		/// Pairs are derived from the chain state, so anyone can refresh them when the chain state
+		/// changes, e.g. when a route used by the pairs is changed.
+		///
+		/// Parameters:
+		/// - `origin`: any signed account
+		///
+		/// Emits `PriorityPairsRefreshed` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::refresh_priority_pairs()
+			.saturating_add(T::DerivedPriorityPairs::derive_weight()))]
+		pub fn refresh_priority_pairs(origin: OriginFor<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let derived_pairs = T::DerivedPriorityPairs::derive().ok_or(Error::<T>::TooManyPriorityPairs)?;
+
+			let mut pairs = BoundedBTreeSet::new();
+			for (source, asset_a, asset_b) in derived_pairs {
+				pairs
+					.try_insert((source, ordered_pair(asset_a, asset_b)))
+					.map_err(|_| Error::<T>::TooManyPriorityPairs)?;
+			}
+
+			let count = pairs.len() as u32;
+			StoredPriorityPairs::<T>::put(pairs);
+
+			Self::deposit_event(Event::PriorityPairsRefreshed { count });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Priority of the pair or `None` if the pair is not tracked.
+	pub fn pair_priority(source: Source, asset_a: AssetId, asset_b: AssetId) -> Option<PairPriority> {
+		let key = (source, ordered_pair(asset_a, asset_b));
+
+		if T::PriorityPairs::contains(&(source, asset_a, asset_b)) || StoredPriorityPairs::<T>::get().contains(&key) {
+			return Some(PairPriority::High);
+		}
+
+		WhitelistedPairs::<T>::get(key)
+	}
+
+	/// Weight of the whitelist check done by the oracle for every ingested entry.
+	pub fn contains_weight() -> Weight {
+		// priority pairs, stored priority pairs, whitelist and the accumulator
+		T::DbWeight::get().reads(5)
+	}
+
+	/// Check the entry about to be ingested into the oracle. Entry of the tracked pair which is not
+	/// accepted by the whitelist is counted in `DroppedEntries` and reported by `EntryDropped`.
+	///
+	/// Returns true if the entry is accepted.
+	pub fn note_entry(source: Source, asset_a: AssetId, asset_b: AssetId) -> bool {
+		let Some(priority) = Self::pair_priority(source, asset_a, asset_b) else {
+			return false;
+		};
+
+		if Self::has_room_for(source, asset_a, asset_b, priority) {
+			return true;
+		}
+
+		DroppedEntries::<T>::mutate(|count| *count = count.saturating_add(1));
+
+		Self::deposit_event(Event::EntryDropped {
+			source,
+			asset_a,
+			asset_b,
+		});
+
+		false
+	}
+
+	/// Weight of `note_entry`.
+	pub fn note_entry_weight() -> Weight {
+		T::DbWeight::get().reads_writes(5, 1)
+	}
+
+	fn has_room_for(source: Source, asset_a: AssetId, asset_b: AssetId, priority: PairPriority) -> bool {
+		if T::Accumulator::contains(source, asset_a, asset_b) {
+			return true;
+		}
+
+		let available_entries = match priority {
+			PairPriority::High => T::MaxEntries::get(),
+			PairPriority::Normal => T::MaxEntries::get().saturating_sub(T::ReservedPriorityEntries::get()),
+		};
+
+		T::Accumulator::entries_count() < available_entries
+	}
+}
+
+/// Oracle whitelist. Entry of the whitelisted pair is accepted if the pair is already accumulated in
+/// the current block or there is room left for it. Regular pairs can't use the reserved entries.
+impl<T: Config> Contains<(Source, AssetId, AssetId)> for Pallet<T> {
+	fn contains((source, asset_a, asset_b): &(Source, AssetId, AssetId)) -> bool {
+		Self::pair_priority(*source, *asset_a, *asset_b)
+			.is_some_and(|priority| Self::has_room_for(*source, *asset_a, *asset_b, priority))
+	}
+}
+
+fn ordered_pair(asset_a: AssetId, asset_b: AssetId) -> (AssetId, AssetId) {
+	if asset_a < asset_b {
+		(asset_a, asset_b)
+	} else {
+		(asset_b, asset_a)
+	}
+}
@@ -0,0 +1,141 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn contains_should_return_false_when_pair_is_not_whitelisted() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert!(!OracleWhitelist::contains(&(XYK, BSX, KSM)));
+			assert!(!OracleWhitelist::contains(&(LBP, KSM, AUSD)));
+			assert_eq!(OracleWhitelist::dropped_entries(), 0);
+		});
+}
+
+#[test]
+fn contains_should_return_true_when_pair_is_whitelisted() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert!(OracleWhitelist::contains(&(XYK, KSM, AUSD)));
+			assert!(OracleWhitelist::contains(&(XYK, AUSD, KSM)));
+		});
+}
+
+#[test]
+fn contains_should_return_true_when_pair_is_priority_pair() {
+	ExtBuilder::default()
+		.with_priority_pair(XYK, BSX, KSM)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert!(OracleWhitelist::contains(&(XYK, KSM, BSX)));
+			assert_eq!(OracleWhitelist::pair_priority(XYK, KSM, BSX), Some(PairPriority::High));
+		});
+}
+
+#[test]
+fn contains_should_return_false_for_normal_pair_when_only_reserved_entries_are_left() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			accumulate(XYK, BSX, KSM);
+			accumulate(XYK, BSX, AUSD);
+
+			//Act and assert
+			assert!(!OracleWhitelist::contains(&(XYK, KSM, AUSD)));
+		});
+}
+
+#[test]
+fn contains_should_accept_high_priority_pair_when_only_reserved_entries_are_left() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::High)
+		.with_priority_pair(XYK, BSX, DOT)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			accumulate(XYK, BSX, KSM);
+			accumulate(XYK, BSX, AUSD);
+
+			//Act and assert
+			assert!(OracleWhitelist::contains(&(XYK, KSM, AUSD)));
+			assert!(OracleWhitelist::contains(&(XYK, BSX, DOT)));
+			assert_eq!(OracleWhitelist::dropped_entries(), 0);
+		});
+}
+
+#[test]
+fn contains_should_return_false_for_high_priority_pair_when_oracle_is_full() {
+	ExtBuilder::default()
+		.with_priority_pair(XYK, BSX, DOT)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			accumulate(XYK, BSX, KSM);
+			accumulate(XYK, BSX, AUSD);
+			accumulate(XYK, KSM, AUSD);
+
+			//Act and assert
+			assert!(!OracleWhitelist::contains(&(XYK, BSX, DOT)));
+		});
+}
+
+#[test]
+fn contains_should_not_count_dropped_entries() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			accumulate(XYK, BSX, KSM);
+			accumulate(XYK, BSX, AUSD);
+			accumulate(XYK, BSX, DOT);
+
+			//Act
+			assert!(!OracleWhitelist::contains(&(XYK, KSM, AUSD)));
+
+			//Assert
+			assert_eq!(OracleWhitelist::dropped_entries(), 0);
+			assert!(System::events().is_empty());
+		});
+}
+
+#[test]
+fn contains_should_return_true_when_pair_is_already_accumulated_and_oracle_is_full() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			accumulate(XYK, BSX, KSM);
+			accumulate(XYK, BSX, AUSD);
+			accumulate(XYK, AUSD, KSM);
+
+			//Act and assert
+			assert!(OracleWhitelist::contains(&(XYK, KSM, AUSD)));
+			assert_eq!(OracleWhitelist::dropped_entries(), 0);
+		});
+}
@@ -0,0 +1,207 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_oracle_whitelist;
+use crate::*;
+
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Everything},
+};
+use frame_system::{self as system, EnsureRoot};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use std::{cell::RefCell, collections::HashSet};
+
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+
+pub const BSX: AssetId = 0;
+pub const KSM: AssetId = 1;
+pub const AUSD: AssetId = 2;
+pub const DOT: AssetId = 3;
+
+pub const XYK: Source = *b"snek/xyk";
+pub const LBP: Source = *b"snek/lbp";
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		OracleWhitelist: pallet_oracle_whitelist,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+parameter_types! {
+	pub const MaxEntries: u32 = 3;
+	pub const ReservedPriorityEntries: u32 = 1;
+	pub const MaxWhitelistedPairs: u32 = 3;
+	pub const MaxDerivedPriorityPairs: u32 = 2;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type PriorityPairs = DummyPriorityPairs;
+	type DerivedPriorityPairs = DummyDerivedPriorityPairs;
+	type Accumulator = DummyAccumulator;
+	type MaxEntries = MaxEntries;
+	type ReservedPriorityEntries = ReservedPriorityEntries;
+	type MaxWhitelistedPairs = MaxWhitelistedPairs;
+	type MaxDerivedPriorityPairs = MaxDerivedPriorityPairs;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	pub static ACCUMULATOR: RefCell<HashSet<(Source, (AssetId, AssetId))>> = RefCell::new(HashSet::default());
+	pub static PRIORITY_PAIRS: RefCell<HashSet<(Source, (AssetId, AssetId))>> = RefCell::new(HashSet::default());
+	pub static DERIVED_PRIORITY_PAIRS: RefCell<Option<Vec<(Source, AssetId, AssetId)>>> = RefCell::new(Some(Vec::new()));
+}
+
+/// Sets pairs returned by the dummy derived priority pairs. `None` means the pairs can't be derived.
+pub fn set_derived_priority_pairs(pairs: Option<Vec<(Source, AssetId, AssetId)>>) {
+	DERIVED_PRIORITY_PAIRS.with(|v| *v.borrow_mut() = pairs);
+}
+
+/// Adds entry of the pair to the dummy accumulator.
+pub fn accumulate(source: Source, asset_a: AssetId, asset_b: AssetId) {
+	ACCUMULATOR.with(|v| v.borrow_mut().insert((source, ordered_pair(asset_a, asset_b))));
+}
+
+/// Dummy accumulator containing the entries added by `accumulate`.
+pub struct DummyAccumulator;
+
+impl OracleAccumulator for DummyAccumulator {
+	fn entries_count() -> u32 {
+		ACCUMULATOR.with(|v| v.borrow().len() as u32)
+	}
+
+	fn contains(source: Source, asset_a: AssetId, asset_b: AssetId) -> bool {
+		ACCUMULATOR.with(|v| v.borrow().contains(&(source, ordered_pair(asset_a, asset_b))))
+	}
+}
+
+/// Dummy priority pairs set by `ExtBuilder::with_priority_pair`.
+pub struct DummyPriorityPairs;
+
+impl Contains<(Source, AssetId, AssetId)> for DummyPriorityPairs {
+	fn contains((source, asset_a, asset_b): &(Source, AssetId, AssetId)) -> bool {
+		PRIORITY_PAIRS.with(|v| v.borrow().contains(&(*source, ordered_pair(*asset_a, *asset_b))))
+	}
+}
+
+/// Dummy derived priority pairs set by `set_derived_priority_pairs`.
+pub struct DummyDerivedPriorityPairs;
+
+impl DerivedPriorityPairs for DummyDerivedPriorityPairs {
+	fn derive() -> Option<Vec<(Source, AssetId, AssetId)>> {
+		DERIVED_PRIORITY_PAIRS.with(|v| v.borrow().clone())
+	}
+
+	fn derive_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	whitelisted_pairs: Vec<(Source, AssetId, AssetId, PairPriority)>,
+	priority_pairs: Vec<(Source, AssetId, AssetId)>,
+}
+
+impl ExtBuilder {
+	pub fn with_whitelisted_pair(
+		mut self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		priority: PairPriority,
+	) -> Self {
+		self.whitelisted_pairs.push((source, asset_a, asset_b, priority));
+		self
+	}
+
+	pub fn with_priority_pair(mut self, source: Source, asset_a: AssetId, asset_b: AssetId) -> Self {
+		self.priority_pairs.push((source, asset_a, asset_b));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		ACCUMULATOR.with(|v| v.borrow_mut().clear());
+		set_derived_priority_pairs(Some(Vec::new()));
+		PRIORITY_PAIRS.with(|v| {
+			let mut pairs = v.borrow_mut();
+			pairs.clear();
+			for (source, asset_a, asset_b) in self.priority_pairs {
+				pairs.insert((source, ordered_pair(asset_a, asset_b)));
+			}
+		});
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			for (source, asset_a, asset_b, priority) in self.whitelisted_pairs {
+				WhitelistedPairs::<Test>::insert((source, ordered_pair(asset_a, asset_b)), priority);
+			}
+		});
+		ext
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn note_entry_should_return_false_when_pair_is_not_tracked() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert!(!OracleWhitelist::note_entry(XYK, KSM, AUSD));
+		assert_eq!(OracleWhitelist::dropped_entries(), 0);
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn note_entry_should_return_true_when_there_is_room_for_the_entry() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			accumulate(XYK, BSX, KSM);
+
+			//Act and assert
+			assert!(OracleWhitelist::note_entry(XYK, KSM, AUSD));
+			assert_eq!(OracleWhitelist::dropped_entries(), 0);
+		});
+}
+
+#[test]
+fn note_entry_should_count_dropped_entry_when_only_reserved_entries_are_left() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			accumulate(XYK, BSX, KSM);
+			accumulate(XYK, BSX, AUSD);
+
+			//Act
+			assert!(!OracleWhitelist::note_entry(XYK, KSM, AUSD));
+
+			//Assert
+			assert_eq!(OracleWhitelist::dropped_entries(), 1);
+			assert_last_event!(Event::EntryDropped {
+				source: XYK,
+				asset_a: KSM,
+				asset_b: AUSD,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn note_entry_should_count_dropped_entry_of_priority_pair_when_oracle_is_full() {
+	ExtBuilder::default()
+		.with_priority_pair(XYK, BSX, DOT)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			accumulate(XYK, BSX, KSM);
+			accumulate(XYK, BSX, AUSD);
+			accumulate(XYK, KSM, AUSD);
+
+			//Act
+			assert!(!OracleWhitelist::note_entry(XYK, BSX, DOT));
+			assert!(!OracleWhitelist::note_entry(XYK, DOT, BSX));
+
+			//Assert
+			assert_eq!(OracleWhitelist::dropped_entries(), 2);
+		});
+}
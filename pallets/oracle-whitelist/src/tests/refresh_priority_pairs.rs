@@ -0,0 +1,116 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn refresh_priority_pairs_should_store_derived_pairs() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_derived_priority_pairs(Some(vec![(XYK, KSM, BSX), (LBP, AUSD, DOT)]));
+
+		//Act
+		assert_ok!(OracleWhitelist::refresh_priority_pairs(Origin::signed(ALICE)));
+
+		//Assert
+		assert_eq!(
+			OracleWhitelist::stored_priority_pairs().into_iter().collect::<Vec<_>>(),
+			vec![(LBP, (AUSD, DOT)), (XYK, (BSX, KSM))]
+		);
+		assert_eq!(OracleWhitelist::pair_priority(XYK, BSX, KSM), Some(PairPriority::High));
+		assert_eq!(OracleWhitelist::pair_priority(LBP, DOT, AUSD), Some(PairPriority::High));
+		assert!(OracleWhitelist::contains(&(XYK, KSM, BSX)));
+
+		assert_last_event!(Event::PriorityPairsRefreshed { count: 2 }.into());
+	});
+}
+
+#[test]
+fn refresh_priority_pairs_should_replace_previously_stored_pairs() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_derived_priority_pairs(Some(vec![(XYK, KSM, BSX)]));
+		assert_ok!(OracleWhitelist::refresh_priority_pairs(Origin::signed(ALICE)));
+
+		set_derived_priority_pairs(Some(vec![(XYK, AUSD, BSX)]));
+
+		//Act
+		assert_ok!(OracleWhitelist::refresh_priority_pairs(Origin::signed(ALICE)));
+
+		//Assert
+		assert_eq!(
+			OracleWhitelist::stored_priority_pairs().into_iter().collect::<Vec<_>>(),
+			vec![(XYK, (BSX, AUSD))]
+		);
+		assert_eq!(OracleWhitelist::pair_priority(XYK, KSM, BSX), None);
+		assert_eq!(OracleWhitelist::pair_priority(XYK, AUSD, BSX), Some(PairPriority::High));
+	});
+}
+
+#[test]
+fn refresh_priority_pairs_should_store_duplicate_pairs_once() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_derived_priority_pairs(Some(vec![(XYK, KSM, BSX), (XYK, BSX, KSM), (XYK, AUSD, BSX)]));
+
+		//Act
+		assert_ok!(OracleWhitelist::refresh_priority_pairs(Origin::signed(ALICE)));
+
+		//Assert
+		assert_eq!(OracleWhitelist::stored_priority_pairs().len(), 2);
+		assert_last_event!(Event::PriorityPairsRefreshed { count: 2 }.into());
+	});
+}
+
+#[test]
+fn refresh_priority_pairs_should_fail_when_derived_pairs_do_not_fit_into_storage() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_derived_priority_pairs(Some(vec![(XYK, KSM, BSX), (XYK, AUSD, BSX), (XYK, DOT, BSX)]));
+
+		//Act and assert
+		assert_noop!(
+			OracleWhitelist::refresh_priority_pairs(Origin::signed(ALICE)),
+			Error::<Test>::TooManyPriorityPairs
+		);
+	});
+}
+
+#[test]
+fn refresh_priority_pairs_should_fail_when_pairs_can_not_be_derived() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_derived_priority_pairs(None);
+
+		//Act and assert
+		assert_noop!(
+			OracleWhitelist::refresh_priority_pairs(Origin::signed(ALICE)),
+			Error::<Test>::TooManyPriorityPairs
+		);
+	});
+}
+
+#[test]
+fn refresh_priority_pairs_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			OracleWhitelist::refresh_priority_pairs(Origin::none()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
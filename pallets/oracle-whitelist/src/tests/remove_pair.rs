@@ -0,0 +1,68 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn remove_pair_should_remove_pair_when_assets_are_in_any_order() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::High)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(OracleWhitelist::remove_pair(Origin::root(), XYK, AUSD, KSM));
+
+			//Assert
+			assert_eq!(OracleWhitelist::whitelisted_pairs((XYK, (KSM, AUSD))), None);
+			assert_eq!(WhitelistedPairs::<Test>::count(), 0);
+
+			assert_last_event!(Event::PairRemoved {
+				source: XYK,
+				asset_a: AUSD,
+				asset_b: KSM,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn remove_pair_should_fail_when_pair_is_not_whitelisted() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				OracleWhitelist::remove_pair(Origin::root(), LBP, KSM, AUSD),
+				Error::<Test>::PairNotWhitelisted
+			);
+		});
+}
+
+#[test]
+fn remove_pair_should_fail_when_origin_is_not_update_origin() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				OracleWhitelist::remove_pair(Origin::signed(ALICE), XYK, KSM, AUSD),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+}
@@ -0,0 +1,163 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn whitelist_pair_should_store_pair_with_ordered_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(OracleWhitelist::whitelist_pair(
+			Origin::root(),
+			XYK,
+			AUSD,
+			KSM,
+			PairPriority::Normal
+		));
+
+		//Assert
+		assert_eq!(
+			OracleWhitelist::whitelisted_pairs((XYK, (KSM, AUSD))),
+			Some(PairPriority::Normal)
+		);
+		assert_eq!(OracleWhitelist::whitelisted_pairs((XYK, (AUSD, KSM))), None);
+		assert_eq!(WhitelistedPairs::<Test>::count(), 1);
+
+		assert_last_event!(Event::PairWhitelisted {
+			source: XYK,
+			asset_a: AUSD,
+			asset_b: KSM,
+			priority: PairPriority::Normal,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn whitelist_pair_should_change_priority_when_pair_is_already_whitelisted() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(OracleWhitelist::whitelist_pair(
+				Origin::root(),
+				XYK,
+				KSM,
+				AUSD,
+				PairPriority::High
+			));
+
+			//Assert
+			assert_eq!(
+				OracleWhitelist::whitelisted_pairs((XYK, (KSM, AUSD))),
+				Some(PairPriority::High)
+			);
+			assert_eq!(WhitelistedPairs::<Test>::count(), 1);
+		});
+}
+
+#[test]
+fn whitelist_pair_should_store_pairs_of_different_sources_separately() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, KSM, AUSD, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(OracleWhitelist::whitelist_pair(
+				Origin::root(),
+				LBP,
+				KSM,
+				AUSD,
+				PairPriority::High
+			));
+
+			//Assert
+			assert_eq!(
+				OracleWhitelist::whitelisted_pairs((XYK, (KSM, AUSD))),
+				Some(PairPriority::Normal)
+			);
+			assert_eq!(
+				OracleWhitelist::whitelisted_pairs((LBP, (KSM, AUSD))),
+				Some(PairPriority::High)
+			);
+			assert_eq!(WhitelistedPairs::<Test>::count(), 2);
+		});
+}
+
+#[test]
+fn whitelist_pair_should_fail_when_whitelist_is_full() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, BSX, KSM, PairPriority::Normal)
+		.with_whitelisted_pair(XYK, BSX, AUSD, PairPriority::Normal)
+		.with_whitelisted_pair(XYK, BSX, DOT, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				OracleWhitelist::whitelist_pair(Origin::root(), XYK, KSM, AUSD, PairPriority::Normal),
+				Error::<Test>::TooManyPairs
+			);
+		});
+}
+
+#[test]
+fn whitelist_pair_should_change_priority_when_whitelist_is_full() {
+	ExtBuilder::default()
+		.with_whitelisted_pair(XYK, BSX, KSM, PairPriority::Normal)
+		.with_whitelisted_pair(XYK, BSX, AUSD, PairPriority::Normal)
+		.with_whitelisted_pair(XYK, BSX, DOT, PairPriority::Normal)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(OracleWhitelist::whitelist_pair(
+				Origin::root(),
+				XYK,
+				DOT,
+				BSX,
+				PairPriority::High
+			));
+
+			//Assert
+			assert_eq!(
+				OracleWhitelist::whitelisted_pairs((XYK, (BSX, DOT))),
+				Some(PairPriority::High)
+			);
+		});
+}
+
+#[test]
+fn whitelist_pair_should_fail_when_assets_are_the_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			OracleWhitelist::whitelist_pair(Origin::root(), XYK, KSM, KSM, PairPriority::Normal),
+			Error::<Test>::SameAssets
+		);
+	});
+}
+
+#[test]
+fn whitelist_pair_should_fail_when_origin_is_not_update_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			OracleWhitelist::whitelist_pair(Origin::signed(ALICE), XYK, KSM, AUSD, PairPriority::Normal),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
@@ -0,0 +1,40 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::weights::Weight;
+use hydradx_traits::Source;
+use primitives::AssetId;
+use sp_std::vec::Vec;
+
+/// Entries accumulated by the oracle in the current block.
+pub trait OracleAccumulator {
+	/// Return number of the accumulated entries.
+	fn entries_count() -> u32;
+
+	/// Return true if the entry of the pair is accumulated. Order of the assets doesn't matter.
+	fn contains(source: Source, asset_a: AssetId, asset_b: AssetId) -> bool;
+}
+
+/// Pairs tracked with `High` priority which are derived from the chain state. Deriving the pairs is
+/// too expensive to be done for every oracle entry, so the pairs are stored by the pallet.
+pub trait DerivedPriorityPairs {
+	/// Return the derived pairs or `None` if they can't be derived within `derive_weight`.
+	fn derive() -> Option<Vec<(Source, AssetId, AssetId)>>;
+
+	/// Weight of `derive`.
+	fn derive_weight() -> Weight;
+}
@@ -0,0 +1,52 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_oracle_whitelist.
+pub trait WeightInfo {
+	fn whitelist_pair() -> Weight;
+	fn remove_pair() -> Weight;
+	fn refresh_priority_pairs() -> Weight;
+}
+
+/// Weights for `pallet_oracle_whitelist`.
+impl WeightInfo for () {
+	/// Storage: `OracleWhitelist::WhitelistedPairs` (r:1 w:1)
+	/// Proof: `OracleWhitelist::WhitelistedPairs` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `OracleWhitelist::CounterForWhitelistedPairs` (r:1 w:1)
+	/// Proof: `OracleWhitelist::CounterForWhitelistedPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn whitelist_pair() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3506`
+		// Minimum execution time: 17_612_000 picoseconds.
+		Weight::from_parts(18_104_000, 3506)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleWhitelist::WhitelistedPairs` (r:1 w:1)
+	/// Proof: `OracleWhitelist::WhitelistedPairs` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `OracleWhitelist::CounterForWhitelistedPairs` (r:1 w:1)
+	/// Proof: `OracleWhitelist::CounterForWhitelistedPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn remove_pair() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115`
+		//  Estimated: `3506`
+		// Minimum execution time: 19_331_000 picoseconds.
+		Weight::from_parts(19_858_000, 3506)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// NOTE: not generated by the `refresh_priority_pairs` benchmark yet, bounded by the weight of
+	/// `whitelist_pair` which reads and writes more storage items.
+	fn refresh_priority_pairs() -> Weight {
+		Self::whitelist_pair()
+	}
+}
@@ -0,0 +1,37 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the trade stats.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_trade_stats::TradeStats;
+use primitives::AssetId;
+
+sp_api::decl_runtime_apis! {
+	/// API to query volume and fees of the traded pairs.
+	pub trait TradeStatsApi<BlockNumber>
+	where
+		BlockNumber: Codec,
+	{
+		/// Return volume and fees of the pair traded in the last `window` blocks. Stats are aggregated
+		/// per bucket of blocks, so the oldest bucket of the window is always included whole.
+		/// Volume and fees of the first asset of the stats belong to `asset_a`.
+		fn pair_stats(asset_a: AssetId, asset_b: AssetId, window: BlockNumber) -> TradeStats;
+	}
+}
@@ -0,0 +1,153 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Trade stats pallet
+//!
+//! ## Overview
+//!
+//! This pallet accumulates volume and fees of the traded pairs over rolling windows, so they can be
+//! queried through the runtime API without running an indexer.
+//!
+//! Swaps are recorded by `note_swap` when they are executed, so the AMM hooks calling it are
+//! charged `note_swap_weight` for every swap. Stats of every pair are aggregated into buckets of
+//! `BucketLength` blocks and only the last `MaxBuckets` buckets are kept, so the longest window
+//! available is `BucketLength * MaxBuckets` blocks.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use primitives::AssetId;
+use sp_runtime::traits::{One, Saturating, UniqueSaturatedInto};
+
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+pub mod weights;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Number of blocks aggregated in one bucket.
+		#[pallet::constant]
+		type BucketLength: Get<BlockNumberFor<Self>>;
+
+		/// Max number of the buckets kept for every pair.
+		#[pallet::constant]
+		type MaxBuckets: Get<u32>;
+
+		/// Weight information for the functions of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Trade stats of the pairs aggregated into buckets of `BucketLength` blocks, oldest first.
+	/// Assets of the pair are ordered by the asset id.
+	#[pallet::storage]
+	#[pallet::getter(fn buckets)]
+	pub type PairStats<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(AssetId, AssetId),
+		BoundedVec<(BucketId, TradeStats), T::MaxBuckets>,
+		ValueQuery,
+	>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Id of the bucket the block belongs to.
+	pub fn bucket_id(block: BlockNumberFor<T>) -> BucketId {
+		(block / T::BucketLength::get().max(One::one())).unique_saturated_into()
+	}
+
+	/// Add the swap executed in the current block to the stats of the traded pair.
+	pub fn note_swap(swap: &Swap) {
+		Self::record_swap(Self::bucket_id(frame_system::Pallet::<T>::block_number()), swap);
+	}
+
+	/// Weight of `note_swap`.
+	pub fn note_swap_weight() -> Weight {
+		T::WeightInfo::note_swap()
+	}
+
+	/// Add the swap to the stats of the traded pair in the bucket and drop the buckets which are
+	/// too old to be kept.
+	pub fn record_swap(bucket_id: BucketId, swap: &Swap) {
+		if swap.asset_in == swap.asset_out {
+			return;
+		}
+
+		let stats = TradeStats::from_swap(swap);
+		let (pair, stats) = if swap.asset_in < swap.asset_out {
+			((swap.asset_in, swap.asset_out), stats)
+		} else {
+			((swap.asset_out, swap.asset_in), stats.inverted())
+		};
+
+		let max_buckets = T::MaxBuckets::get();
+		PairStats::<T>::mutate(pair, |buckets| {
+			buckets.retain(|(id, _)| id.saturating_add(max_buckets) > bucket_id);
+			match buckets.last_mut() {
+				Some((id, last)) if *id == bucket_id => last.accumulate(&stats),
+				_ => {
+					// can't overflow because only `MaxBuckets - 1` older buckets are retained
+					let _ = buckets.try_push((bucket_id, stats));
+				}
+			}
+		});
+	}
+
+	/// Volume and fees of the pair traded in the last `window` blocks. Oldest bucket of the window
+	/// is included whole. Volume and fees of the first asset of the stats belong to `asset_a`.
+	pub fn pair_stats(asset_a: AssetId, asset_b: AssetId, window: BlockNumberFor<T>) -> TradeStats {
+		let now = frame_system::Pallet::<T>::block_number();
+		let first_bucket_id = Self::bucket_id(now.saturating_sub(window).saturating_add(One::one()));
+
+		let (pair, inverted) = if asset_a < asset_b {
+			((asset_a, asset_b), false)
+		} else {
+			((asset_b, asset_a), true)
+		};
+
+		let stats = PairStats::<T>::get(pair)
+			.iter()
+			.filter(|(id, _)| *id >= first_bucket_id)
+			.fold(TradeStats::default(), |mut total, (_, stats)| {
+				total.accumulate(stats);
+				total
+			});
+
+		if inverted {
+			stats.inverted()
+		} else {
+			stats
+		}
+	}
+}
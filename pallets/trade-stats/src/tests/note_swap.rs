@@ -0,0 +1,153 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn note_swap_should_record_swaps_of_the_block_into_the_current_bucket() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		swap(BSX, KSM, 10 * ONE, 5 * ONE, ONE / 100);
+		swap(BSX, KSM, 20 * ONE, 9 * ONE, ONE / 50);
+
+		//Assert
+		assert_eq!(
+			TradeStats::buckets((BSX, KSM)).to_vec(),
+			vec![(
+				0,
+				crate::TradeStats {
+					swaps: 2,
+					volume_a: 30 * ONE,
+					volume_b: 14 * ONE,
+					fee_a: 0,
+					fee_b: 3 * ONE / 100,
+				}
+			)]
+		);
+	});
+}
+
+#[test]
+fn note_swap_should_record_swaps_under_ordered_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		swap(AUSD, KSM, 10 * ONE, 5 * ONE, ONE / 100);
+
+		//Assert
+		assert!(TradeStats::buckets((AUSD, KSM)).is_empty());
+		assert_eq!(
+			TradeStats::buckets((KSM, AUSD)).to_vec(),
+			vec![(
+				0,
+				crate::TradeStats {
+					swaps: 1,
+					volume_a: 5 * ONE,
+					volume_b: 10 * ONE,
+					fee_a: ONE / 100,
+					fee_b: 0,
+				}
+			)]
+		);
+	});
+}
+
+#[test]
+fn note_swap_should_accumulate_swaps_of_blocks_in_the_same_bucket() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		swap(BSX, KSM, 10 * ONE, 5 * ONE, 0);
+		next_block();
+
+		//Act
+		swap(KSM, BSX, 5 * ONE, 10 * ONE, 0);
+
+		//Assert
+		assert_eq!(
+			TradeStats::buckets((BSX, KSM)).to_vec(),
+			vec![(
+				0,
+				crate::TradeStats {
+					swaps: 2,
+					volume_a: 20 * ONE,
+					volume_b: 10 * ONE,
+					fee_a: 0,
+					fee_b: 0,
+				}
+			)]
+		);
+	});
+}
+
+#[test]
+fn note_swap_should_create_new_bucket_when_bucket_length_passed() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		swap(BSX, KSM, 10 * ONE, 5 * ONE, 0);
+		go_to_block(10);
+
+		//Act
+		swap(BSX, KSM, 10 * ONE, 5 * ONE, 0);
+
+		//Assert
+		let bucket_ids: Vec<BucketId> = TradeStats::buckets((BSX, KSM)).iter().map(|(id, _)| *id).collect();
+		assert_eq!(bucket_ids, vec![0, 1]);
+	});
+}
+
+#[test]
+fn note_swap_should_drop_buckets_older_than_max_buckets() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		for block in [1, 10, 20] {
+			go_to_block(block);
+			swap(BSX, KSM, 10 * ONE, 5 * ONE, 0);
+		}
+		go_to_block(30);
+
+		//Act
+		swap(BSX, KSM, 10 * ONE, 5 * ONE, 0);
+
+		//Assert
+		let bucket_ids: Vec<BucketId> = TradeStats::buckets((BSX, KSM)).iter().map(|(id, _)| *id).collect();
+		assert_eq!(bucket_ids, vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn note_swap_should_record_every_swap_of_the_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		for _ in 0..100 {
+			swap(BSX, KSM, 10 * ONE, 5 * ONE, 0);
+		}
+
+		//Assert
+		assert_eq!(TradeStats::buckets((BSX, KSM))[0].1.swaps, 100);
+		assert_eq!(TradeStats::buckets((BSX, KSM))[0].1.volume_a, 1_000 * ONE);
+	});
+}
+
+#[test]
+fn note_swap_should_ignore_swaps_of_the_same_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		swap(BSX, BSX, 10 * ONE, 10 * ONE, 0);
+
+		//Assert
+		assert!(TradeStats::buckets((BSX, BSX)).is_empty());
+	});
+}
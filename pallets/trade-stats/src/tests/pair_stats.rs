@@ -0,0 +1,82 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn trade_in_buckets() {
+	// one swap of 10 BSX for 5 KSM in each of the buckets 0, 1 and 2
+	for block in [1, 10, 20] {
+		go_to_block(block);
+		swap(BSX, KSM, 10 * ONE, 5 * ONE, ONE / 100);
+	}
+	go_to_block(25);
+}
+
+#[test]
+fn pair_stats_should_sum_buckets_in_the_window() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		trade_in_buckets();
+
+		//Act and assert
+		assert_eq!(
+			TradeStats::pair_stats(BSX, KSM, 10),
+			crate::TradeStats {
+				swaps: 2,
+				volume_a: 20 * ONE,
+				volume_b: 10 * ONE,
+				fee_a: 0,
+				fee_b: 2 * ONE / 100,
+			}
+		);
+		assert_eq!(TradeStats::pair_stats(BSX, KSM, 30).swaps, 3);
+		assert_eq!(TradeStats::pair_stats(BSX, KSM, 5).swaps, 1);
+	});
+}
+
+#[test]
+fn pair_stats_should_be_inverted_when_assets_are_not_ordered() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		trade_in_buckets();
+
+		//Act and assert
+		assert_eq!(
+			TradeStats::pair_stats(KSM, BSX, 10),
+			crate::TradeStats {
+				swaps: 2,
+				volume_a: 10 * ONE,
+				volume_b: 20 * ONE,
+				fee_a: 2 * ONE / 100,
+				fee_b: 0,
+			}
+		);
+	});
+}
+
+#[test]
+fn pair_stats_should_be_empty_when_pair_was_not_traded_in_the_window() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		trade_in_buckets();
+		go_to_block(40);
+
+		//Act and assert
+		assert_eq!(TradeStats::pair_stats(BSX, KSM, 10), crate::TradeStats::default());
+		assert_eq!(TradeStats::pair_stats(BSX, AUSD, 30), crate::TradeStats::default());
+	});
+}
@@ -0,0 +1,95 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use primitives::{AssetId, Balance};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Saturating;
+use sp_std::vec::Vec;
+
+pub type BucketId = u32;
+
+/// Swap collected from the executed trades.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Swap {
+	/// Asset sold
+	pub asset_in: AssetId,
+	/// Asset bought
+	pub asset_out: AssetId,
+	/// Amount of `asset_in` sold
+	pub amount_in: Balance,
+	/// Amount of `asset_out` bought
+	pub amount_out: Balance,
+	/// Fees of the swap as `(asset, amount)`
+	pub fees: Vec<(AssetId, Balance)>,
+}
+
+/// Volume and fees of the pair of assets.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TradeStats {
+	/// Number of the swaps
+	pub swaps: u32,
+	/// Amount of the first asset sold and bought
+	pub volume_a: Balance,
+	/// Amount of the second asset sold and bought
+	pub volume_b: Balance,
+	/// Fees paid in the first asset
+	pub fee_a: Balance,
+	/// Fees paid in the second asset
+	pub fee_b: Balance,
+}
+
+impl TradeStats {
+	/// Stats of the swap with `asset_in` as the first asset. Fees paid in other assets are ignored.
+	pub fn from_swap(swap: &Swap) -> Self {
+		let fee = |asset| {
+			swap.fees
+				.iter()
+				.filter(|(fee_asset, _)| *fee_asset == asset)
+				.fold(0, |total: Balance, (_, amount)| total.saturating_add(*amount))
+		};
+
+		Self {
+			swaps: 1,
+			volume_a: swap.amount_in,
+			volume_b: swap.amount_out,
+			fee_a: fee(swap.asset_in),
+			fee_b: fee(swap.asset_out),
+		}
+	}
+
+	/// Add the `other` stats of the same pair.
+	pub fn accumulate(&mut self, other: &Self) {
+		self.swaps.saturating_accrue(other.swaps);
+		self.volume_a.saturating_accrue(other.volume_a);
+		self.volume_b.saturating_accrue(other.volume_b);
+		self.fee_a.saturating_accrue(other.fee_a);
+		self.fee_b.saturating_accrue(other.fee_b);
+	}
+
+	/// Stats with the assets of the pair swapped.
+	pub fn inverted(self) -> Self {
+		Self {
+			swaps: self.swaps,
+			volume_a: self.volume_b,
+			volume_b: self.volume_a,
+			fee_a: self.fee_b,
+			fee_b: self.fee_a,
+		}
+	}
+}
@@ -0,0 +1,29 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_trade_stats.
+pub trait WeightInfo {
+	fn note_swap() -> Weight;
+}
+
+/// Weights for `pallet_trade_stats`.
+impl WeightInfo for () {
+	/// Storage: `TradeStats::PairStats` (r:1 w:1)
+	/// Proof: `TradeStats::PairStats` (`max_values`: None, `max_size`: Some(1773), added: 4248, mode: `MaxEncodedLen`)
+	fn note_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1768`
+		//  Estimated: `5238`
+		Weight::from_parts(33_898_000, 5238)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -21,14 +21,15 @@
 
 mod mock;
 
+use hydradx_traits::AMM;
 use pallet_liquidity_mining::{GlobalFarmId, LoyaltyCurve, YieldFarmId};
 use pallet_xyk::types::{AssetId, AssetPair, Balance};
-use pallet_xyk_liquidity_mining::Pallet as XYKLiquidityMining;
+use pallet_xyk_liquidity_mining::{DepositLpShares, Pallet as XYKLiquidityMining};
 
 use frame_benchmarking::{account, benchmarks};
 use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System, RawOrigin};
 
-use frame_support::dispatch;
+use frame_support::{dispatch, traits::Get};
 use orml_traits::arithmetic::One;
 use orml_traits::MultiCurrency;
 use sp_arithmetic::FixedU128;
@@ -144,6 +145,43 @@ fn lm_create_yield_farm<T: Config>(
 	)
 }
 
+//NOTE: global farms' ids are 1, 3, 5, ..., yield farms' ids are 2, 4, 6, ...
+fn lm_create_farms_for_max_farm_entries<T: Config>(owner: T::AccountId) -> dispatch::DispatchResult {
+	for i in 0..<T as pallet_xyk_liquidity_mining::Config>::MaxFarmEntriesPerDeposit::get() {
+		lm_create_global_farm::<T>(100_000 * ONE, owner.clone(), Perquintill::from_percent(20))?;
+		lm_create_yield_farm::<T>(owner.clone(), i * 2 + 1, ASSET_PAIR, FixedU128::one())?;
+	}
+
+	Ok(())
+}
+
+fn lm_deposit_shares_to_max_farm_entries<T: Config>(
+	caller: T::AccountId,
+	deposit_id: u128,
+	amount: Balance,
+) -> dispatch::DispatchResult {
+	lm_deposit_shares::<T>(caller.clone(), ASSET_PAIR, amount)?;
+
+	for i in 1..<T as pallet_xyk_liquidity_mining::Config>::MaxFarmEntriesPerDeposit::get() {
+		XYKLiquidityMining::<T>::redeposit_shares(
+			RawOrigin::Signed(caller.clone()).into(),
+			i * 2 + 1,
+			i * 2 + 2,
+			ASSET_PAIR,
+			deposit_id,
+		)?;
+	}
+
+	Ok(())
+}
+
+fn lm_deposit_shares_amount<T: Config>(deposit_id: u128) -> Option<Balance> {
+	<<T as pallet_xyk_liquidity_mining::Config>::LiquidityMiningHandler as DepositLpShares<T::AccountId>>::deposit_lp_shares(
+		deposit_id,
+	)
+	.map(|(_, shares)| shares)
+}
+
 fn set_period<T: Config>(block: u32) {
 	//NOTE: predefined global farm has period size = 1 block.
 	System::<T>::set_block_number(block.into());
@@ -415,6 +453,33 @@ benchmarks! {
 		assert!(MultiCurrencyOf::<T>::free_balance(BSX, &liq_provider).gt(&liq_provider_bsx_balance));
 	}
 
+	emergency_withdraw {
+		let caller = create_funded_account::<T>("caller", 0);
+		let xyk_caller = create_funded_account::<T>("xyk_caller", 1);
+		let liq_provider = create_funded_account::<T>("liq_provider", 2);
+		let shares_amount = 10 * ONE;
+
+		initialize_pool::<T>(xyk_caller, ASSET_PAIR.asset_in, ASSET_PAIR.asset_out, 1_000_000 * ONE, 10_000_000 * ONE)?;
+		xyk_add_liquidity::<T>(liq_provider.clone(), ASSET_PAIR, 10 * ONE, 1_000 * ONE)?;
+
+		//global id: 1, yield id: 2
+		lm_create_global_farm::<T>(100_000 * ONE, caller.clone(), Perquintill::from_percent(20))?;
+		lm_create_yield_farm::<T>(caller, GLOBAL_FARM_ID, ASSET_PAIR, FixedU128::one())?;
+
+		set_period::<T>(200_000);
+
+		XYKLiquidityMining::<T>::deposit_shares(RawOrigin::Signed(liq_provider.clone()).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, ASSET_PAIR, shares_amount)?;
+
+		set_period::<T>(400_000);
+
+		let share_token = xykpool::Pallet::<T>::get_share_token(ASSET_PAIR);
+		let liq_provider_shares_balance = MultiCurrencyOf::<T>::free_balance(share_token, &liq_provider);
+	}: {
+		XYKLiquidityMining::<T>::emergency_withdraw(RawOrigin::Signed(liq_provider.clone()).into(), DEPOSIT_ID)?
+	} verify {
+		assert_eq!(MultiCurrencyOf::<T>::free_balance(share_token, &liq_provider), liq_provider_shares_balance + shares_amount);
+	}
+
 	resume_yield_farm {
 		let caller = create_funded_account::<T>("caller", 0);
 		let xyk_caller = create_funded_account::<T>("xyk_caller", 1);
@@ -447,6 +512,52 @@ benchmarks! {
 	}: {
 		XYKLiquidityMining::<T>::resume_yield_farm(RawOrigin::Signed(caller.clone()).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, ASSET_PAIR, FixedU128::from(12_452))?
 	}
+
+	//Worst case: deposit is entered into max. number of yield farms.
+	split_deposit {
+		let caller = create_funded_account::<T>("caller", 0);
+		let xyk_caller = create_funded_account::<T>("xyk_caller", 1);
+		let liq_provider = create_funded_account::<T>("liq_provider", 2);
+		let shares_amount = 10 * ONE;
+
+		initialize_pool::<T>(xyk_caller, ASSET_PAIR.asset_in, ASSET_PAIR.asset_out, 1_000_000 * ONE, 10_000_000 * ONE)?;
+		xyk_add_liquidity::<T>(liq_provider.clone(), ASSET_PAIR, 10 * ONE, 1_000 * ONE)?;
+
+		lm_create_farms_for_max_farm_entries::<T>(caller)?;
+
+		set_period::<T>(200_000);
+
+		lm_deposit_shares_to_max_farm_entries::<T>(liq_provider.clone(), DEPOSIT_ID, shares_amount)?;
+	}: {
+		XYKLiquidityMining::<T>::split_deposit(RawOrigin::Signed(liq_provider.clone()).into(), DEPOSIT_ID, shares_amount / 2)?
+	} verify {
+		assert_eq!(lm_deposit_shares_amount::<T>(DEPOSIT_ID), Some(shares_amount / 2));
+		assert_eq!(lm_deposit_shares_amount::<T>(DEPOSIT_ID + 1), Some(shares_amount / 2));
+	}
+
+	//Worst case: deposits are entered into max. number of yield farms.
+	merge_deposits {
+		let caller = create_funded_account::<T>("caller", 0);
+		let xyk_caller = create_funded_account::<T>("xyk_caller", 1);
+		let liq_provider = create_funded_account::<T>("liq_provider", 2);
+		let shares_amount = 10 * ONE;
+
+		initialize_pool::<T>(xyk_caller, ASSET_PAIR.asset_in, ASSET_PAIR.asset_out, 1_000_000 * ONE, 10_000_000 * ONE)?;
+		xyk_add_liquidity::<T>(liq_provider.clone(), ASSET_PAIR, 20 * ONE, 2_000 * ONE)?;
+
+		lm_create_farms_for_max_farm_entries::<T>(caller)?;
+
+		//NOTE: deposits must be entered in the same period to be merged.
+		set_period::<T>(200_000);
+
+		lm_deposit_shares_to_max_farm_entries::<T>(liq_provider.clone(), DEPOSIT_ID, shares_amount)?;
+		lm_deposit_shares_to_max_farm_entries::<T>(liq_provider.clone(), DEPOSIT_ID + 1, shares_amount)?;
+	}: {
+		XYKLiquidityMining::<T>::merge_deposits(RawOrigin::Signed(liq_provider.clone()).into(), DEPOSIT_ID, DEPOSIT_ID + 1)?
+	} verify {
+		assert_eq!(lm_deposit_shares_amount::<T>(DEPOSIT_ID), Some(2 * shares_amount));
+		assert_eq!(lm_deposit_shares_amount::<T>(DEPOSIT_ID + 1), None);
+	}
 }
 
 #[cfg(test)]
@@ -21,13 +21,14 @@ use frame_support::{
 	instances::Instance1,
 	parameter_types,
 	traits::{AsEnsureOriginWithArg, Everything, Nothing},
+	weights::Weight,
 	PalletId,
 };
 
 use frame_system as system;
 use frame_system::{EnsureRoot, EnsureSigned};
 use hydradx_traits::{AssetPairAccountIdFor, Source};
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
 use primitives::{
 	constants::{
 		chain::{DISCOUNTED_FEE, MAX_IN_RATIO, MAX_OUT_RATIO, MIN_POOL_LIQUIDITY, MIN_TRADING_LIMIT},
@@ -40,7 +41,7 @@ use pallet_nft::{CollectionType, NftPermissions};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
-	BuildStorage,
+	BuildStorage, DispatchError, Permill,
 };
 
 pub const UNITS: Balance = 1_000_000_000_000;
@@ -158,19 +159,90 @@ impl pallet_liquidity_mining::Config<Instance1> for Test {
 parameter_types! {
 	pub const LMPalletId: PalletId = PalletId(*b"LiqMinId");
 	pub const NftCollection: primitives::CollectionId = LIQ_MINING_NFT_COLLECTION;
+	pub const MaxLiquidityMiningFarmEntries: u32 = 5;
+	pub const MaxDepositsPerClaim: u32 = 20;
+	pub const MaxCompoundingSlippage: Permill = Permill::from_percent(3);
+	pub const CompoundingPeriod: BlockNumber = 100;
+	pub const MaxScheduledYieldFarms: u32 = 5;
+	pub const MaxPendingDeposits: u32 = 5;
+	pub const FarmCreationBond: Balance = 1_000 * UNITS;
+	pub MaxGlobalFarmYieldFarms: u32 = MaxYieldFarmsPerGlobalFarm::get() as u32;
 }
 
 impl pallet_xyk_liquidity_mining::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Currency;
 	type CreateOrigin = EnsureRoot<AccountId>;
+	type ForceTerminateOrigin = EnsureRoot<AccountId>;
+	type BondAssetId = BSXAssetId;
+	type FarmCreationBond = FarmCreationBond;
+	type MaxYieldFarmsPerGlobalFarm = MaxGlobalFarmYieldFarms;
+	type BlockNumberProvider = MockBlockNumberProvider;
 	type PalletId = LMPalletId;
 	type NftCollectionId = NftCollection;
-	type AMM = XYK;
+	type AMM = pallet_xyk_liquidity_mining::XykPools<XYK>;
 	type WeightInfo = ();
 	type NFTHandler = NFT;
 	type LiquidityMiningHandler = WarehouseLM;
 	type NonDustableWhitelistHandler = Duster;
+	type AMMLiquidityHandler = pallet_xyk_liquidity_mining::XykLiquidity<Test>;
+	type MaxFarmEntriesPerDeposit = MaxLiquidityMiningFarmEntries;
+	type MaxDepositsPerClaim = MaxDepositsPerClaim;
+	type Swap = XykSwap;
+	type DepositSharesHandler = WarehouseLM;
+	type DepositSplitMergeHandler = WarehouseLM;
+	type ListedDeposits = Nothing;
+	type MaxCompoundingSlippage = MaxCompoundingSlippage;
+	type CompoundingPeriod = CompoundingPeriod;
+	type MaxScheduledYieldFarms = MaxScheduledYieldFarms;
+	type MaxPendingDeposits = MaxPendingDeposits;
+}
+
+/// Swaps rewards directly in the XYK pools. Spot price of the pool is used as oracle price.
+pub struct XykSwap;
+
+impl pallet_xyk_liquidity_mining::AssetSwap<AccountId, AssetId, Balance> for XykSwap {
+	fn sell(
+		who: &AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	) -> Result<Balance, DispatchError> {
+		let balance_before = Currency::free_balance(asset_out, who);
+
+		XYK::sell(
+			RuntimeOrigin::signed(*who),
+			asset_in,
+			asset_out,
+			amount_in,
+			min_amount_out,
+			false,
+		)?;
+
+		Ok(Currency::free_balance(asset_out, who).saturating_sub(balance_before))
+	}
+
+	fn oracle_price(asset_in: AssetId, asset_out: AssetId) -> Option<(Balance, Balance)> {
+		let pool = XYK::pair_account_from_assets(asset_in, asset_out);
+
+		Some((
+			Currency::free_balance(asset_out, &pool),
+			Currency::free_balance(asset_in, &pool),
+		))
+	}
+
+	fn oracle_pairs(asset_in: AssetId, asset_out: AssetId) -> Vec<(Source, AssetId, AssetId)> {
+		vec![(*b"snek/xyk", asset_in, asset_out)]
+	}
+
+	fn sell_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn oracle_price_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 impl pallet_duster::Config for Test {
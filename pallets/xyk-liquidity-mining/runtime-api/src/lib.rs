@@ -0,0 +1,37 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the XYK liquidity mining.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_xyk_liquidity_mining::DepositValuation;
+use primitives::CollectionId as DepositId;
+
+sp_api::decl_runtime_apis! {
+	/// API to query liq. mining deposits.
+	pub trait XykLiquidityMiningApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Return valuation of the deposit(nft) or `None` if deposit doesn't exist. Valuation
+		/// contains deposit's LP shares, liquidity of the pool behind the LP shares and rewards
+		/// which can be claimed from the deposit's yield farms at the current block.
+		fn deposit_valuation(deposit_id: DepositId) -> Option<DepositValuation<AccountId>>;
+	}
+}
@@ -18,7 +18,8 @@
 //! ## Overview
 //!
 //! This pallet provides functionality for liquidity mining programs with time incentive(loyalty
-//! factor) and multiple incentives scheme for XYK pools.
+//! factor) and multiple incentives scheme for AMM pools. Pools are identified by pool type and
+//! asset pair so farms can be created for shares of any pool type supported by `Config::AMM`.
 //! Users are rewarded for each period they stay in liq. mining program.
 //!
 //! Reward per one period is derived from the user's loyalty factor which grows with time(periods)
@@ -41,12 +42,24 @@
 #[cfg(test)]
 mod tests;
 
+pub mod traits;
 pub mod weights;
 
 pub use pallet::*;
+pub use traits::{
+	AMMLiquidity, AMMPools, AssetSwap, DepositLpShares, DepositYieldFarms, EmergencyWithdraw, GlobalFarmAssets,
+	GlobalFarmOwner, GlobalFarmTopUp, IncreaseDepositShares, SplitMergeDeposits, XykLiquidity, XykPools,
+	YieldFarmEntryStart,
+};
 
 use frame_support::traits::tokens::nonfungibles::{Inspect, Mutate};
-use frame_support::{ensure, sp_runtime::traits::Zero, PalletId};
+use frame_support::{
+	ensure,
+	sp_runtime::traits::{BlockNumberProvider, Zero},
+	storage::{with_transaction, TransactionOutcome},
+	traits::Contains,
+	PalletId,
+};
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::liquidity_mining::{GlobalFarmId, Mutate as LiquidityMiningMutate, YieldFarmId};
 use pallet_liquidity_mining::{FarmMultiplier, LoyaltyCurve};
@@ -57,20 +70,84 @@ use frame_support::{pallet_prelude::*, sp_runtime::traits::AccountIdConversion};
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use hydradx_traits::{
 	nft::{CreateTypedCollection, ReserveCollectionId},
-	AMMPosition, AMM,
+	router::PoolType,
+	Source,
 };
-use orml_traits::MultiCurrency;
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
 use pallet_nft::CollectionType;
 use primitives::CollectionId as DepositId;
 use scale_info::TypeInfo;
-use sp_arithmetic::{FixedU128, Perquintill};
+use sp_arithmetic::{
+	helpers_128bit::multiply_by_rational_with_rounding, ArithmeticError, FixedU128, PerThing, Permill, Perquintill,
+	Rounding,
+};
 use sp_std::{
+	collections::btree_map::BTreeMap,
 	convert::{From, Into, TryInto},
 	vec,
+	vec::Vec,
 };
 
 type PeriodOf<T> = BlockNumberFor<T>;
 
+/// Max number of the oracle entries used to price one global farm in the `Oracle` mode.
+pub const MAX_ORACLE_PRICING_ENTRIES: u32 = 10;
+
+/// Auto-compounding configuration of the deposit.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AutoCompoundingData<AccountId, BlockNumber> {
+	/// Account which enabled auto-compounding. Compounding is stopped if deposit's owner changes.
+	pub owner: AccountId,
+	/// Type of the pool deposit's LP shares belong to.
+	pub pool_type: PoolType<AssetId>,
+	/// Assets of the pool deposit's LP shares belong to.
+	pub assets: (AssetId, AssetId),
+	/// Block number when rewards were compounded last time.
+	pub last_compounded_at: BlockNumber,
+}
+
+/// Yield farm waiting for its start.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ScheduledYieldFarm<AccountId, BlockNumber> {
+	/// Block number(provided by `T::BlockNumberProvider`) the yield farm starts at.
+	pub start_at: BlockNumber,
+	/// Multiplier the yield farm is resumed with at its start.
+	pub multiplier: FarmMultiplier,
+	/// Account of the AMM pool the yield farm incentivizes.
+	pub amm_pool_id: AccountId,
+	/// Number of accounts with pending deposit in the yield farm.
+	pub pending_deposits: u32,
+}
+
+/// Valuation of the liq. mining deposit.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DepositValuation<AccountId> {
+	/// Owner of the deposit(nft).
+	pub owner: AccountId,
+	/// Account of the AMM pool the deposit's LP shares belong to.
+	pub amm_pool_id: AccountId,
+	/// LP token of the pool.
+	pub lp_token: AssetId,
+	/// Amount of LP shares locked in the deposit.
+	pub shares: Balance,
+	/// Amounts of the pool's assets behind the deposit's LP shares.
+	pub liquidity: Vec<(AssetId, Balance)>,
+	/// Rewards which can be claimed from the deposit's yield farms as
+	/// `(global farm id, yield farm id, reward currency, amount)`.
+	pub rewards: Vec<(GlobalFarmId, YieldFarmId, AssetId, Balance)>,
+}
+
+/// Source of the global farm's price adjustment.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PriceAdjustmentMode {
+	/// Price adjustment is set by the farm's owner.
+	#[default]
+	Manual,
+	/// Price adjustment is derived from the oracle price of the incentivized asset in the reward
+	/// currency.
+	Oracle,
+}
+
 #[frame_support::pallet]
 #[allow(clippy::too_many_arguments)]
 pub mod pallet {
@@ -86,6 +163,22 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			//NOTE: scheduled yield farm is started before any extrinsic of the block so nobody can
+			//enter the yield farm before its pending deposits. `T::BlockNumberProvider` may not be
+			//updated yet in `on_initialize` so yield farm is started in the first block the last
+			//known block number reached its `start_at`.
+			if Self::start_next_scheduled_yield_farm() {
+				Self::start_yield_farm_weight().saturating_add(T::DbWeight::get().reads_writes(1, 1))
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::compound_deposits(now, remaining_weight)
+		}
+
 		fn integrity_test() {
 			assert!(
 				T::NFTHandler::is_id_reserved(T::NftCollectionId::get()),
@@ -122,16 +215,35 @@ pub mod pallet {
 	pub trait Config: frame_system::Config + TypeInfo {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-		/// Currency for transfers.
-		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
+		/// Currency for transfers and farm creation bonds.
+		type MultiCurrency: MultiReservableCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Balance>;
 
-		/// AMM helper functions.
-		type AMM: AMM<Self::AccountId, AssetId, AssetPair, Balance>
-			+ AMMPosition<AssetId, Balance, Error = DispatchError>;
+		/// AMM pools liq. mining can run for.
+		type AMM: AMMPools<Self::AccountId>;
 
 		/// The origin account that can create new liquidity mining program.
 		type CreateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which can terminate any global farm and slash its creation bond.
+		type ForceTerminateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Asset farm creation bond is reserved in.
+		#[pallet::constant]
+		type BondAssetId: Get<AssetId>;
+
+		/// Amount reserved from the account creating global farm without `CreateOrigin`.
+		#[pallet::constant]
+		type FarmCreationBond: Get<Balance>;
+
+		/// Max number of yield farms in the global farm. This value should be the same as the
+		/// liq. mining's `MaxYieldFarmsPerGlobalFarm`.
+		#[pallet::constant]
+		type MaxYieldFarmsPerGlobalFarm: Get<u32>;
+
+		/// Block number provider used to start scheduled yield farms. This should be the same as
+		/// the liq. mining's `BlockNumberProvider`.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
 		/// Pallet id.
 		type PalletId: Get<PalletId>;
 
@@ -151,23 +263,188 @@ pub mod pallet {
 
 		/// Liquidity mining handler for managing liquidity mining functionalities
 		type LiquidityMiningHandler: LiquidityMiningMutate<
-			Self::AccountId,
-			AssetId,
-			BlockNumberFor<Self>,
-			Error = DispatchError,
-			AmmPoolId = Self::AccountId,
-			Balance = Balance,
-			LoyaltyCurve = LoyaltyCurve,
-			Period = PeriodOf<Self>,
-		>;
+				Self::AccountId,
+				AssetId,
+				BlockNumberFor<Self>,
+				Error = DispatchError,
+				AmmPoolId = Self::AccountId,
+				Balance = Balance,
+				LoyaltyCurve = LoyaltyCurve,
+				Period = PeriodOf<Self>,
+			> + DepositYieldFarms
+			+ GlobalFarmOwner<Self::AccountId>
+			+ GlobalFarmAssets<AssetId>
+			+ GlobalFarmTopUp<Self::AccountId, PeriodOf<Self>>
+			+ EmergencyWithdraw<Self::AccountId>
+			+ YieldFarmEntryStart<BlockNumberFor<Self>>
+			+ DepositLpShares<Self::AccountId>;
 
 		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
 		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
 
+		/// AMM liquidity handler used to add/remove liquidity when entering/exiting farms in
+		/// a single call.
+		type AMMLiquidityHandler: AMMLiquidity<Self::AccountId, AssetPair, Balance>;
+
+		/// Max number of farm entries a deposit can have. This value should be the same as the
+		/// liq. mining's `MaxFarmEntriesPerDeposit`.
+		#[pallet::constant]
+		type MaxFarmEntriesPerDeposit: Get<u32>;
+
+		/// Max number of deposits rewards can be claimed from in a single call.
+		#[pallet::constant]
+		type MaxDepositsPerClaim: Get<u32>;
+
+		/// Asset swap handler used to swap claimed rewards to the pool's assets when
+		/// auto-compounding.
+		type Swap: AssetSwap<Self::AccountId, AssetId, Balance>;
+
+		/// Handler used to increase deposit's LP shares when auto-compounding.
+		type DepositSharesHandler: IncreaseDepositShares<Self::AccountId>;
+
+		/// Handler used to split and merge deposits.
+		type DepositSplitMergeHandler: SplitMergeDeposits;
+
+		/// Deposits(nfts) listed for sale. Owner of the listed deposit can't claim its rewards or
+		/// withdraw, split or otherwise modify it until the listing is removed.
+		type ListedDeposits: Contains<DepositId>;
+
+		/// Max allowed difference between oracle price and execution price of the swaps done
+		/// when auto-compounding.
+		#[pallet::constant]
+		type MaxCompoundingSlippage: Get<Permill>;
+
+		/// Min. number of blocks between two auto-compoundings of the same deposit.
+		#[pallet::constant]
+		type CompoundingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Max number of scheduled yield farms waiting for their start.
+		#[pallet::constant]
+		type MaxScheduledYieldFarms: Get<u32>;
+
+		/// Max number of accounts with pending deposit in a single scheduled yield farm. All
+		/// pending deposits enter the yield farm in the block of its start.
+		#[pallet::constant]
+		type MaxPendingDeposits: Get<u32>;
+
 		/// Weight information for extrinsic in this module.
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::storage]
+	/// Auto-compounding configuration of the deposits which opted in to auto-compounding.
+	pub type AutoCompound<T: Config> =
+		StorageMap<_, Blake2_128Concat, DepositId, AutoCompoundingData<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Last deposit processed by auto-compounding in `on_idle`.
+	pub type CompoundingCursor<T: Config> = StorageValue<_, DepositId, OptionQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultPoolType() -> PoolType<AssetId> {
+		PoolType::XYK
+	}
+
+	#[pallet::storage]
+	/// Type of the pools yield farms were created for. Pools of farms created before pool types
+	/// were introduced are XYK pools.
+	pub type PoolTypes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, PoolType<AssetId>, ValueQuery, DefaultPoolType>;
+
+	#[pallet::storage]
+	/// Type of the pool the global farm's yield farms for the asset pair were created for. Asset
+	/// pair is ordered. Yield farms without stored pool type are XYK yield farms.
+	pub type FarmPoolTypes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		GlobalFarmId,
+		Blake2_128Concat,
+		(AssetId, AssetId),
+		PoolType<AssetId>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Price adjustment mode of the global farms. Runtime's `PriceAdjustment` implementation
+	/// uses the oracle price only for global farms in the `Oracle` mode.
+	pub type PriceAdjustmentModes<T: Config> =
+		StorageMap<_, Blake2_128Concat, GlobalFarmId, PriceAdjustmentMode, ValueQuery>;
+
+	#[pallet::storage]
+	/// Oracle entries used to price the global farms in the `Oracle` mode. Entries are captured
+	/// when the mode is set, so the mode has to be set again to follow a changed route.
+	pub type OraclePricingEntries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		GlobalFarmId,
+		BoundedVec<(Source, AssetId, AssetId), ConstU32<MAX_ORACLE_PRICING_ENTRIES>>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Number of the global farms in the `Oracle` mode priced by the oracle entry. Assets of the
+	/// entry are ordered by the asset id.
+	pub type OraclePricedEntries<T: Config> =
+		StorageMap<_, Blake2_128Concat, (Source, (AssetId, AssetId)), u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// Creation bonds of the global farms created without `CreateOrigin`. Bond is returned to
+	/// the account when farm is terminated by its owner.
+	pub type FarmBonds<T: Config> = StorageMap<_, Blake2_128Concat, GlobalFarmId, (T::AccountId, Balance), OptionQuery>;
+
+	#[pallet::storage]
+	/// Yield farms which were not started yet. Scheduled yield farm is stopped until
+	/// `start_at` block and it's resumed with the `multiplier` when started.
+	pub type ScheduledYieldFarms<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		GlobalFarmId,
+		Blake2_128Concat,
+		YieldFarmId,
+		ScheduledYieldFarm<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Start blocks of the scheduled yield farms sorted by the start block.
+	pub type ScheduledYieldFarmStarts<T: Config> = StorageValue<
+		_,
+		BoundedVec<(BlockNumberFor<T>, GlobalFarmId, YieldFarmId), T::MaxScheduledYieldFarms>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Start blocks of the yield farms created by `schedule_yield_farm`. Loyalty of the entries
+	/// of pending deposits is counted from the period of this block.
+	pub type YieldFarmStarts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		GlobalFarmId,
+		Blake2_128Concat,
+		YieldFarmId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// LP shares deposited into the scheduled yield farms before their start. Pending deposits
+	/// enter the yield farm in the block of its start.
+	pub type PendingDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(GlobalFarmId, YieldFarmId),
+		Blake2_128Concat,
+		T::AccountId,
+		Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Accounts deposits' rewards are paid to instead of the deposit's owner. Beneficiary is
+	/// used only while the deposit is owned by the account which set it.
+	pub type RewardBeneficiaries<T: Config> =
+		StorageMap<_, Blake2_128Concat, DepositId, (T::AccountId, T::AccountId), OptionQuery>;
+
 	#[pallet::error]
 	#[cfg_attr(test, derive(PartialEq, Eq))]
 	pub enum Error<T> {
@@ -177,13 +454,13 @@ pub mod pallet {
 		/// Account balance of XYK pool shares is not sufficient.
 		InsufficientXykSharesBalance,
 
-		/// XYK pool does not exist
+		/// AMM pool does not exist
 		XykPoolDoesntExist,
 
 		/// Account is not deposit owner.
 		NotDepositOwner,
 
-		/// XYK did not return assets for given pool id
+		/// AMM did not return assets for given pool id
 		// Not tested because previous checks in the code prevents this error
 		CantGetXykAssets,
 
@@ -198,6 +475,78 @@ pub mod pallet {
 
 		/// Provided `AssetPair` is not used by the deposit.
 		InvalidAssetPair,
+
+		/// No farm entries were provided.
+		NoFarmEntriesSpecified,
+
+		/// Deposit still has farm entries after exiting provided yield farms.
+		DepositNotFullyWithdrawn,
+
+		/// Auto-compounding is not enabled for the deposit.
+		AutoCompoundingNotEnabled,
+
+		/// Auto-compounding is already enabled for the deposit.
+		AutoCompoundingAlreadyEnabled,
+
+		/// Rewards were compounded less than `CompoundingPeriod` blocks ago.
+		CompoundingNotDue,
+
+		/// Deposit's owner is not the account which enabled auto-compounding.
+		DepositOwnerChanged,
+
+		/// Oracle price of the swapped assets is not available.
+		OraclePriceNotAvailable,
+
+		/// Pool type is not supported by the AMM.
+		PoolTypeNotSupported,
+
+		/// Global farm doesn't exist.
+		GlobalFarmNotFound,
+
+		/// Account is not global farm's owner.
+		NotGlobalFarmOwner,
+
+		/// Price adjustment of the global farm is derived from the oracle and can't be set manually.
+		PriceAdjustmentIsOracleDriven,
+
+		/// Account balance of bond asset is not sufficient to reserve farm creation bond.
+		InsufficientBondBalance,
+
+		/// Top-up amount and extra periods are both zero.
+		NothingToTopUp,
+
+		/// Start block of the scheduled yield farm must be in the future.
+		InvalidStartBlock,
+
+		/// Scheduled yield farm was not started yet.
+		YieldFarmNotStarted,
+
+		/// Account has no pending deposit in the yield farm.
+		PendingDepositNotFound,
+
+		/// Yield farm is not scheduled.
+		ScheduledYieldFarmNotFound,
+
+		/// Split amount must be non-zero.
+		ZeroSplitAmount,
+
+		/// Deposit can't be merged with itself.
+		CantMergeDepositWithItself,
+
+		/// Deposit is listed for sale and can't be modified.
+		DepositIsListedForSale,
+
+		/// Max number of scheduled yield farms was reached.
+		TooManyScheduledYieldFarms,
+
+		/// Max number of pending deposits of the scheduled yield farm was reached.
+		TooManyPendingDeposits,
+
+		/// Farm entry is specified more than once.
+		DuplicateFarmEntry,
+
+		/// Global farm already has yield farm for the asset pair in pool of different type.
+		PoolTypeMismatch,
 	}
 
 	#[pallet::event]
@@ -229,6 +578,7 @@ pub mod pallet {
 			global_farm_id: GlobalFarmId,
 			yield_farm_id: YieldFarmId,
 			multiplier: FarmMultiplier,
+			pool_type: PoolType<AssetId>,
 			asset_pair: AssetPair,
 			loyalty_curve: Option<LoyaltyCurve>,
 		},
@@ -269,6 +619,7 @@ pub mod pallet {
 			claimed: Balance,
 			reward_currency: AssetId,
 			deposit_id: DepositId,
+			beneficiary: T::AccountId,
 		},
 
 		/// LP tokens was withdrawn.
@@ -317,6 +668,126 @@ pub mod pallet {
 
 		/// NFT representing deposit has been destroyed
 		DepositDestroyed { who: T::AccountId, deposit_id: DepositId },
+
+		/// Rewards were claimed from multiple deposits. `claimed` contains total claimed
+		/// amount per reward currency.
+		AllRewardsClaimed {
+			who: T::AccountId,
+			claimed: Vec<(AssetId, Balance)>,
+		},
+
+		/// Auto-compounding of rewards was enabled for the deposit.
+		AutoCompoundingEnabled { who: T::AccountId, deposit_id: DepositId },
+
+		/// Auto-compounding of rewards was disabled for the deposit.
+		AutoCompoundingDisabled { who: T::AccountId, deposit_id: DepositId },
+
+		/// Claimed rewards were added as liquidity and LP shares were added to the deposit.
+		RewardsCompounded {
+			who: T::AccountId,
+			deposit_id: DepositId,
+			shares_amount: Balance,
+		},
+
+		/// Price adjustment mode of the global farm was changed.
+		PriceAdjustmentModeUpdated {
+			global_farm_id: GlobalFarmId,
+			who: T::AccountId,
+			mode: PriceAdjustmentMode,
+		},
+
+		/// Farm creation bond was reserved.
+		FarmBondReserved {
+			global_farm_id: GlobalFarmId,
+			who: T::AccountId,
+			amount: Balance,
+		},
+
+		/// Farm creation bond was returned to the account.
+		FarmBondReleased {
+			global_farm_id: GlobalFarmId,
+			who: T::AccountId,
+			amount: Balance,
+		},
+
+		/// Global farm was terminated by `ForceTerminateOrigin` and its creation bond was slashed.
+		GlobalFarmForceTerminated {
+			global_farm_id: GlobalFarmId,
+			owner: T::AccountId,
+			slashed_bond: Balance,
+		},
+
+		/// Global farm's rewards were topped up.
+		GlobalFarmToppedUp {
+			global_farm_id: GlobalFarmId,
+			who: T::AccountId,
+			amount: Balance,
+			extra_periods: PeriodOf<T>,
+			max_reward_per_period: Balance,
+		},
+
+		/// Yield farm was created and scheduled to start at `start_at` block.
+		YieldFarmScheduled {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			start_at: BlockNumberFor<T>,
+		},
+
+		/// Scheduled yield farm was started.
+		YieldFarmStarted {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+		},
+
+		/// LP shares were deposited into the scheduled yield farm before its start.
+		PendingSharesDeposited {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			amount: Balance,
+			lp_token: AssetId,
+		},
+
+		/// Part of the deposit's LP shares was moved to the new deposit.
+		DepositSplit {
+			who: T::AccountId,
+			deposit_id: DepositId,
+			new_deposit_id: DepositId,
+			shares_amount: Balance,
+		},
+
+		/// Beneficiary of the deposit's rewards was set. `None` means rewards are paid to the
+		/// deposit's owner.
+		RewardBeneficiarySet {
+			who: T::AccountId,
+			deposit_id: DepositId,
+			beneficiary: Option<T::AccountId>,
+		},
+
+		/// Deposit was merged into another deposit and destroyed.
+		DepositsMerged {
+			who: T::AccountId,
+			deposit_id: DepositId,
+			merged_deposit_id: DepositId,
+		},
+
+		/// Pending deposit was cancelled and LP shares were returned to the account.
+		PendingDepositCancelled {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			amount: Balance,
+			lp_token: AssetId,
+		},
+
+		/// LP shares were withdrawn from the deposit without claiming rewards. Unclaimed rewards
+		/// were forfeited.
+		SharesEmergencyWithdrawn {
+			who: T::AccountId,
+			deposit_id: DepositId,
+			lp_token: AssetId,
+			amount: Balance,
+		},
 	}
 
 	#[pallet::call]
@@ -395,7 +866,8 @@ pub mod pallet {
 
 		/// Update global farm's prices adjustment.
 		///
-		/// Only farm's owner can perform this action.
+		/// Only farm's owner can perform this action. Price adjustment can be updated only if
+		/// farm's price adjustment mode is `Manual`.
 		///
 		/// Parameters:
 		/// - `origin`: global farm's owner.
@@ -404,7 +876,7 @@ pub mod pallet {
 		///
 		/// Emits `GlobalFarmUpdated` event when successful.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::update_global_farm())]
+		#[pallet::weight(<T as Config>::WeightInfo::update_global_farm().saturating_add(T::DbWeight::get().reads(1)))]
 		pub fn update_global_farm(
 			origin: OriginFor<T>,
 			global_farm_id: GlobalFarmId,
@@ -412,6 +884,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
+			ensure!(
+				PriceAdjustmentModes::<T>::get(global_farm_id) == PriceAdjustmentMode::Manual,
+				Error::<T>::PriceAdjustmentIsOracleDriven
+			);
+
 			T::LiquidityMiningHandler::update_global_farm_price_adjustment(who, global_farm_id, price_adjustment)?;
 
 			Self::deposit_event(Event::GlobalFarmUpdated {
@@ -434,13 +911,26 @@ pub mod pallet {
 		///
 		/// Emits `GlobalFarmTerminated` event when successful.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::terminate_global_farm())]
+		#[pallet::weight(<T as Config>::WeightInfo::terminate_global_farm().saturating_add(T::DbWeight::get().reads_writes(4, 5)).saturating_add(Pallet::<T>::oracle_pricing_entries_weight()))]
 		pub fn terminate_global_farm(origin: OriginFor<T>, global_farm_id: GlobalFarmId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			let (reward_currency, undistributed_rewards, who) =
 				T::LiquidityMiningHandler::terminate_global_farm(who, global_farm_id)?;
 
+			PriceAdjustmentModes::<T>::remove(global_farm_id);
+			Self::release_oracle_pricing_entries(global_farm_id);
+
+			if let Some((bond_owner, amount)) = FarmBonds::<T>::take(global_farm_id) {
+				T::MultiCurrency::unreserve(T::BondAssetId::get(), &bond_owner, amount);
+
+				Self::deposit_event(Event::FarmBondReleased {
+					global_farm_id,
+					who: bond_owner,
+					amount,
+				});
+			}
+
 			Self::deposit_event(Event::GlobalFarmTerminated {
 				global_farm_id,
 				who,
@@ -458,6 +948,8 @@ pub mod pallet {
 		/// pool for `asset_pair` has to exist to successfully create yield farm.
 		/// Yield farm for same `asset_pair` can exist only once in the global farm.
 		///
+		/// Yield farms for pools of other types are created by `create_yield_farm_for_pool_type`.
+		///
 		/// Parameters:
 		/// - `origin`: global farm's owner.
 		/// - `farm_id`: global farm id to which a yield farm will be added.
@@ -469,7 +961,7 @@ pub mod pallet {
 		///
 		/// Emits `YieldFarmCreated` event when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::create_yield_farm())]
+		#[pallet::weight(<T as Config>::WeightInfo::create_yield_farm().saturating_add(T::DbWeight::get().reads_writes(1, 2)))]
 		pub fn create_yield_farm(
 			origin: OriginFor<T>,
 			global_farm_id: GlobalFarmId,
@@ -478,24 +970,15 @@ pub mod pallet {
 			loyalty_curve: Option<LoyaltyCurve>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let amm_pool_id = Self::ensure_xyk(asset_pair)?;
 
-			let yield_farm_id = T::LiquidityMiningHandler::create_yield_farm(
+			Self::do_create_yield_farm(
 				who,
 				global_farm_id,
-				multiplier,
-				loyalty_curve.clone(),
-				amm_pool_id,
-				vec![asset_pair.asset_in, asset_pair.asset_out],
-			)?;
-
-			Self::deposit_event(Event::YieldFarmCreated {
-				global_farm_id,
-				yield_farm_id,
+				PoolType::XYK,
+				asset_pair,
 				multiplier,
 				loyalty_curve,
-				asset_pair,
-			});
+			)?;
 
 			Ok(())
 		}
@@ -520,7 +1003,7 @@ pub mod pallet {
 			multiplier: FarmMultiplier,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let amm_pool_id = Self::ensure_xyk(asset_pair)?;
+			let amm_pool_id = Self::ensure_pool(Self::farm_pool_type(global_farm_id, asset_pair), asset_pair)?;
 
 			let yield_farm_id = T::LiquidityMiningHandler::update_yield_farm_multiplier(
 				who.clone(),
@@ -564,8 +1047,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			//NOTE: don't check XYK pool existance, owner must be able to stop yield farm.
-			let amm_pool_id = T::AMM::get_pair_id(asset_pair);
+			//NOTE: don't check pool existance, owner must be able to stop yield farm.
+			let amm_pool_id = Self::farm_pool_id(global_farm_id, asset_pair)?;
 			let yield_farm_id = T::LiquidityMiningHandler::stop_yield_farm(who.clone(), global_farm_id, amm_pool_id)?;
 
 			Self::deposit_event(Event::YieldFarmStopped {
@@ -585,6 +1068,9 @@ pub mod pallet {
 		///
 		/// WARN: Yield farm is NOT rewarded for time it was stopped.
 		///
+		/// Scheduled yield farm is started by this function before its start block and its
+		/// pending deposits enter the yield farm.
+		///
 		/// Only farm owner can perform this action.
 		///
 		/// Parameters:
@@ -596,22 +1082,24 @@ pub mod pallet {
 		///
 		/// Emits `YieldFarmResumed` event when successful.
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as Config>::WeightInfo::resume_yield_farm())]
+		#[pallet::weight(<T as Config>::WeightInfo::resume_yield_farm()
+			.saturating_add(Pallet::<T>::enter_pending_deposits_weight(T::MaxPendingDeposits::get()))
+			.saturating_add(T::DbWeight::get().writes(1)))]
 		pub fn resume_yield_farm(
 			origin: OriginFor<T>,
 			global_farm_id: GlobalFarmId,
 			yield_farm_id: YieldFarmId,
 			asset_pair: AssetPair,
 			multiplier: FarmMultiplier,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let amm_pool_id = Self::ensure_xyk(asset_pair)?;
+			let amm_pool_id = Self::ensure_pool(Self::farm_pool_type(global_farm_id, asset_pair), asset_pair)?;
 
 			T::LiquidityMiningHandler::resume_yield_farm(
 				who.clone(),
 				global_farm_id,
 				yield_farm_id,
-				amm_pool_id,
+				amm_pool_id.clone(),
 				multiplier,
 			)?;
 
@@ -623,7 +1111,20 @@ pub mod pallet {
 				multiplier,
 			});
 
-			Ok(())
+			let mut entered_deposits: u32 = 0;
+			if Self::remove_scheduled_yield_farm(global_farm_id, yield_farm_id).is_some() {
+				//NOTE: yield farm started earlier than scheduled starts now.
+				let start_at = T::BlockNumberProvider::current_block_number();
+				YieldFarmStarts::<T>::insert(global_farm_id, yield_farm_id, start_at);
+
+				entered_deposits = Self::enter_pending_deposits(global_farm_id, yield_farm_id, amm_pool_id, start_at)?;
+			}
+
+			Ok(Some(
+				<T as Config>::WeightInfo::resume_yield_farm()
+					.saturating_add(Self::enter_pending_deposits_weight(entered_deposits)),
+			)
+			.into())
 		}
 
 		/// Remove yield farm
@@ -646,7 +1147,7 @@ pub mod pallet {
 		///
 		/// Emits `YieldFarmTerminated` event when successful.
 		#[pallet::call_index(7)]
-		#[pallet::weight(<T as Config>::WeightInfo::terminate_yield_farm())]
+		#[pallet::weight(<T as Config>::WeightInfo::terminate_yield_farm().saturating_add(T::DbWeight::get().reads_writes(3, 4)))]
 		pub fn terminate_yield_farm(
 			origin: OriginFor<T>,
 			global_farm_id: GlobalFarmId,
@@ -655,10 +1156,18 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			//NOTE: don't check XYK pool existance, owner must be able to stop yield farm.
-			let amm_pool_id = T::AMM::get_pair_id(asset_pair);
+			//NOTE: don't check pool existance, owner must be able to stop yield farm.
+			let amm_pool_id = Self::farm_pool_id(global_farm_id, asset_pair)?;
 
-			T::LiquidityMiningHandler::terminate_yield_farm(who.clone(), global_farm_id, yield_farm_id, amm_pool_id)?;
+			T::LiquidityMiningHandler::terminate_yield_farm(
+				who.clone(),
+				global_farm_id,
+				yield_farm_id,
+				amm_pool_id.clone(),
+			)?;
+
+			Self::remove_scheduled_yield_farm(global_farm_id, yield_farm_id);
+			YieldFarmStarts::<T>::remove(global_farm_id, yield_farm_id);
 
 			Self::deposit_event(Event::YieldFarmTerminated {
 				global_farm_id,
@@ -683,9 +1192,14 @@ pub mod pallet {
 		/// - `asset_pair`: asset pair identifying LP shares user wants to deposit.
 		/// - `shares_amount`: amount of LP shares user wants to deposit.
 		///
-		/// Emits `SharesDeposited` event when successful.
+		/// LP shares deposited to the scheduled yield farm are locked as pending deposit which
+		/// enters the yield farm at its start.
+		///
+		/// Emits:
+		/// * `SharesDeposited` when successful
+		/// * `PendingSharesDeposited` when yield farm is not started yet
 		#[pallet::call_index(8)]
-		#[pallet::weight(<T as Config>::WeightInfo::deposit_shares())]
+		#[pallet::weight(<T as Config>::WeightInfo::deposit_shares().saturating_add(T::DbWeight::get().reads(1)).saturating_add(Pallet::<T>::price_adjustment_weight()))]
 		pub fn deposit_shares(
 			origin: OriginFor<T>,
 			global_farm_id: GlobalFarmId,
@@ -694,34 +1208,12 @@ pub mod pallet {
 			shares_amount: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let amm_pool_id = Self::ensure_xyk(asset_pair)?;
-
-			let amm_share_token = T::AMM::get_share_token(asset_pair);
-
-			ensure!(
-				T::MultiCurrency::ensure_can_withdraw(amm_share_token, &who, shares_amount).is_ok(),
-				Error::<T>::InsufficientXykSharesBalance
-			);
-
-			let deposit_id = T::LiquidityMiningHandler::deposit_lp_shares(
-				global_farm_id,
-				yield_farm_id,
-				amm_pool_id,
-				shares_amount,
-				Self::get_token_value_of_lp_shares,
-			)?;
 
-			Self::lock_lp_tokens(amm_share_token, &who, shares_amount)?;
-			T::NFTHandler::mint_into(&T::NftCollectionId::get(), &deposit_id, &who)?;
+			if ScheduledYieldFarms::<T>::contains_key(global_farm_id, yield_farm_id) {
+				return Self::do_deposit_pending_shares(who, global_farm_id, yield_farm_id, asset_pair, shares_amount);
+			}
 
-			Self::deposit_event(Event::SharesDeposited {
-				global_farm_id,
-				yield_farm_id,
-				who,
-				amount: shares_amount,
-				lp_token: amm_share_token,
-				deposit_id,
-			});
+			Self::do_deposit_shares(who, global_farm_id, yield_farm_id, asset_pair, shares_amount)?;
 
 			Ok(())
 		}
@@ -742,7 +1234,7 @@ pub mod pallet {
 		///
 		/// Emits `SharesRedeposited` event when successful.
 		#[pallet::call_index(9)]
-		#[pallet::weight(<T as Config>::WeightInfo::redeposit_shares())]
+		#[pallet::weight(<T as Config>::WeightInfo::redeposit_shares().saturating_add(Pallet::<T>::price_adjustment_weight()))]
 		pub fn redeposit_shares(
 			origin: OriginFor<T>,
 			global_farm_id: GlobalFarmId,
@@ -751,66 +1243,33 @@ pub mod pallet {
 			deposit_id: DepositId,
 		) -> DispatchResult {
 			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
-			let amm_pool_id = Self::ensure_xyk(asset_pair)?;
-
-			let amm_share_token = T::AMM::get_share_token(asset_pair);
-
-			let (shares_amount, deposit_amm_pool_id) = T::LiquidityMiningHandler::redeposit_lp_shares(
-				global_farm_id,
-				yield_farm_id,
-				deposit_id,
-				Self::get_token_value_of_lp_shares,
-			)?;
-
-			ensure!(amm_pool_id == deposit_amm_pool_id, Error::<T>::InvalidAssetPair);
-
-			Self::deposit_event(Event::SharesRedeposited {
-				global_farm_id,
-				yield_farm_id,
-				who: owner,
-				amount: shares_amount,
-				lp_token: amm_share_token,
-				deposit_id,
-			});
 
-			Ok(())
+			Self::do_redeposit_shares(owner, global_farm_id, yield_farm_id, asset_pair, deposit_id)
 		}
 
 		/// Claim rewards from liq. mining for deposit represented by `nft_id`.
 		///
-		/// This function calculate user rewards from liq. mining and transfer rewards to `origin`
-		/// account. Claiming in the same period is allowed only once.
+		/// This function calculate user rewards from liq. mining and transfer rewards to deposit's
+		/// reward beneficiary or to `origin` account if beneficiary is not set. Claiming in the same
+		/// period is allowed only once.
 		///
 		/// Parameters:
 		/// - `origin`: account owner of deposit(nft).
 		/// - `deposit_id`: nft id representing deposit in the yield farm.
 		/// - `yield_farm_id`: yield farm identifier to claim rewards from.
 		///
-		/// Emits `RewardClaimed` event when successful.
+		/// Emits `RewardClaimed` event for each claim when successful.
 		#[pallet::call_index(10)]
-		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
+		#[pallet::weight(Pallet::<T>::claim_rewards_weight())]
 		pub fn claim_rewards(
 			origin: OriginFor<T>,
 			deposit_id: DepositId,
 			yield_farm_id: YieldFarmId,
 		) -> DispatchResult {
 			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+			let beneficiary = Self::reward_beneficiary(&owner, deposit_id);
 
-			let (global_farm_id, reward_currency, claimed, _) =
-				T::LiquidityMiningHandler::claim_rewards(owner.clone(), deposit_id, yield_farm_id)?;
-
-			ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
-
-			Self::deposit_event(Event::RewardClaimed {
-				global_farm_id,
-				yield_farm_id,
-				who: owner,
-				claimed,
-				reward_currency,
-				deposit_id,
-			});
-
-			Ok(())
+			Self::do_claim_rewards(owner, beneficiary, deposit_id, yield_farm_id)
 		}
 
 		/// Withdraw LP shares from liq. mining with reward claiming if possible.
@@ -824,7 +1283,8 @@ pub mod pallet {
 		/// * yield farm was terminated - only LP shares will be transferred.
 		/// * farm was terminated - only LP shares will be transferred.
 		///
-		/// User's unclaimable rewards will be transferred back to global farm's account.
+		/// User's unclaimable rewards will be transferred back to global farm's account. Claimed
+		/// rewards are transferred to deposit's reward beneficiary if it's set.
 		///
 		/// Parameters:
 		/// - `origin`: account owner of deposit(nft).
@@ -836,7 +1296,7 @@ pub mod pallet {
 		/// * `RewardClaimed` if claim happen
 		/// * `SharesWithdrawn` event when successful
 		#[pallet::call_index(11)]
-		#[pallet::weight(<T as Config>::WeightInfo::withdraw_shares())]
+		#[pallet::weight(Pallet::<T>::withdraw_shares_weight())]
 		pub fn withdraw_shares(
 			origin: OriginFor<T>,
 			deposit_id: DepositId,
@@ -844,50 +1304,900 @@ pub mod pallet {
 			asset_pair: AssetPair,
 		) -> DispatchResult {
 			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
-			let amm_pool_id = Self::ensure_xyk(asset_pair)?;
-
-			let global_farm_id = T::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
-				.ok_or(Error::<T>::DepositDataNotFound)?;
 
-			let (withdrawn_amount, claim_data, is_destroyed) = T::LiquidityMiningHandler::withdraw_lp_shares(
-				owner.clone(),
-				deposit_id,
-				global_farm_id,
-				yield_farm_id,
-				amm_pool_id.clone(),
-			)?;
+			Self::do_withdraw_shares(owner, deposit_id, yield_farm_id, asset_pair)?;
 
-			if let Some((reward_currency, claimed, _)) = claim_data {
-				if !claimed.is_zero() {
-					Self::deposit_event(Event::RewardClaimed {
-						global_farm_id,
-						yield_farm_id,
-						who: owner.clone(),
-						claimed,
-						reward_currency,
-						deposit_id,
-					});
-				}
-			}
+			Ok(())
+		}
 
-			let lp_token = Self::get_lp_token(&amm_pool_id)?;
-			if !withdrawn_amount.is_zero() {
-				Self::deposit_event(Event::SharesWithdrawn {
-					global_farm_id,
-					yield_farm_id,
-					who: owner.clone(),
-					lp_token,
-					amount: withdrawn_amount,
-					deposit_id,
+		/// Add liquidity to AMM pool and deposit minted LP shares to multiple yield farms.
+		///
+		/// This function adds liquidity to the `asset_pair` pool, creates a deposit(nft) from the
+		/// minted LP shares in the first yield farm and redeposits it to the rest of the
+		/// `farm_entries`. Only one deposit(nft) is minted. Pool type is the pool type of the
+		/// first yield farm.
+		///
+		/// Parameters:
+		/// - `origin`: account providing liquidity and depositing LP shares.
+		/// - `asset_pair`: asset pair identifying pool to add liquidity to.
+		/// - `amount_a`: amount of `asset_pair.asset_in` to add.
+		/// - `amount_b_max_limit`: max amount of `asset_pair.asset_out` to add.
+		/// - `farm_entries`: list of (global farm id, yield farm id) pairs to join, each entry can be
+		/// specified only once.
+		///
+		/// Emits:
+		/// * `SharesDeposited` for the first farm entry
+		/// * `SharesRedeposited` for each of the remaining farm entries
+		#[pallet::call_index(12)]
+		#[pallet::weight(Pallet::<T>::add_liquidity_and_join_farms_weight(farm_entries.len() as u32))]
+		pub fn add_liquidity_and_join_farms(
+			origin: OriginFor<T>,
+			asset_pair: AssetPair,
+			amount_a: Balance,
+			amount_b_max_limit: Balance,
+			farm_entries: BoundedVec<(GlobalFarmId, YieldFarmId), T::MaxFarmEntriesPerDeposit>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let ((global_farm_id, yield_farm_id), redeposit_entries) =
+				farm_entries.split_first().ok_or(Error::<T>::NoFarmEntriesSpecified)?;
+
+			let pool_type = Self::farm_pool_type(*global_farm_id, asset_pair);
+			Self::ensure_pool(pool_type, asset_pair)?;
+
+			let has_duplicates = farm_entries
+				.iter()
+				.enumerate()
+				.any(|(i, entry)| farm_entries[..i].contains(entry));
+			ensure!(!has_duplicates, Error::<T>::DuplicateFarmEntry);
+
+			let shares_amount =
+				T::AMMLiquidityHandler::add_liquidity(&who, pool_type, asset_pair, amount_a, amount_b_max_limit)?;
+
+			let deposit_id =
+				Self::do_deposit_shares(who.clone(), *global_farm_id, *yield_farm_id, asset_pair, shares_amount)?;
+
+			for (global_farm_id, yield_farm_id) in redeposit_entries {
+				Self::do_redeposit_shares(who.clone(), *global_farm_id, *yield_farm_id, asset_pair, deposit_id)?;
+			}
+
+			Ok(())
+		}
+
+		/// Withdraw LP shares from multiple yield farms and remove liquidity from AMM pool.
+		///
+		/// This function claims rewards(if possible) and withdraws LP shares from all the
+		/// `yield_farm_ids`, destroys the deposit and removes unlocked LP shares from the pool.
+		/// `yield_farm_ids` must contain all the farm entries of the deposit. Claimed rewards are
+		/// transferred to deposit's reward beneficiary if it's set.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `deposit_id`: nft id representing deposit in the yield farms.
+		/// - `asset_pair`: asset pair identifying pool of the deposit.
+		/// - `yield_farm_ids`: yield farms to withdraw LP shares from.
+		///
+		/// Emits:
+		/// * `RewardClaimed` for each claim
+		/// * `SharesWithdrawn` for each yield farm
+		/// * `DepositDestroyed` when deposit is destroyed
+		#[pallet::call_index(13)]
+		#[pallet::weight(Pallet::<T>::exit_farms_and_remove_liquidity_weight(yield_farm_ids.len() as u32))]
+		pub fn exit_farms_and_remove_liquidity(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			asset_pair: AssetPair,
+			yield_farm_ids: BoundedVec<YieldFarmId, T::MaxFarmEntriesPerDeposit>,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+			ensure!(!yield_farm_ids.is_empty(), Error::<T>::NoFarmEntriesSpecified);
+
+			let (pool_type, _) = Self::deposit_pool(deposit_id, asset_pair)?;
+
+			let mut unlocked_shares = None;
+			for yield_farm_id in yield_farm_ids {
+				let (withdrawn_amount, is_destroyed) =
+					Self::do_withdraw_shares(owner.clone(), deposit_id, yield_farm_id, asset_pair)?;
+
+				if is_destroyed {
+					unlocked_shares = Some(withdrawn_amount);
+				}
+			}
+
+			let shares_amount = unlocked_shares.ok_or(Error::<T>::DepositNotFullyWithdrawn)?;
+
+			T::AMMLiquidityHandler::remove_liquidity(&owner, pool_type, asset_pair, shares_amount)
+		}
+
+		/// Claim rewards from all yield farms of provided deposits.
+		///
+		/// This function claims rewards from every yield farm entry of each deposit in
+		/// `deposit_ids`. Yield farm entries which can't be claimed(e.g. rewards were already
+		/// claimed in this period) are skipped. Total claimed amounts are aggregated per reward
+		/// currency.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of all the deposits(nfts).
+		/// - `deposit_ids`: nft ids representing deposits to claim rewards from.
+		///
+		/// Emits:
+		/// * `RewardClaimed` for each claim
+		/// * `AllRewardsClaimed` with total claimed amounts per reward currency
+		#[pallet::call_index(14)]
+		#[pallet::weight(Pallet::<T>::claim_all_weight(deposit_ids.len() as u32))]
+		pub fn claim_all(
+			origin: OriginFor<T>,
+			deposit_ids: BoundedVec<DepositId, T::MaxDepositsPerClaim>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut claimed_rewards: BTreeMap<AssetId, Balance> = BTreeMap::new();
+			let mut claims_count: u32 = 0;
+
+			for deposit_id in deposit_ids {
+				Self::ensure_deposit_owner(&who, deposit_id)?;
+
+				let beneficiary = Self::reward_beneficiary(&who, deposit_id);
+				claims_count = claims_count.saturating_add(Self::claim_deposit_rewards(
+					&who,
+					&beneficiary,
+					deposit_id,
+					&mut claimed_rewards,
+				));
+			}
+
+			ensure!(!claimed_rewards.is_empty(), Error::<T>::ZeroClaimedRewards);
+
+			Self::deposit_event(Event::AllRewardsClaimed {
+				who,
+				claimed: claimed_rewards.into_iter().collect(),
+			});
+
+			Ok(Some(
+				<T as Config>::WeightInfo::claim_rewards()
+					.saturating_add(Self::price_adjustment_weight())
+					.saturating_mul(claims_count.into()),
+			)
+			.into())
+		}
+
+		/// Enable auto-compounding of rewards for the deposit.
+		///
+		/// Rewards of the deposit are periodically claimed, swapped to the pool's assets, added
+		/// as liquidity to the pool and minted LP shares are added to the deposit.
+		/// Compounding is done in `on_idle` or by anyone calling `compound_rewards`. Rewards of the
+		/// deposit with reward beneficiary are paid to the beneficiary instead of being compounded.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `deposit_id`: nft id representing deposit in the yield farms.
+		/// - `asset_pair`: asset pair identifying pool of the deposit.
+		///
+		/// Emits `AutoCompoundingEnabled` event when successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(5, 1))]
+		pub fn enable_auto_compounding(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			asset_pair: AssetPair,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+			let (pool_type, _) = Self::deposit_pool(deposit_id, asset_pair)?;
+
+			ensure!(
+				!AutoCompound::<T>::contains_key(deposit_id),
+				Error::<T>::AutoCompoundingAlreadyEnabled
+			);
+
+			AutoCompound::<T>::insert(
+				deposit_id,
+				AutoCompoundingData {
+					owner: owner.clone(),
+					pool_type,
+					assets: (asset_pair.asset_in, asset_pair.asset_out),
+					last_compounded_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::AutoCompoundingEnabled { who: owner, deposit_id });
+
+			Ok(())
+		}
+
+		/// Disable auto-compounding of rewards for the deposit.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `deposit_id`: nft id representing deposit in the yield farms.
+		///
+		/// Emits `AutoCompoundingDisabled` event when successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+		pub fn disable_auto_compounding(origin: OriginFor<T>, deposit_id: DepositId) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+
+			ensure!(
+				AutoCompound::<T>::contains_key(deposit_id),
+				Error::<T>::AutoCompoundingNotEnabled
+			);
+
+			AutoCompound::<T>::remove(deposit_id);
+
+			Self::deposit_event(Event::AutoCompoundingDisabled { who: owner, deposit_id });
+
+			Ok(())
+		}
+
+		/// Compound rewards of the deposit with enabled auto-compounding.
+		///
+		/// This function can be called by anyone. Rewards can be compounded only once per
+		/// `CompoundingPeriod`. Swaps of the claimed rewards are protected by the oracle price.
+		///
+		/// Parameters:
+		/// - `origin`: any signed account.
+		/// - `deposit_id`: nft id representing deposit in the yield farms.
+		///
+		/// Emits:
+		/// * `RewardClaimed` for each claim
+		/// * `RewardsCompounded` when successful
+		#[pallet::call_index(17)]
+		#[pallet::weight(Pallet::<T>::compound_weight())]
+		pub fn compound_rewards(origin: OriginFor<T>, deposit_id: DepositId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::do_compound(deposit_id, frame_system::Pallet::<T>::block_number())
+		}
+
+		/// Set source of the global farm's price adjustment.
+		///
+		/// In the `Oracle` mode price adjustment is derived from the oracle price of the
+		/// incentivized asset in the reward currency and `update_global_farm` is not allowed.
+		/// In the `Manual` mode price adjustment is set by the farm's owner via
+		/// `update_global_farm`.
+		///
+		/// Oracle entries used to price the farm in the `Oracle` mode are captured in
+		/// `OraclePricingEntries`, so they can be tracked by the oracle with priority. Setting the
+		/// `Oracle` mode again refreshes the captured entries.
+		///
+		/// Only farm's owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: id of the global farm to update.
+		/// - `mode`: new price adjustment mode.
+		///
+		/// Emits `PriceAdjustmentModeUpdated` event when successful.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(4, 2).saturating_add(Pallet::<T>::oracle_pricing_entries_weight()))]
+		pub fn set_price_adjustment_mode(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			mode: PriceAdjustmentMode,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let owner =
+				T::LiquidityMiningHandler::global_farm_owner(global_farm_id).ok_or(Error::<T>::GlobalFarmNotFound)?;
+			ensure!(owner == who, Error::<T>::NotGlobalFarmOwner);
+
+			PriceAdjustmentModes::<T>::insert(global_farm_id, mode);
+
+			Self::release_oracle_pricing_entries(global_farm_id);
+			if mode == PriceAdjustmentMode::Oracle {
+				Self::capture_oracle_pricing_entries(global_farm_id);
+			}
+
+			Self::deposit_event(Event::PriceAdjustmentModeUpdated {
+				global_farm_id,
+				who,
+				mode,
+			});
+
+			Ok(())
+		}
+
+		/// Create new liquidity mining program owned by the origin.
+		///
+		/// This function can be called by any account. `FarmCreationBond` of `BondAssetId` is
+		/// reserved from the origin and `total_rewards` are transferred from the origin to the farm
+		/// account. Bond is returned when the farm is terminated by its owner and slashed when the
+		/// farm is terminated by `ForceTerminateOrigin`. `total_rewards` must be at least liq.
+		/// mining's `MinTotalFarmRewards`.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `total_rewards`: total rewards planned to distribute.
+		/// - `planned_yielding_periods`: planned number of periods to distribute `total_rewards`.
+		/// - `blocks_per_period`:  number of blocks in a single period.
+		/// - `incentivized_asset`: asset to be incentivized in AMM pools.
+		/// - `reward_currency`: payoff currency of rewards.
+		/// - `yield_per_period`: percentage return on `reward_currency` of all farms p.a.
+		/// - `min_deposit`: minimum amount which can be deposited to the farm
+		/// - `price_adjustment`: price adjustment of the farm.
+		///
+		/// Emits:
+		/// * `GlobalFarmCreated`
+		/// * `FarmBondReserved` when successful
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_global_farm().saturating_add(T::DbWeight::get().reads_writes(1, 2)))]
+		pub fn create_global_farm_with_bond(
+			origin: OriginFor<T>,
+			total_rewards: Balance,
+			planned_yielding_periods: PeriodOf<T>,
+			blocks_per_period: BlockNumberFor<T>,
+			incentivized_asset: AssetId,
+			reward_currency: AssetId,
+			yield_per_period: Perquintill,
+			min_deposit: Balance,
+			price_adjustment: FixedU128,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let bond = T::FarmCreationBond::get();
+			T::MultiCurrency::reserve(T::BondAssetId::get(), &who, bond)
+				.map_err(|_| Error::<T>::InsufficientBondBalance)?;
+
+			let (id, max_reward_per_period) = T::LiquidityMiningHandler::create_global_farm(
+				total_rewards,
+				planned_yielding_periods,
+				blocks_per_period,
+				incentivized_asset,
+				reward_currency,
+				who.clone(),
+				yield_per_period,
+				min_deposit,
+				price_adjustment,
+			)?;
+
+			FarmBonds::<T>::insert(id, (who.clone(), bond));
+
+			Self::deposit_event(Event::GlobalFarmCreated {
+				id,
+				owner: who.clone(),
+				total_rewards,
+				reward_currency,
+				yield_per_period,
+				planned_yielding_periods,
+				blocks_per_period,
+				incentivized_asset,
+				max_reward_per_period,
+				min_deposit,
+				price_adjustment,
+			});
+
+			Self::deposit_event(Event::FarmBondReserved {
+				global_farm_id: id,
+				who,
+				amount: bond,
+			});
+
+			Ok(())
+		}
+
+		/// Terminate global farm and slash its creation bond.
+		///
+		/// All the yield farms of the global farm have to be provided. Active yield farms are
+		/// stopped and all of them are terminated before the global farm is terminated.
+		/// Undistributed rewards are transferred to the farm's owner. Farms created by
+		/// `CreateOrigin` don't have a bond so nothing is slashed.
+		///
+		/// The dispatch origin for this call must be `T::ForceTerminateOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: `T::ForceTerminateOrigin`.
+		/// - `global_farm_id`: id of global farm to be terminated.
+		/// - `yield_farms`: yield farm id and asset pair of all the global farm's yield farms which
+		/// are not terminated yet.
+		///
+		/// Emits:
+		/// * `YieldFarmStopped` for each active yield farm
+		/// * `YieldFarmTerminated` for each yield farm
+		/// * `GlobalFarmTerminated`
+		/// * `GlobalFarmForceTerminated` when successful
+		#[pallet::call_index(23)]
+		#[pallet::weight(Pallet::<T>::force_terminate_global_farm_weight(yield_farms.len() as u32))]
+		pub fn force_terminate_global_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farms: BoundedVec<(YieldFarmId, AssetPair), T::MaxYieldFarmsPerGlobalFarm>,
+		) -> DispatchResult {
+			T::ForceTerminateOrigin::ensure_origin(origin)?;
+
+			let owner =
+				T::LiquidityMiningHandler::global_farm_owner(global_farm_id).ok_or(Error::<T>::GlobalFarmNotFound)?;
+
+			for (yield_farm_id, asset_pair) in yield_farms {
+				let amm_pool_id = Self::farm_pool_id(global_farm_id, asset_pair)?;
+
+				//NOTE: stopping fails if yield farm is already stopped.
+				if frame_support::storage::with_storage_layer(|| {
+					T::LiquidityMiningHandler::stop_yield_farm(owner.clone(), global_farm_id, amm_pool_id.clone())
+				})
+				.is_ok()
+				{
+					Self::deposit_event(Event::YieldFarmStopped {
+						global_farm_id,
+						yield_farm_id,
+						who: owner.clone(),
+						asset_pair,
+					});
+				}
+
+				T::LiquidityMiningHandler::terminate_yield_farm(
+					owner.clone(),
+					global_farm_id,
+					yield_farm_id,
+					amm_pool_id.clone(),
+				)?;
+
+				Self::remove_scheduled_yield_farm(global_farm_id, yield_farm_id);
+				YieldFarmStarts::<T>::remove(global_farm_id, yield_farm_id);
+
+				Self::deposit_event(Event::YieldFarmTerminated {
+					global_farm_id,
+					yield_farm_id,
+					who: owner.clone(),
+					asset_pair,
 				});
 			}
 
-			if is_destroyed {
-				Self::unlock_lp_tokens(lp_token, &owner, withdrawn_amount)?;
-				T::NFTHandler::burn(&T::NftCollectionId::get(), &deposit_id, Some(&owner))?;
+			let (reward_currency, undistributed_rewards, _) =
+				T::LiquidityMiningHandler::terminate_global_farm(owner.clone(), global_farm_id)?;
+
+			PriceAdjustmentModes::<T>::remove(global_farm_id);
+			Self::release_oracle_pricing_entries(global_farm_id);
+
+			let slashed_bond = FarmBonds::<T>::take(global_farm_id)
+				.map(|(bond_owner, amount)| {
+					let not_slashed = T::MultiCurrency::slash_reserved(T::BondAssetId::get(), &bond_owner, amount);
+					amount.saturating_sub(not_slashed)
+				})
+				.unwrap_or_default();
+
+			Self::deposit_event(Event::GlobalFarmTerminated {
+				global_farm_id,
+				who: owner.clone(),
+				reward_currency,
+				undistributed_rewards,
+			});
+
+			Self::deposit_event(Event::GlobalFarmForceTerminated {
+				global_farm_id,
+				owner,
+				slashed_bond,
+			});
+
+			Ok(())
+		}
+
+		/// Top-up rewards of the global farm and extend its duration.
+		///
+		/// `amount` of global farm's reward currency is transferred from the origin to the global
+		/// farm and global farm's planned yielding periods are extended by `extra_periods`.
+		/// `max_reward_per_period` is recalculated from the rewards left in the global farm so
+		/// deposits stay in the farm and keep their loyalty.
+		///
+		/// Only farm's owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: id of the global farm to top-up.
+		/// - `amount`: amount of reward currency to add to the global farm.
+		/// - `extra_periods`: number of periods to extend global farm's planned yielding periods by.
+		///
+		/// Emits `GlobalFarmToppedUp` event when successful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_global_farm().saturating_add(T::DbWeight::get().reads_writes(5, 3)))]
+		pub fn top_up_global_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			amount: Balance,
+			extra_periods: PeriodOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				!amount.is_zero() || !extra_periods.is_zero(),
+				Error::<T>::NothingToTopUp
+			);
+
+			let owner =
+				T::LiquidityMiningHandler::global_farm_owner(global_farm_id).ok_or(Error::<T>::GlobalFarmNotFound)?;
+			ensure!(owner == who, Error::<T>::NotGlobalFarmOwner);
+
+			let max_reward_per_period =
+				T::LiquidityMiningHandler::top_up_global_farm(&who, global_farm_id, amount, extra_periods)?;
+
+			Self::deposit_event(Event::GlobalFarmToppedUp {
+				global_farm_id,
+				who,
+				amount,
+				extra_periods,
+				max_reward_per_period,
+			});
+
+			Ok(())
+		}
+
+		/// Add yield farm for given `asset_pair` pool of `pool_type` which starts at `start_at`
+		/// block.
+		///
+		/// Yield farm is created stopped so no rewards accrue before `start_at`. LP shares
+		/// deposited before the start are locked as pending deposits. Yield farm is started at
+		/// the beginning of the first block `start_at` is reached in, before any extrinsic of
+		/// the block, and all its pending deposits enter the yield farm. Single scheduled yield
+		/// farm is started per block so yield farms with the same `start_at` are started in
+		/// subsequent blocks. Loyalty of the pending deposits is counted from the period of
+		/// `start_at` even if the start is delayed. `start_yield_farm` can be used to start the
+		/// yield farm if the start is delayed. Owner can start the yield farm earlier by
+		/// `resume_yield_farm`.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id to which a yield farm will be added.
+		/// - `pool_type`: type of the pool identified by `asset_pair`.
+		/// - `asset_pair`: asset pair identifying yield farm.
+		/// - `multiplier`: yield farm multiplier used when yield farm is started.
+		/// - `loyalty_curve`: curve to calculate loyalty multiplier to distribute rewards to users
+		/// with time incentive. `None` means no loyalty multiplier.
+		/// - `start_at`: block number the yield farm starts at. Blocks are provided by
+		/// `T::BlockNumberProvider`.
+		///
+		/// Emits:
+		/// * `YieldFarmCreated`
+		/// * `YieldFarmScheduled` when successful
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_yield_farm()
+			.saturating_add(<T as Config>::WeightInfo::stop_yield_farm())
+			.saturating_add(T::DbWeight::get().reads_writes(1, 3)))]
+		pub fn schedule_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			pool_type: PoolType<AssetId>,
+			asset_pair: AssetPair,
+			multiplier: FarmMultiplier,
+			loyalty_curve: Option<LoyaltyCurve>,
+			start_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				start_at > T::BlockNumberProvider::current_block_number(),
+				Error::<T>::InvalidStartBlock
+			);
+
+			let (yield_farm_id, amm_pool_id) = Self::do_create_yield_farm(
+				who.clone(),
+				global_farm_id,
+				pool_type,
+				asset_pair,
+				multiplier,
+				loyalty_curve,
+			)?;
+
+			T::LiquidityMiningHandler::stop_yield_farm(who, global_farm_id, amm_pool_id.clone())?;
+
+			ScheduledYieldFarmStarts::<T>::try_mutate(|starts| -> DispatchResult {
+				let idx = starts.partition_point(|(farm_start_at, _, _)| *farm_start_at <= start_at);
+
+				starts
+					.try_insert(idx, (start_at, global_farm_id, yield_farm_id))
+					.map_err(|_| Error::<T>::TooManyScheduledYieldFarms.into())
+			})?;
+
+			YieldFarmStarts::<T>::insert(global_farm_id, yield_farm_id, start_at);
+			ScheduledYieldFarms::<T>::insert(
+				global_farm_id,
+				yield_farm_id,
+				ScheduledYieldFarm {
+					start_at,
+					multiplier,
+					amm_pool_id,
+					pending_deposits: 0,
+				},
+			);
+
+			Self::deposit_event(Event::YieldFarmScheduled {
+				global_farm_id,
+				yield_farm_id,
+				start_at,
+			});
+
+			Ok(())
+		}
+
+		/// Start scheduled yield farm whose start block was reached.
+		///
+		/// Scheduled yield farms are started automatically one per block. This function can be
+		/// used to start the yield farm if its automatic start is delayed. All pending
+		/// deposits enter the yield farm.
+		///
+		/// This function can be called by anyone.
+		///
+		/// Parameters:
+		/// - `origin`: any signed account.
+		/// - `global_farm_id`: global farm id of the yield farm.
+		/// - `yield_farm_id`: id of the scheduled yield farm.
+		/// - `asset_pair`: asset pair identifying yield farm in the global farm.
+		///
+		/// Emits:
+		/// * `SharesDeposited` for each pending deposit
+		/// * `YieldFarmStarted` when successful
+		#[pallet::call_index(26)]
+		#[pallet::weight(Pallet::<T>::start_yield_farm_weight())]
+		pub fn start_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			asset_pair: AssetPair,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let scheduled = ScheduledYieldFarms::<T>::get(global_farm_id, yield_farm_id)
+				.ok_or(Error::<T>::ScheduledYieldFarmNotFound)?;
+
+			let amm_pool_id = Self::ensure_pool(Self::farm_pool_type(global_farm_id, asset_pair), asset_pair)?;
+			ensure!(scheduled.amm_pool_id == amm_pool_id, Error::<T>::InvalidAssetPair);
+
+			Self::start_scheduled_yield_farm(global_farm_id, yield_farm_id)
+		}
+
+		/// Cancel pending deposit and return locked LP shares.
+		///
+		/// Parameters:
+		/// - `origin`: account owning the pending deposit.
+		/// - `global_farm_id`: global farm id of the yield farm.
+		/// - `yield_farm_id`: id of the yield farm.
+		/// - `asset_pair`: asset pair identifying LP shares of the pending deposit.
+		///
+		/// Emits `PendingDepositCancelled` event when successful.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(4, 4))]
+		pub fn cancel_pending_deposit(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			asset_pair: AssetPair,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			//NOTE: don't check pool existance, user must be able to get LP shares back.
+			let amm_share_token = T::AMM::get_share_token(Self::farm_pool_type(global_farm_id, asset_pair), asset_pair)
+				.ok_or(Error::<T>::PoolTypeNotSupported)?;
+
+			let shares_amount = PendingDeposits::<T>::take((global_farm_id, yield_farm_id), &who);
+			ensure!(!shares_amount.is_zero(), Error::<T>::PendingDepositNotFound);
+
+			//NOTE: yield farm may be terminated before its start.
+			ScheduledYieldFarms::<T>::mutate(global_farm_id, yield_farm_id, |maybe_scheduled| {
+				if let Some(scheduled) = maybe_scheduled {
+					scheduled.pending_deposits = scheduled.pending_deposits.saturating_sub(1);
+				}
+			});
+
+			Self::unlock_lp_tokens(amm_share_token, &who, shares_amount)?;
+
+			Self::deposit_event(Event::PendingDepositCancelled {
+				global_farm_id,
+				yield_farm_id,
+				who,
+				amount: shares_amount,
+				lp_token: amm_share_token,
+			});
+
+			Ok(())
+		}
+
+		/// Split deposit into two deposits.
+		///
+		/// This function moves `shares_amount` of deposit's LP shares to a new deposit and mints
+		/// nft for it. New deposit is entered into the same yield farms and keeps the loyalty
+		/// state of the original deposit so no loyalty is lost.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `deposit_id`: nft id representing deposit to split.
+		/// - `shares_amount`: amount of LP shares to move to the new deposit.
+		///
+		/// Emits `DepositSplit` event when successful.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_deposit())]
+		pub fn split_deposit(origin: OriginFor<T>, deposit_id: DepositId, shares_amount: Balance) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+
+			ensure!(!shares_amount.is_zero(), Error::<T>::ZeroSplitAmount);
+
+			let new_deposit_id = T::DepositSplitMergeHandler::split_deposit(deposit_id, shares_amount)?;
+
+			T::NFTHandler::mint_into(&T::NftCollectionId::get(), &new_deposit_id, &owner)?;
+
+			Self::deposit_event(Event::DepositSplit {
+				who: owner,
+				deposit_id,
+				new_deposit_id,
+				shares_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Merge two deposits.
+		///
+		/// This function moves LP shares and yield farm entries of `other_deposit_id` to
+		/// `deposit_id` and burns nft of `other_deposit_id`. Deposits must be in the same pool
+		/// and entered into the same yield farms. Auto-compounding of `other_deposit_id` is
+		/// disabled.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of both deposits(nfts).
+		/// - `deposit_id`: nft id representing deposit to merge into.
+		/// - `other_deposit_id`: nft id representing deposit to be merged and destroyed.
+		///
+		/// Emits `DepositsMerged` event when successful.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_deposits())]
+		pub fn merge_deposits(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			other_deposit_id: DepositId,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+			Self::ensure_deposit_owner(&owner, other_deposit_id)?;
+
+			ensure!(deposit_id != other_deposit_id, Error::<T>::CantMergeDepositWithItself);
+
+			T::DepositSplitMergeHandler::merge_deposits(deposit_id, other_deposit_id)?;
+
+			T::NFTHandler::burn(&T::NftCollectionId::get(), &other_deposit_id, Some(&owner))?;
+			AutoCompound::<T>::remove(other_deposit_id);
+			RewardBeneficiaries::<T>::remove(other_deposit_id);
+
+			Self::deposit_event(Event::DepositsMerged {
+				who: owner,
+				deposit_id,
+				merged_deposit_id: other_deposit_id,
+			});
+
+			Ok(())
+		}
+
+		/// Set account deposit's rewards are paid to.
+		///
+		/// Beneficiary is used only while the deposit is owned by the account which set it. Rewards
+		/// claimed by `claim_rewards`, `claim_all`, on withdrawal and by auto-compounding are paid
+		/// to the beneficiary. Rewards of auto-compounded deposit with beneficiary are paid to the
+		/// beneficiary instead of being compounded.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `deposit_id`: nft id representing deposit in the yield farms.
+		/// - `beneficiary`: account to pay rewards to. `None` removes the beneficiary.
+		///
+		/// Emits `RewardBeneficiarySet` event when successful.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_reward_beneficiary(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			beneficiary: Option<T::AccountId>,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+
+			match beneficiary.clone() {
+				Some(account) => RewardBeneficiaries::<T>::insert(deposit_id, (owner.clone(), account)),
+				None => RewardBeneficiaries::<T>::remove(deposit_id),
+			}
+
+			Self::deposit_event(Event::RewardBeneficiarySet {
+				who: owner,
+				deposit_id,
+				beneficiary,
+			});
+
+			Ok(())
+		}
 
-				Self::deposit_event(Event::DepositDestroyed { who: owner, deposit_id });
-			}
+		/// Claim rewards from liq. mining for deposit represented by `nft_id` to `beneficiary`.
+		///
+		/// This function works as `claim_rewards` but rewards are transferred to `beneficiary`
+		/// regardless of deposit's reward beneficiary.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `deposit_id`: nft id representing deposit in the yield farm.
+		/// - `yield_farm_id`: yield farm identifier to claim rewards from.
+		/// - `beneficiary`: account to transfer claimed rewards to.
+		///
+		/// Emits `RewardClaimed` event for each claim when successful.
+		#[pallet::call_index(32)]
+		#[pallet::weight(Pallet::<T>::claim_rewards_weight())]
+		pub fn claim_rewards_to(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			yield_farm_id: YieldFarmId,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+
+			Self::do_claim_rewards(owner, beneficiary, deposit_id, yield_farm_id)
+		}
+
+		/// Withdraw LP shares of the deposit without claiming rewards and destroy the deposit.
+		///
+		/// This function is meant to be used when `withdraw_shares` fails because of an error in
+		/// the farms' reward accounting e.g. overflow or missing global farm. Deposit is removed
+		/// from all its yield farms without any reward calculations, unclaimed rewards are
+		/// forfeited and LP shares are unlocked.
+		///
+		/// Parameters:
+		/// - `origin`: account owner of deposit(nft).
+		/// - `deposit_id`: nft id representing deposit in the yield farms.
+		///
+		/// Emits:
+		/// * `SharesEmergencyWithdrawn` when LP shares are unlocked
+		/// * `DepositDestroyed` when deposit is destroyed
+		#[pallet::call_index(33)]
+		#[pallet::weight(Pallet::<T>::emergency_withdraw_weight())]
+		pub fn emergency_withdraw(origin: OriginFor<T>, deposit_id: DepositId) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+
+			let (amm_pool_id, shares_amount) =
+				T::LiquidityMiningHandler::emergency_withdraw(deposit_id).ok_or(Error::<T>::DepositDataNotFound)?;
+
+			let lp_token = Self::get_lp_token(PoolTypes::<T>::get(&amm_pool_id), &amm_pool_id)?;
+			Self::unlock_lp_tokens(lp_token, &owner, shares_amount)?;
+
+			T::NFTHandler::burn(&T::NftCollectionId::get(), &deposit_id, Some(&owner))?;
+			AutoCompound::<T>::remove(deposit_id);
+			RewardBeneficiaries::<T>::remove(deposit_id);
+
+			Self::deposit_event(Event::SharesEmergencyWithdrawn {
+				who: owner.clone(),
+				deposit_id,
+				lp_token,
+				amount: shares_amount,
+			});
+
+			Self::deposit_event(Event::DepositDestroyed { who: owner, deposit_id });
+
+			Ok(())
+		}
+
+		/// Create yield farm for given `asset_pair` pool of `pool_type`.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Pool of `pool_type` for `asset_pair` has to exist to successfully create yield farm.
+		/// All the global farm's yield farms for the same `asset_pair` must be created for the pool
+		/// of the same type. Other calls identify the yield farm's pool by `asset_pair` and the
+		/// pool type stored on the yield farm's creation.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id to which a yield farm will be added.
+		/// - `pool_type`: type of the pool identified by `asset_pair`.
+		/// - `asset_pair`: asset pair identifying yield farm. One of the assets in the pair must
+		/// be `incentivized_asset`.
+		/// - `multiplier`: yield farm multiplier.
+		/// - `loyalty_curve`: curve to calculate loyalty multiplier to distribute rewards to users
+		/// with time incentive. `None` means no loyalty multiplier.
+		///
+		/// Emits `YieldFarmCreated` event when successful.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_yield_farm().saturating_add(T::DbWeight::get().reads_writes(1, 2)))]
+		pub fn create_yield_farm_for_pool_type(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			pool_type: PoolType<AssetId>,
+			asset_pair: AssetPair,
+			multiplier: FarmMultiplier,
+			loyalty_curve: Option<LoyaltyCurve>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_create_yield_farm(who, global_farm_id, pool_type, asset_pair, multiplier, loyalty_curve)?;
 
 			Ok(())
 		}
@@ -901,14 +2211,14 @@ impl<T: Config> Pallet<T> {
 		<T as pallet::Config>::PalletId::get().into_account_truncating()
 	}
 
-	fn get_lp_token(amm_pool_id: &T::AccountId) -> Result<AssetId, Error<T>> {
-		let assets = T::AMM::get_pool_assets(amm_pool_id).ok_or(Error::<T>::CantGetXykAssets)?;
+	fn get_lp_token(pool_type: PoolType<AssetId>, amm_pool_id: &T::AccountId) -> Result<AssetId, Error<T>> {
+		let assets = T::AMM::get_pool_assets(pool_type, amm_pool_id).ok_or(Error::<T>::CantGetXykAssets)?;
 		let asset_pair = AssetPair::new(assets[0], assets[1]);
 
 		//NOTE: this check is important AMM:get_share_token() return `0` if amm doesn't exist
-		ensure!(T::AMM::exists(asset_pair), Error::<T>::XykPoolDoesntExist);
+		ensure!(T::AMM::exists(pool_type, asset_pair), Error::<T>::XykPoolDoesntExist);
 
-		Ok(T::AMM::get_share_token(asset_pair))
+		T::AMM::get_share_token(pool_type, asset_pair).ok_or(Error::<T>::PoolTypeNotSupported)
 	}
 
 	fn lock_lp_tokens(lp_token: AssetId, who: &T::AccountId, amount: Balance) -> Result<(), DispatchError> {
@@ -923,39 +2233,919 @@ impl<T: Config> Pallet<T> {
 		T::MultiCurrency::transfer(lp_token, &service_account_for_lp_shares, who, amount)
 	}
 
-	/// This function retuns value of lp tokens in the `asset` currency.
+	/// This function retuns value of lp tokens in the `asset` currency. Valuation is done by
+	/// the AMM of the pool's type.
 	fn get_token_value_of_lp_shares(
 		asset: AssetId,
 		amm_pool_id: T::AccountId,
 		lp_shares_amount: Balance,
 	) -> Result<Balance, DispatchError> {
-		let assets = T::AMM::get_pool_assets(&amm_pool_id).ok_or(Error::<T>::CantGetXykAssets)?;
+		let pool_type = PoolTypes::<T>::get(&amm_pool_id);
+		let assets = T::AMM::get_pool_assets(pool_type, &amm_pool_id).ok_or(Error::<T>::CantGetXykAssets)?;
 
 		ensure!(assets.contains(&asset), Error::<T>::AssetNotInAssetPair);
 
-		let (liquidity_a, liquidity_b) = T::AMM::get_liquidity_behind_shares(assets[0], assets[1], lp_shares_amount)?;
+		T::AMM::get_token_value_of_shares(pool_type, &amm_pool_id, asset, lp_shares_amount)
+	}
+
+	/// Lock LP shares as pending deposit of the scheduled yield farm.
+	fn do_deposit_pending_shares(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		asset_pair: AssetPair,
+		shares_amount: Balance,
+	) -> DispatchResult {
+		let pool_type = Self::farm_pool_type(global_farm_id, asset_pair);
+		Self::ensure_pool(pool_type, asset_pair)?;
+
+		let amm_share_token = T::AMM::get_share_token(pool_type, asset_pair).ok_or(Error::<T>::PoolTypeNotSupported)?;
+
+		ensure!(
+			T::MultiCurrency::ensure_can_withdraw(amm_share_token, &who, shares_amount).is_ok(),
+			Error::<T>::InsufficientXykSharesBalance
+		);
+
+		PendingDeposits::<T>::try_mutate((global_farm_id, yield_farm_id), &who, |pending| -> DispatchResult {
+			if pending.is_zero() {
+				ScheduledYieldFarms::<T>::try_mutate(global_farm_id, yield_farm_id, |maybe_scheduled| {
+					let scheduled = maybe_scheduled.as_mut().ok_or(Error::<T>::ScheduledYieldFarmNotFound)?;
+
+					ensure!(
+						scheduled.pending_deposits < T::MaxPendingDeposits::get(),
+						Error::<T>::TooManyPendingDeposits
+					);
+					scheduled.pending_deposits = scheduled.pending_deposits.saturating_add(1);
+
+					Ok::<(), Error<T>>(())
+				})?;
+			}
+
+			*pending = pending.checked_add(shares_amount).ok_or(ArithmeticError::Overflow)?;
+
+			Ok(())
+		})?;
+
+		Self::lock_lp_tokens(amm_share_token, &who, shares_amount)?;
+
+		Self::deposit_event(Event::PendingSharesDeposited {
+			global_farm_id,
+			yield_farm_id,
+			who,
+			amount: shares_amount,
+			lp_token: amm_share_token,
+		});
+
+		Ok(())
+	}
+
+	/// Start scheduled yield farm if its start block was reached and enter all its pending
+	/// deposits into the yield farm.
+	fn start_scheduled_yield_farm(global_farm_id: GlobalFarmId, yield_farm_id: YieldFarmId) -> DispatchResult {
+		let scheduled = ScheduledYieldFarms::<T>::get(global_farm_id, yield_farm_id)
+			.ok_or(Error::<T>::ScheduledYieldFarmNotFound)?;
+
+		ensure!(
+			T::BlockNumberProvider::current_block_number() >= scheduled.start_at,
+			Error::<T>::YieldFarmNotStarted
+		);
+
+		let owner =
+			T::LiquidityMiningHandler::global_farm_owner(global_farm_id).ok_or(Error::<T>::GlobalFarmNotFound)?;
+
+		T::LiquidityMiningHandler::resume_yield_farm(
+			owner,
+			global_farm_id,
+			yield_farm_id,
+			scheduled.amm_pool_id.clone(),
+			scheduled.multiplier,
+		)?;
+
+		Self::remove_scheduled_yield_farm(global_farm_id, yield_farm_id);
+		Self::enter_pending_deposits(global_farm_id, yield_farm_id, scheduled.amm_pool_id, scheduled.start_at)?;
+
+		Self::deposit_event(Event::YieldFarmStarted {
+			global_farm_id,
+			yield_farm_id,
+		});
+
+		Ok(())
+	}
+
+	/// Start the first scheduled yield farm if its start block was reached. Yield farm which
+	/// fails to start is dropped from the start queue and it can be started by the owner.
+	/// This function returns `true` if start of the yield farm was attempted.
+	fn start_next_scheduled_yield_farm() -> bool {
+		let Some((start_at, global_farm_id, yield_farm_id)) = ScheduledYieldFarmStarts::<T>::get().first().copied()
+		else {
+			return false;
+		};
+
+		if T::BlockNumberProvider::current_block_number() < start_at {
+			return false;
+		}
+
+		if let Err(e) = frame_support::storage::with_storage_layer(|| {
+			Self::start_scheduled_yield_farm(global_farm_id, yield_farm_id)
+		}) {
+			log::error!(
+				target: "runtime::xyk-liquidity-mining",
+				"failed to start scheduled yield farm: {:?}, global farm: {:?}, error: {:?}",
+				yield_farm_id,
+				global_farm_id,
+				e
+			);
+
+			ScheduledYieldFarmStarts::<T>::mutate(|starts| {
+				starts.retain(|(_, gf_id, yf_id)| (*gf_id, *yf_id) != (global_farm_id, yield_farm_id))
+			});
+		}
+
+		true
+	}
+
+	/// Enter pending deposits of the scheduled yield farm into the yield farm. LP shares were
+	/// locked when pending deposits were created. All pending deposits are entered as of the
+	/// period of `start_at` so order and time of their entry doesn't matter. This function
+	/// returns number of entered deposits.
+	fn enter_pending_deposits(
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		amm_pool_id: T::AccountId,
+		start_at: BlockNumberFor<T>,
+	) -> Result<u32, DispatchError> {
+		let lp_token = Self::get_lp_token(PoolTypes::<T>::get(&amm_pool_id), &amm_pool_id)?;
+
+		let mut entered_deposits: u32 = 0;
+		for (who, shares_amount) in PendingDeposits::<T>::drain_prefix((global_farm_id, yield_farm_id)) {
+			let deposit_id = T::LiquidityMiningHandler::deposit_lp_shares(
+				global_farm_id,
+				yield_farm_id,
+				amm_pool_id.clone(),
+				shares_amount,
+				Self::get_token_value_of_lp_shares,
+			)?;
+			T::LiquidityMiningHandler::set_entry_start(deposit_id, yield_farm_id, start_at)?;
+
+			T::NFTHandler::mint_into(&T::NftCollectionId::get(), &deposit_id, &who)?;
+
+			Self::deposit_event(Event::SharesDeposited {
+				global_farm_id,
+				yield_farm_id,
+				who,
+				amount: shares_amount,
+				lp_token,
+				deposit_id,
+			});
+
+			entered_deposits = entered_deposits.saturating_add(1);
+		}
+
+		Ok(entered_deposits)
+	}
+
+	/// Remove yield farm from the scheduled yield farms and its start from the start queue.
+	fn remove_scheduled_yield_farm(
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+	) -> Option<ScheduledYieldFarm<T::AccountId, BlockNumberFor<T>>> {
+		let scheduled = ScheduledYieldFarms::<T>::take(global_farm_id, yield_farm_id)?;
+
+		ScheduledYieldFarmStarts::<T>::mutate(|starts| {
+			starts.retain(|(_, gf_id, yf_id)| (*gf_id, *yf_id) != (global_farm_id, yield_farm_id))
+		});
+
+		Some(scheduled)
+	}
+
+	/// This function returns account id of the pool of `pool_type` for `asset_pair` if such
+	/// pool exists.
+	fn ensure_pool(pool_type: PoolType<AssetId>, asset_pair: AssetPair) -> Result<T::AccountId, Error<T>> {
+		let amm_pool_id = T::AMM::get_pool_id(pool_type, asset_pair).ok_or(Error::<T>::PoolTypeNotSupported)?;
+
+		ensure!(T::AMM::exists(pool_type, asset_pair), Error::<T>::XykPoolDoesntExist);
+
+		Ok(amm_pool_id)
+	}
+
+	/// This function returns type of the pool the global farm's yield farms for `asset_pair` were
+	/// created for.
+	fn farm_pool_type(global_farm_id: GlobalFarmId, asset_pair: AssetPair) -> PoolType<AssetId> {
+		FarmPoolTypes::<T>::get(global_farm_id, ordered_pair(asset_pair.asset_in, asset_pair.asset_out))
+			.unwrap_or(PoolType::XYK)
+	}
+
+	/// This function returns account id of the pool the global farm's yield farms for
+	/// `asset_pair` were created for. Pool's existence is not checked.
+	fn farm_pool_id(global_farm_id: GlobalFarmId, asset_pair: AssetPair) -> Result<T::AccountId, Error<T>> {
+		T::AMM::get_pool_id(Self::farm_pool_type(global_farm_id, asset_pair), asset_pair)
+			.ok_or(Error::<T>::PoolTypeNotSupported)
+	}
+
+	/// This function returns type and account id of the deposit's pool if `asset_pair`
+	/// identifies it and the pool exists.
+	fn deposit_pool(
+		deposit_id: DepositId,
+		asset_pair: AssetPair,
+	) -> Result<(PoolType<AssetId>, T::AccountId), DispatchError> {
+		let (deposit_amm_pool_id, _) =
+			<T::LiquidityMiningHandler as DepositLpShares<T::AccountId>>::deposit_lp_shares(deposit_id)
+				.ok_or(Error::<T>::DepositDataNotFound)?;
+
+		let pool_type = PoolTypes::<T>::get(&deposit_amm_pool_id);
+		let amm_pool_id = Self::ensure_pool(pool_type, asset_pair)?;
+		ensure!(amm_pool_id == deposit_amm_pool_id, Error::<T>::InvalidAssetPair);
+
+		Ok((pool_type, amm_pool_id))
+	}
+
+	fn do_create_yield_farm(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		pool_type: PoolType<AssetId>,
+		asset_pair: AssetPair,
+		multiplier: FarmMultiplier,
+		loyalty_curve: Option<LoyaltyCurve>,
+	) -> Result<(YieldFarmId, T::AccountId), DispatchError> {
+		let amm_pool_id = Self::ensure_pool(pool_type, asset_pair)?;
+
+		let assets = ordered_pair(asset_pair.asset_in, asset_pair.asset_out);
+		ensure!(
+			FarmPoolTypes::<T>::get(global_farm_id, assets).map_or(true, |farm_pool_type| farm_pool_type == pool_type),
+			Error::<T>::PoolTypeMismatch
+		);
+
+		let yield_farm_id = T::LiquidityMiningHandler::create_yield_farm(
+			who,
+			global_farm_id,
+			multiplier,
+			loyalty_curve.clone(),
+			amm_pool_id.clone(),
+			vec![asset_pair.asset_in, asset_pair.asset_out],
+		)?;
+
+		PoolTypes::<T>::insert(&amm_pool_id, pool_type);
+		FarmPoolTypes::<T>::insert(global_farm_id, assets, pool_type);
+
+		Self::deposit_event(Event::YieldFarmCreated {
+			global_farm_id,
+			yield_farm_id,
+			multiplier,
+			loyalty_curve,
+			pool_type,
+			asset_pair,
+		});
+
+		Ok((yield_farm_id, amm_pool_id))
+	}
+
+	fn do_deposit_shares(
+		who: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		asset_pair: AssetPair,
+		shares_amount: Balance,
+	) -> Result<DepositId, DispatchError> {
+		let pool_type = Self::farm_pool_type(global_farm_id, asset_pair);
+		let amm_pool_id = Self::ensure_pool(pool_type, asset_pair)?;
+
+		let amm_share_token = T::AMM::get_share_token(pool_type, asset_pair).ok_or(Error::<T>::PoolTypeNotSupported)?;
+
+		ensure!(
+			T::MultiCurrency::ensure_can_withdraw(amm_share_token, &who, shares_amount).is_ok(),
+			Error::<T>::InsufficientXykSharesBalance
+		);
+
+		let deposit_id = T::LiquidityMiningHandler::deposit_lp_shares(
+			global_farm_id,
+			yield_farm_id,
+			amm_pool_id,
+			shares_amount,
+			Self::get_token_value_of_lp_shares,
+		)?;
+
+		Self::lock_lp_tokens(amm_share_token, &who, shares_amount)?;
+		T::NFTHandler::mint_into(&T::NftCollectionId::get(), &deposit_id, &who)?;
+
+		Self::deposit_event(Event::SharesDeposited {
+			global_farm_id,
+			yield_farm_id,
+			who,
+			amount: shares_amount,
+			lp_token: amm_share_token,
+			deposit_id,
+		});
+
+		Ok(deposit_id)
+	}
+
+	fn do_redeposit_shares(
+		owner: T::AccountId,
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		asset_pair: AssetPair,
+		deposit_id: DepositId,
+	) -> DispatchResult {
+		let pool_type = Self::farm_pool_type(global_farm_id, asset_pair);
+		let amm_pool_id = Self::ensure_pool(pool_type, asset_pair)?;
+
+		let amm_share_token = T::AMM::get_share_token(pool_type, asset_pair).ok_or(Error::<T>::PoolTypeNotSupported)?;
+
+		let (shares_amount, deposit_amm_pool_id) = T::LiquidityMiningHandler::redeposit_lp_shares(
+			global_farm_id,
+			yield_farm_id,
+			deposit_id,
+			Self::get_token_value_of_lp_shares,
+		)?;
+
+		ensure!(amm_pool_id == deposit_amm_pool_id, Error::<T>::InvalidAssetPair);
+
+		Self::deposit_event(Event::SharesRedeposited {
+			global_farm_id,
+			yield_farm_id,
+			who: owner,
+			amount: shares_amount,
+			lp_token: amm_share_token,
+			deposit_id,
+		});
+
+		Ok(())
+	}
+
+	/// This function returns withdrawn amount of LP shares and `true` if the deposit was
+	/// destroyed and LP shares were unlocked.
+	fn do_withdraw_shares(
+		owner: T::AccountId,
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+		asset_pair: AssetPair,
+	) -> Result<(Balance, bool), DispatchError> {
+		let (pool_type, amm_pool_id) = Self::deposit_pool(deposit_id, asset_pair)?;
+
+		let global_farm_id = T::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
+			.ok_or(Error::<T>::DepositDataNotFound)?;
+
+		//NOTE: claimed rewards are transferred to the account passed to the withdrawal.
+		let beneficiary = Self::reward_beneficiary(&owner, deposit_id);
+		let (withdrawn_amount, claim_data, is_destroyed) = T::LiquidityMiningHandler::withdraw_lp_shares(
+			beneficiary.clone(),
+			deposit_id,
+			global_farm_id,
+			yield_farm_id,
+			amm_pool_id.clone(),
+		)?;
+
+		if let Some((reward_currency, claimed, _)) = claim_data {
+			if !claimed.is_zero() {
+				Self::deposit_event(Event::RewardClaimed {
+					global_farm_id,
+					yield_farm_id,
+					who: owner.clone(),
+					claimed,
+					reward_currency,
+					deposit_id,
+					beneficiary,
+				});
+			}
+		}
+
+		let lp_token = Self::get_lp_token(pool_type, &amm_pool_id)?;
+		if !withdrawn_amount.is_zero() {
+			Self::deposit_event(Event::SharesWithdrawn {
+				global_farm_id,
+				yield_farm_id,
+				who: owner.clone(),
+				lp_token,
+				amount: withdrawn_amount,
+				deposit_id,
+			});
+		}
+
+		if is_destroyed {
+			Self::unlock_lp_tokens(lp_token, &owner, withdrawn_amount)?;
+			T::NFTHandler::burn(&T::NftCollectionId::get(), &deposit_id, Some(&owner))?;
+			AutoCompound::<T>::remove(deposit_id);
+			RewardBeneficiaries::<T>::remove(deposit_id);
+
+			Self::deposit_event(Event::DepositDestroyed { who: owner, deposit_id });
+		}
+
+		Ok((withdrawn_amount, is_destroyed))
+	}
+
+	fn add_liquidity_and_join_farms_weight(farm_entries: u32) -> Weight {
+		T::AMMLiquidityHandler::add_liquidity_weight()
+			.saturating_add(<T as Config>::WeightInfo::deposit_shares())
+			.saturating_add(
+				<T as Config>::WeightInfo::redeposit_shares().saturating_mul(farm_entries.saturating_sub(1).into()),
+			)
+	}
+
+	fn enter_pending_deposits_weight(pending_deposits: u32) -> Weight {
+		//NOTE: LP shares of pending deposits are already locked so `deposit_shares` overestimates.
+		<T as Config>::WeightInfo::deposit_shares()
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+			.saturating_mul(pending_deposits.into())
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+
+	fn start_yield_farm_weight() -> Weight {
+		<T as Config>::WeightInfo::resume_yield_farm()
+			.saturating_add(Self::enter_pending_deposits_weight(T::MaxPendingDeposits::get()))
+			.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+	}
+
+	fn emergency_withdraw_weight() -> Weight {
+		//NOTE: benchmark is done with single farm entry, every other entry updates its yield
+		//and global farm.
+		<T as Config>::WeightInfo::emergency_withdraw().saturating_add(
+			T::DbWeight::get()
+				.reads_writes(2, 2)
+				.saturating_mul(T::MaxFarmEntriesPerDeposit::get().saturating_sub(1).into()),
+		)
+	}
+
+	/// Weight of withdrawing LP shares from the yield farm including read of the deposit's
+	/// reward beneficiary and global farm's price adjustment lookup.
+	fn withdraw_shares_weight() -> Weight {
+		<T as Config>::WeightInfo::withdraw_shares()
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Self::price_adjustment_weight())
+	}
+
+	fn exit_farms_and_remove_liquidity_weight(farm_entries: u32) -> Weight {
+		Self::withdraw_shares_weight()
+			.saturating_mul(farm_entries.into())
+			.saturating_add(T::AMMLiquidityHandler::remove_liquidity_weight())
+	}
+
+	/// Claim rewards from all yield farms of the deposit. Yield farm entries which can't be
+	/// claimed are skipped and claimed amounts are accumulated per reward currency in
+	/// `claimed_rewards`.
+	///
+	/// This function returns number of processed yield farm entries.
+	fn claim_deposit_rewards(
+		who: &T::AccountId,
+		beneficiary: &T::AccountId,
+		deposit_id: DepositId,
+		claimed_rewards: &mut BTreeMap<AssetId, Balance>,
+	) -> u32 {
+		let mut claims_count: u32 = 0;
+
+		for yield_farm_id in T::LiquidityMiningHandler::yield_farm_ids(deposit_id) {
+			claims_count = claims_count.saturating_add(1);
+
+			//NOTE: failed claim must not prevent claiming from the rest of the farms.
+			let Ok((global_farm_id, reward_currency, claimed, _)) = frame_support::storage::with_storage_layer(|| {
+				T::LiquidityMiningHandler::claim_rewards(beneficiary.clone(), deposit_id, yield_farm_id)
+			}) else {
+				continue;
+			};
+
+			if claimed.is_zero() {
+				continue;
+			}
+
+			let total_claimed = claimed_rewards.entry(reward_currency).or_default();
+			*total_claimed = total_claimed.saturating_add(claimed);
+
+			Self::deposit_event(Event::RewardClaimed {
+				global_farm_id,
+				yield_farm_id,
+				who: who.clone(),
+				claimed,
+				reward_currency,
+				deposit_id,
+				beneficiary: beneficiary.clone(),
+			});
+		}
+
+		claims_count
+	}
+
+	/// Claim rewards from the deposit's yield farm to `beneficiary`.
+	fn do_claim_rewards(
+		owner: T::AccountId,
+		beneficiary: T::AccountId,
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+	) -> DispatchResult {
+		let (global_farm_id, reward_currency, claimed, _) =
+			T::LiquidityMiningHandler::claim_rewards(beneficiary.clone(), deposit_id, yield_farm_id)?;
+
+		ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
+
+		Self::deposit_event(Event::RewardClaimed {
+			global_farm_id,
+			yield_farm_id,
+			who: owner,
+			claimed,
+			reward_currency,
+			deposit_id,
+			beneficiary,
+		});
+
+		Ok(())
+	}
+
+	/// Capture oracle entries used to price the global farm in the `Oracle` mode.
+	fn capture_oracle_pricing_entries(global_farm_id: GlobalFarmId) {
+		let Some((incentivized_asset, reward_currency)) = T::LiquidityMiningHandler::global_farm_assets(global_farm_id)
+		else {
+			return;
+		};
+
+		if incentivized_asset == reward_currency {
+			return;
+		}
+
+		let entries: BoundedVec<_, ConstU32<MAX_ORACLE_PRICING_ENTRIES>> =
+			BoundedVec::truncate_from(T::Swap::oracle_pairs(incentivized_asset, reward_currency));
+		for (source, asset_a, asset_b) in entries.iter() {
+			OraclePricedEntries::<T>::mutate((source, ordered_pair(*asset_a, *asset_b)), |count| {
+				*count = count.saturating_add(1)
+			});
+		}
+
+		OraclePricingEntries::<T>::insert(global_farm_id, entries);
+	}
+
+	/// Release oracle entries captured to price the global farm.
+	fn release_oracle_pricing_entries(global_farm_id: GlobalFarmId) {
+		for (source, asset_a, asset_b) in OraclePricingEntries::<T>::take(global_farm_id) {
+			OraclePricedEntries::<T>::mutate_exists((source, ordered_pair(asset_a, asset_b)), |maybe_count| {
+				*maybe_count = maybe_count
+					.and_then(|count| count.checked_sub(1))
+					.filter(|count| !count.is_zero());
+			});
+		}
+	}
+
+	/// Return true if the oracle entry is used to price a global farm in the `Oracle` mode. Order
+	/// of the assets doesn't matter.
+	pub fn is_oracle_pricing_entry(source: Source, asset_a: AssetId, asset_b: AssetId) -> bool {
+		OraclePricedEntries::<T>::contains_key((source, ordered_pair(asset_a, asset_b)))
+	}
+
+	/// Account deposit's rewards are paid to. Beneficiary set by previous owner of the deposit is
+	/// ignored.
+	fn reward_beneficiary(owner: &T::AccountId, deposit_id: DepositId) -> T::AccountId {
+		match RewardBeneficiaries::<T>::get(deposit_id) {
+			Some((set_by, beneficiary)) if set_by == *owner => beneficiary,
+			_ => owner.clone(),
+		}
+	}
+
+	/// Claim deposit's rewards, swap them to the pool's assets, add liquidity and add minted
+	/// LP shares to the deposit.
+	fn do_compound(deposit_id: DepositId, now: BlockNumberFor<T>) -> DispatchResult {
+		let mut compounding = AutoCompound::<T>::get(deposit_id).ok_or(Error::<T>::AutoCompoundingNotEnabled)?;
+
+		ensure!(
+			now >= compounding
+				.last_compounded_at
+				.saturating_add(T::CompoundingPeriod::get()),
+			Error::<T>::CompoundingNotDue
+		);
+
+		let owner =
+			T::NFTHandler::owner(&T::NftCollectionId::get(), &deposit_id).ok_or(Error::<T>::CantFindDepositOwner)?;
+		ensure!(owner == compounding.owner, Error::<T>::DepositOwnerChanged);
+
+		let (asset_a, asset_b) = compounding.assets;
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+		let amm_pool_id = Self::ensure_pool(compounding.pool_type, asset_pair)?;
+
+		let beneficiary = Self::reward_beneficiary(&owner, deposit_id);
+		let mut claimed_rewards: BTreeMap<AssetId, Balance> = BTreeMap::new();
+		Self::claim_deposit_rewards(&owner, &beneficiary, deposit_id, &mut claimed_rewards);
+
+		ensure!(!claimed_rewards.is_empty(), Error::<T>::ZeroClaimedRewards);
+
+		//NOTE: rewards paid to the beneficiary are not compounded.
+		if beneficiary != owner {
+			compounding.last_compounded_at = now;
+			AutoCompound::<T>::insert(deposit_id, compounding);
+
+			return Ok(());
+		}
+
+		let (mut amount_a, mut amount_b): (Balance, Balance) = (0, 0);
+		for (reward_currency, claimed) in claimed_rewards {
+			let (a, b) = Self::swap_to_pool_assets(&owner, reward_currency, claimed, asset_a, asset_b)?;
+
+			amount_a = amount_a.saturating_add(a);
+			amount_b = amount_b.saturating_add(b);
+		}
+
+		//NOTE: swaps changed the pool's price so we add max. amount of `asset_a` which can be
+		//matched by `amount_b` with the current price. Leftovers stay in the owner's account.
+		let reserve_a = T::MultiCurrency::free_balance(asset_a, &amm_pool_id);
+		let reserve_b = T::MultiCurrency::free_balance(asset_b, &amm_pool_id);
+		let matched_amount_a = multiply_by_rational_with_rounding(amount_b, reserve_a, reserve_b, Rounding::Down)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let shares_amount = T::AMMLiquidityHandler::add_liquidity(
+			&owner,
+			compounding.pool_type,
+			asset_pair,
+			amount_a.min(matched_amount_a),
+			amount_b,
+		)?;
+
+		let amm_share_token =
+			T::AMM::get_share_token(compounding.pool_type, asset_pair).ok_or(Error::<T>::PoolTypeNotSupported)?;
+		Self::lock_lp_tokens(amm_share_token, &owner, shares_amount)?;
+		T::DepositSharesHandler::increase_deposit_shares(
+			deposit_id,
+			amm_pool_id,
+			shares_amount,
+			Self::get_token_value_of_lp_shares,
+		)?;
+
+		compounding.last_compounded_at = now;
+		AutoCompound::<T>::insert(deposit_id, compounding);
+
+		Self::deposit_event(Event::RewardsCompounded {
+			who: owner,
+			deposit_id,
+			shares_amount,
+		});
+
+		Ok(())
+	}
+
+	/// Swap `amount` of `asset` so the result can be added as liquidity to the pool of
+	/// `asset_a` and `asset_b`.
+	///
+	/// This function returns amounts of `asset_a` and `asset_b` available after the swaps.
+	fn swap_to_pool_assets(
+		who: &T::AccountId,
+		asset: AssetId,
+		amount: Balance,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (asset, amount) = if asset == asset_a || asset == asset_b {
+			(asset, amount)
+		} else {
+			(asset_a, Self::sell_with_oracle_check(who, asset, asset_a, amount)?)
+		};
+
+		let sell_amount = amount / 2;
+		let remaining_amount = amount.saturating_sub(sell_amount);
+
+		if asset == asset_a {
+			let received = Self::sell_with_oracle_check(who, asset_a, asset_b, sell_amount)?;
+
+			Ok((remaining_amount, received))
+		} else {
+			let received = Self::sell_with_oracle_check(who, asset_b, asset_a, sell_amount)?;
+
+			Ok((received, remaining_amount))
+		}
+	}
+
+	/// Sell `amount_in` of `asset_in` with min. amount out derived from the oracle price.
+	fn sell_with_oracle_check(
+		who: &T::AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, DispatchError> {
+		if amount_in.is_zero() {
+			return Ok(Zero::zero());
+		}
+
+		let (price_n, price_d) =
+			T::Swap::oracle_price(asset_in, asset_out).ok_or(Error::<T>::OraclePriceNotAvailable)?;
+
+		let oracle_amount_out = multiply_by_rational_with_rounding(amount_in, price_n, price_d, Rounding::Down)
+			.ok_or(ArithmeticError::Overflow)?;
+		let min_amount_out =
+			oracle_amount_out.saturating_sub(T::MaxCompoundingSlippage::get().mul_ceil(oracle_amount_out));
+
+		T::Swap::sell(who, asset_in, asset_out, amount_in, min_amount_out)
+	}
+
+	/// Compound rewards of the deposits with enabled auto-compounding until `remaining_weight`
+	/// is used. Processing continues from the last processed deposit in the next block.
+	fn compound_deposits(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let compound_weight = Self::compound_weight();
+		// read of the entry and worst case compounding
+		let max_iteration_weight = db_weight.reads(1).saturating_add(compound_weight);
+
+		// read and write of the cursor
+		let mut used_weight = db_weight.reads_writes(1, 1);
+		if used_weight
+			.saturating_add(max_iteration_weight)
+			.any_gt(remaining_weight)
+		{
+			return Weight::zero();
+		}
+
+		let deposits = match CompoundingCursor::<T>::get() {
+			Some(deposit_id) => AutoCompound::<T>::iter_from(AutoCompound::<T>::hashed_key_for(deposit_id)),
+			None => AutoCompound::<T>::iter(),
+		};
+
+		let mut exhausted = true;
+		let mut last_processed = None;
+		for (deposit_id, compounding) in deposits {
+			if used_weight
+				.saturating_add(max_iteration_weight)
+				.any_gt(remaining_weight)
+			{
+				exhausted = false;
+				break;
+			}
 
-		if assets[0] == asset {
-			return Ok(liquidity_a);
+			used_weight.saturating_accrue(db_weight.reads(1));
+			last_processed = Some(deposit_id);
+
+			if now
+				< compounding
+					.last_compounded_at
+					.saturating_add(T::CompoundingPeriod::get())
+			{
+				continue;
+			}
+
+			used_weight.saturating_accrue(compound_weight);
+
+			//NOTE: failed compounding must not block compounding of other deposits.
+			match frame_support::storage::with_storage_layer(|| Self::do_compound(deposit_id, now)) {
+				Ok(()) => {}
+				Err(e) if e == DispatchError::from(Error::<T>::DepositOwnerChanged) => {
+					AutoCompound::<T>::remove(deposit_id);
+				}
+				Err(_) => {
+					// retry in the next compounding period
+					AutoCompound::<T>::insert(
+						deposit_id,
+						AutoCompoundingData {
+							last_compounded_at: now,
+							..compounding
+						},
+					);
+				}
+			}
 		}
 
-		Ok(liquidity_b)
+		if exhausted {
+			CompoundingCursor::<T>::kill();
+		} else if let Some(deposit_id) = last_processed {
+			CompoundingCursor::<T>::put(deposit_id);
+		}
+
+		used_weight
+	}
+
+	/// Worst case weight of compounding deposit's rewards.
+	/// Worst case weight of compounding the deposit's rewards. Deposit has max. number of farm
+	/// entries each paying rewards in different currency which requires 2 swaps.
+	fn compound_weight() -> Weight {
+		let entries = T::MaxFarmEntriesPerDeposit::get();
+
+		//NOTE: increase of deposit's shares values and updates every farm entry the same way
+		//redeposit does, so `redeposit_shares` is used for every entry.
+		Self::claim_rewards_weight()
+			.saturating_add(<T as Config>::WeightInfo::redeposit_shares())
+			.saturating_add(T::Swap::sell_weight().saturating_mul(2))
+			.saturating_mul(entries.into())
+			.saturating_add(T::AMMLiquidityHandler::add_liquidity_weight())
+			// auto-compounding data, deposit's owner and lock of the LP shares
+			.saturating_add(T::DbWeight::get().reads_writes(5, 3))
+	}
+
+	fn force_terminate_global_farm_weight(yield_farms_count: u32) -> Weight {
+		<T as Config>::WeightInfo::stop_yield_farm()
+			.saturating_add(<T as Config>::WeightInfo::terminate_yield_farm())
+			.saturating_add(T::DbWeight::get().reads_writes(3, 5))
+			.saturating_mul(yield_farms_count.into())
+			.saturating_add(<T as Config>::WeightInfo::terminate_global_farm())
+			.saturating_add(T::DbWeight::get().reads_writes(5, 5))
+			.saturating_add(Self::oracle_pricing_entries_weight())
+	}
+
+	/// Worst case weight of releasing and capturing the oracle entries used to price the global
+	/// farm.
+	fn oracle_pricing_entries_weight() -> Weight {
+		let entries: u64 = MAX_ORACLE_PRICING_ENTRIES.into();
+
+		// route and global farm are read when captured, every entry is released and captured
+		T::DbWeight::get().reads_writes(
+			entries.saturating_mul(2).saturating_add(3),
+			entries.saturating_mul(2).saturating_add(2),
+		)
+	}
+
+	/// Weight of the global farm's price adjustment lookup done by liq. mining when the global
+	/// farm is synced. Price adjustment of the farms in the `Oracle` mode is queried from the
+	/// oracle.
+	fn price_adjustment_weight() -> Weight {
+		// price adjustment mode is read for every farm
+		T::Swap::oracle_price_weight().saturating_add(T::DbWeight::get().reads(1))
+	}
+
+	/// Weight of claiming rewards from the yield farm including read of the deposit's reward
+	/// beneficiary and global farm's price adjustment lookup.
+	fn claim_rewards_weight() -> Weight {
+		<T as Config>::WeightInfo::claim_rewards()
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Self::price_adjustment_weight())
 	}
 
-	fn ensure_xyk(asset_pair: AssetPair) -> Result<T::AccountId, Error<T>> {
-		ensure!(T::AMM::exists(asset_pair), Error::<T>::XykPoolDoesntExist);
+	/// Weight of claiming rewards from `deposits` deposits with max. number of farm entries.
+	fn claim_all_weight(deposits: u32) -> Weight {
+		let max_claims = deposits.saturating_mul(T::MaxFarmEntriesPerDeposit::get());
 
-		Ok(T::AMM::get_pair_id(asset_pair))
+		<T as Config>::WeightInfo::claim_rewards()
+			.saturating_add(Self::price_adjustment_weight())
+			.saturating_mul(max_claims.into())
+			.saturating_add(T::DbWeight::get().reads(deposits.into()))
 	}
 
 	fn ensure_nft_owner(origin: OriginFor<T>, deposit_id: DepositId) -> Result<T::AccountId, DispatchError> {
 		let who = ensure_signed(origin)?;
 
+		Self::ensure_deposit_owner(&who, deposit_id)?;
+
+		Ok(who)
+	}
+
+	fn ensure_deposit_owner(who: &T::AccountId, deposit_id: DepositId) -> DispatchResult {
 		let nft_owner =
 			T::NFTHandler::owner(&T::NftCollectionId::get(), &deposit_id).ok_or(Error::<T>::CantFindDepositOwner)?;
 
-		ensure!(nft_owner == who, Error::<T>::NotDepositOwner);
+		ensure!(nft_owner == *who, Error::<T>::NotDepositOwner);
 
-		Ok(who)
+		//NOTE: seller must not be able to claim rewards or withdraw LP shares between listing
+		//and sale of the deposit.
+		ensure!(
+			!T::ListedDeposits::contains(&deposit_id),
+			Error::<T>::DepositIsListedForSale
+		);
+
+		Ok(())
+	}
+
+	/// Return valuation of the deposit or `None` if deposit doesn't exist.
+	///
+	/// Claimable rewards are calculated by claiming rewards from all the deposit's yield farms
+	/// in a transactional layer which is always rolled back so this function doesn't change
+	/// the state. Yield farms which can't be claimed are not included in the `rewards`.
+	pub fn deposit_valuation(deposit_id: DepositId) -> Option<DepositValuation<T::AccountId>> {
+		let owner = T::NFTHandler::owner(&T::NftCollectionId::get(), &deposit_id)?;
+		let (amm_pool_id, shares) =
+			<T::LiquidityMiningHandler as DepositLpShares<T::AccountId>>::deposit_lp_shares(deposit_id)?;
+
+		let pool_type = PoolTypes::<T>::get(&amm_pool_id);
+		let lp_token = Self::get_lp_token(pool_type, &amm_pool_id).ok()?;
+		let liquidity = T::AMM::get_pool_assets(pool_type, &amm_pool_id)?
+			.into_iter()
+			.map(|asset| {
+				let amount = Self::get_token_value_of_lp_shares(asset, amm_pool_id.clone(), shares).unwrap_or_default();
+
+				(asset, amount)
+			})
+			.collect();
+
+		let rewards = with_transaction(|| {
+			let rewards: Vec<(GlobalFarmId, YieldFarmId, AssetId, Balance)> =
+				T::LiquidityMiningHandler::yield_farm_ids(deposit_id)
+					.into_iter()
+					.filter_map(|yield_farm_id| {
+						let (global_farm_id, reward_currency, claimed, _) =
+							T::LiquidityMiningHandler::claim_rewards(owner.clone(), deposit_id, yield_farm_id).ok()?;
+
+						Some((global_farm_id, yield_farm_id, reward_currency, claimed))
+					})
+					.collect();
+
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(rewards))
+		})
+		.ok()?;
+
+		Some(DepositValuation {
+			owner,
+			amm_pool_id,
+			lp_token,
+			shares,
+			liquidity,
+			rewards,
+		})
+	}
+}
+
+fn ordered_pair(asset_a: AssetId, asset_b: AssetId) -> (AssetId, AssetId) {
+	if asset_a < asset_b {
+		(asset_a, asset_b)
+	} else {
+		(asset_b, asset_a)
 	}
 }
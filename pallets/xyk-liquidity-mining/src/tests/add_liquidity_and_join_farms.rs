@@ -0,0 +1,265 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn add_liquidity_and_join_farms_should_work_with_multiple_farm_entries() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX, 1_000 * ONE),
+			(ALICE, KSM, 1_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_yield_farm(BOB, 2, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			set_block_number(1_800);
+			let added_amount = 100 * ONE;
+
+			//Act
+			assert_ok!(LiquidityMining::add_liquidity_and_join_farms(
+				Origin::signed(ALICE),
+				BSX_KSM_ASSET_PAIR,
+				added_amount,
+				200 * ONE,
+				vec![(1, 3), (2, 4)].try_into().unwrap(),
+			));
+
+			//Assert
+			pretty_assertions::assert_eq!(
+				has_event(
+					crate::Event::SharesDeposited {
+						global_farm_id: 1,
+						yield_farm_id: 3,
+						who: ALICE,
+						lp_token: BSX_KSM_SHARE_ID,
+						amount: added_amount,
+						deposit_id: 1
+					}
+					.into()
+				),
+				true
+			);
+
+			assert_last_event!(crate::Event::SharesRedeposited {
+				global_farm_id: 2,
+				yield_farm_id: 4,
+				who: ALICE,
+				lp_token: BSX_KSM_SHARE_ID,
+				amount: added_amount,
+				deposit_id: 1
+			}
+			.into());
+
+			pretty_assertions::assert_eq!(Tokens::free_balance(BSX_KSM_SHARE_ID, &ALICE), 0);
+			pretty_assertions::assert_eq!(
+				Tokens::free_balance(BSX_KSM_SHARE_ID, &LiquidityMining::account_id()),
+				added_amount
+			);
+			pretty_assertions::assert_eq!(Tokens::free_balance(BSX, &ALICE), 900 * ONE);
+			pretty_assertions::assert_eq!(Tokens::free_balance(KSM, &ALICE), 900 * ONE);
+
+			let nft_owner: AccountId = DummyNFT::owner(&LM_NFT_COLLECTION, &1).unwrap();
+			pretty_assertions::assert_eq!(nft_owner, ALICE);
+			pretty_assertions::assert_eq!(NFTS.with(|v| v.borrow().len()), 1);
+		});
+}
+
+#[test]
+fn add_liquidity_and_join_farms_should_fail_when_no_farm_entries_are_specified() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX, 1_000 * ONE),
+			(ALICE, KSM, 1_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::add_liquidity_and_join_farms(
+					Origin::signed(ALICE),
+					BSX_KSM_ASSET_PAIR,
+					100 * ONE,
+					200 * ONE,
+					vec![].try_into().unwrap(),
+				),
+				Error::<Test>::NoFarmEntriesSpecified
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_and_join_farms_should_fail_when_xyk_pool_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX, 1_000 * ONE),
+			(ALICE, DOT, 1_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::add_liquidity_and_join_farms(
+					Origin::signed(ALICE),
+					BSX_DOT_ASSET_PAIR,
+					100 * ONE,
+					200 * ONE,
+					vec![(1, 2)].try_into().unwrap(),
+				),
+				Error::<Test>::XykPoolDoesntExist
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_and_join_farms_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX, 1_000 * ONE),
+			(ALICE, KSM, 1_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::add_liquidity_and_join_farms(
+					Origin::none(),
+					BSX_KSM_ASSET_PAIR,
+					100 * ONE,
+					200 * ONE,
+					vec![(1, 2)].try_into().unwrap(),
+				),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_and_join_farms_should_fail_when_farm_entry_is_duplicated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX, 1_000 * ONE),
+			(ALICE, KSM, 1_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_yield_farm(BOB, 2, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			set_block_number(1_800);
+
+			assert_noop!(
+				LiquidityMining::add_liquidity_and_join_farms(
+					Origin::signed(ALICE),
+					BSX_KSM_ASSET_PAIR,
+					100 * ONE,
+					200 * ONE,
+					vec![(1, 3), (2, 4), (1, 3)].try_into().unwrap(),
+				),
+				Error::<Test>::DuplicateFarmEntry
+			);
+
+			pretty_assertions::assert_eq!(NFTS.with(|v| v.borrow().len()), 0);
+		});
+}
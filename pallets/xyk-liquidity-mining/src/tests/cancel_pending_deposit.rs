@@ -0,0 +1,96 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn cancel_pending_deposit_should_return_lp_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE), (ALICE, BSX_KSM_SHARE_ID, 100 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(100);
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_KSM_ASSET_PAIR,
+				One::one(),
+				None,
+				1_000
+			));
+			assert_ok!(LiquidityMining::deposit_shares(
+				Origin::signed(ALICE),
+				1,
+				2,
+				BSX_KSM_ASSET_PAIR,
+				50 * ONE
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::cancel_pending_deposit(
+				Origin::signed(ALICE),
+				1,
+				2,
+				BSX_KSM_ASSET_PAIR
+			));
+
+			//Assert
+			assert_eq!(crate::PendingDeposits::<Test>::get((1, 2), ALICE), 0);
+			assert_eq!(
+				crate::ScheduledYieldFarms::<Test>::get(1, 2).unwrap().pending_deposits,
+				0
+			);
+			assert_eq!(Tokens::total_balance(BSX_KSM_SHARE_ID, &ALICE), 100 * ONE);
+
+			assert_last_event!(crate::Event::PendingDepositCancelled {
+				global_farm_id: 1,
+				yield_farm_id: 2,
+				who: ALICE,
+				amount: 50 * ONE,
+				lp_token: BSX_KSM_SHARE_ID,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn cancel_pending_deposit_should_fail_when_account_has_no_pending_deposit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::cancel_pending_deposit(Origin::signed(ALICE), 1, 2, BSX_KSM_ASSET_PAIR),
+				Error::<Test>::PendingDepositNotFound
+			);
+		});
+}
@@ -0,0 +1,171 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn with_two_reward_currencies_farms() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			KSM,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_yield_farm(BOB, 2, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 3, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.with_deposit(CHARLIE, 1, 3, BSX_KSM_ASSET_PAIR, 100 * ONE)
+}
+
+#[test]
+fn claim_all_should_claim_from_all_farm_entries_of_all_deposits() {
+	with_two_reward_currencies_farms().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LiquidityMining::redeposit_shares(
+			Origin::signed(CHARLIE),
+			2,
+			4,
+			BSX_KSM_ASSET_PAIR,
+			1
+		));
+
+		set_block_number(10_000);
+
+		//Act
+		assert_ok!(LiquidityMining::claim_all(
+			Origin::signed(CHARLIE),
+			vec![1, 2].try_into().unwrap()
+		));
+
+		//Assert
+		for (global_farm_id, yield_farm_id, reward_currency, deposit_id) in
+			[(1, 3, BSX, 1), (2, 4, KSM, 1), (1, 3, BSX, 2)]
+		{
+			pretty_assertions::assert_eq!(
+				has_event(
+					crate::Event::RewardClaimed {
+						global_farm_id,
+						yield_farm_id,
+						who: CHARLIE,
+						claimed: 20_000_000 * ONE,
+						reward_currency,
+						deposit_id,
+						beneficiary: CHARLIE,
+					}
+					.into()
+				),
+				true
+			);
+		}
+
+		assert_last_event!(crate::Event::AllRewardsClaimed {
+			who: CHARLIE,
+			claimed: vec![(BSX, 40_000_000 * ONE), (KSM, 20_000_000 * ONE)],
+		}
+		.into());
+	});
+}
+
+#[test]
+fn claim_all_should_skip_farm_entries_which_were_already_claimed_in_the_period() {
+	with_two_reward_currencies_farms().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LiquidityMining::redeposit_shares(
+			Origin::signed(CHARLIE),
+			2,
+			4,
+			BSX_KSM_ASSET_PAIR,
+			1
+		));
+
+		set_block_number(10_000);
+		assert_ok!(LiquidityMining::claim_rewards(Origin::signed(CHARLIE), 1, 3));
+
+		//Act
+		assert_ok!(LiquidityMining::claim_all(
+			Origin::signed(CHARLIE),
+			vec![1].try_into().unwrap()
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::AllRewardsClaimed {
+			who: CHARLIE,
+			claimed: vec![(KSM, 20_000_000 * ONE)],
+		}
+		.into());
+	});
+}
+
+#[test]
+fn claim_all_should_fail_when_nothing_was_claimed() {
+	with_two_reward_currencies_farms().build().execute_with(|| {
+		//Arrange
+		set_block_number(10_000);
+		assert_ok!(LiquidityMining::claim_rewards(Origin::signed(CHARLIE), 1, 3));
+
+		//Act and assert
+		assert_noop!(
+			LiquidityMining::claim_all(Origin::signed(CHARLIE), vec![1].try_into().unwrap()),
+			Error::<Test>::ZeroClaimedRewards
+		);
+	});
+}
+
+#[test]
+fn claim_all_should_fail_when_any_deposit_is_not_owned_by_origin() {
+	with_two_reward_currencies_farms().build().execute_with(|| {
+		//Arrange
+		set_block_number(10_000);
+
+		//Act and assert
+		assert_noop!(
+			LiquidityMining::claim_all(Origin::signed(BOB), vec![1, 2].try_into().unwrap()),
+			Error::<Test>::NotDepositOwner
+		);
+	});
+}
+
+#[test]
+fn claim_all_should_fail_when_origin_is_not_signed() {
+	with_two_reward_currencies_farms().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::claim_all(Origin::none(), vec![1].try_into().unwrap()),
+			BadOrigin
+		);
+	});
+}
@@ -54,6 +54,7 @@ fn claim_rewards_should_work_when_deposit_exist() {
 				claimed: 20_000_000 * ONE,
 				reward_currency: BSX,
 				deposit_id: 1,
+				beneficiary: CHARLIE,
 			}
 			.into());
 		});
@@ -184,3 +185,38 @@ fn claim_rewards_should_fail_when_claimed_reward_is_zero() {
 			);
 		});
 }
+
+#[test]
+fn claim_rewards_should_fail_when_deposit_is_listed_for_sale() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(10_000);
+			LISTED_DEPOSITS.with(|v| v.borrow_mut().push(1));
+
+			//Act and assert
+			assert_noop!(
+				LiquidityMining::claim_rewards(Origin::signed(CHARLIE), 1, 2),
+				Error::<Test>::DepositIsListedForSale
+			);
+		});
+}
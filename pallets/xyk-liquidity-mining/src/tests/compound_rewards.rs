@@ -0,0 +1,263 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+
+const COMPOUNDING_START: u64 = 1_000;
+
+//NOTE: dummy liq. mining doesn't transfer rewards so claimed rewards are endowed to the deposit
+//owner.
+fn with_auto_compounded_deposit() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+			(CHARLIE, BSX, 20_000_000 * ONE),
+			(BSX_KSM_AMM, BSX, 200_000_000 * ONE),
+			(BSX_KSM_AMM, KSM, 100_000_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+}
+
+fn enable_auto_compounding() {
+	set_block_number(COMPOUNDING_START);
+	assert_ok!(LiquidityMining::enable_auto_compounding(
+		Origin::signed(CHARLIE),
+		1,
+		BSX_KSM_ASSET_PAIR
+	));
+}
+
+#[test]
+fn compound_rewards_should_add_claimed_rewards_to_deposit() {
+	with_auto_compounded_deposit().build().execute_with(|| {
+		//Arrange
+		enable_auto_compounding();
+		set_block_number(COMPOUNDING_START + CompoundingPeriod::get());
+
+		//Act
+		assert_ok!(LiquidityMining::compound_rewards(Origin::signed(BOB), 1));
+
+		//Assert
+		pretty_assertions::assert_eq!(
+			has_event(
+				crate::Event::RewardClaimed {
+					global_farm_id: 1,
+					yield_farm_id: 2,
+					who: CHARLIE,
+					claimed: 20_000_000 * ONE,
+					reward_currency: BSX,
+					deposit_id: 1,
+					beneficiary: CHARLIE,
+				}
+				.into()
+			),
+			true
+		);
+
+		assert_last_event!(crate::Event::RewardsCompounded {
+			who: CHARLIE,
+			deposit_id: 1,
+			shares_amount: 10_000_000 * ONE,
+		}
+		.into());
+
+		//NOTE: half of the rewards was swapped to KSM and all of it was added as liquidity.
+		pretty_assertions::assert_eq!(Tokens::free_balance(BSX, &CHARLIE), 0);
+		pretty_assertions::assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 0);
+		pretty_assertions::assert_eq!(
+			Tokens::free_balance(BSX_KSM_SHARE_ID, &LiquidityMining::account_id()),
+			100 * ONE + 10_000_000 * ONE
+		);
+
+		pretty_assertions::assert_eq!(
+			crate::AutoCompound::<Test>::get(1).unwrap().last_compounded_at,
+			COMPOUNDING_START + CompoundingPeriod::get()
+		);
+	});
+}
+
+#[test]
+fn compound_rewards_should_pay_rewards_to_beneficiary_when_beneficiary_is_set() {
+	with_auto_compounded_deposit().build().execute_with(|| {
+		//Arrange
+		enable_auto_compounding();
+		assert_ok!(LiquidityMining::set_reward_beneficiary(
+			Origin::signed(CHARLIE),
+			1,
+			Some(BOB)
+		));
+		set_block_number(COMPOUNDING_START + CompoundingPeriod::get());
+
+		//Act
+		assert_ok!(LiquidityMining::compound_rewards(Origin::signed(BOB), 1));
+
+		//Assert
+		assert_last_event!(crate::Event::RewardClaimed {
+			global_farm_id: 1,
+			yield_farm_id: 2,
+			who: CHARLIE,
+			claimed: 20_000_000 * ONE,
+			reward_currency: BSX,
+			deposit_id: 1,
+			beneficiary: BOB,
+		}
+		.into());
+
+		//NOTE: rewards paid to the beneficiary are not compounded.
+		pretty_assertions::assert_eq!(
+			Tokens::free_balance(BSX_KSM_SHARE_ID, &LiquidityMining::account_id()),
+			100 * ONE
+		);
+
+		pretty_assertions::assert_eq!(
+			crate::AutoCompound::<Test>::get(1).unwrap().last_compounded_at,
+			COMPOUNDING_START + CompoundingPeriod::get()
+		);
+	});
+}
+
+#[test]
+fn compound_rewards_should_fail_when_compounding_period_did_not_pass() {
+	with_auto_compounded_deposit().build().execute_with(|| {
+		//Arrange
+		enable_auto_compounding();
+		set_block_number(COMPOUNDING_START + CompoundingPeriod::get() - 1);
+
+		//Act and assert
+		assert_noop!(
+			LiquidityMining::compound_rewards(Origin::signed(BOB), 1),
+			Error::<Test>::CompoundingNotDue
+		);
+	});
+}
+
+#[test]
+fn compound_rewards_should_fail_when_auto_compounding_is_not_enabled() {
+	with_auto_compounded_deposit().build().execute_with(|| {
+		//Arrange
+		set_block_number(COMPOUNDING_START + CompoundingPeriod::get());
+
+		//Act and assert
+		assert_noop!(
+			LiquidityMining::compound_rewards(Origin::signed(BOB), 1),
+			Error::<Test>::AutoCompoundingNotEnabled
+		);
+	});
+}
+
+#[test]
+fn compound_rewards_should_fail_when_deposit_owner_changed() {
+	with_auto_compounded_deposit().build().execute_with(|| {
+		//Arrange
+		enable_auto_compounding();
+		set_block_number(COMPOUNDING_START + CompoundingPeriod::get());
+		NFTS.with(|v| v.borrow_mut().insert(1, BOB));
+
+		//Act and assert
+		assert_noop!(
+			LiquidityMining::compound_rewards(Origin::signed(BOB), 1),
+			Error::<Test>::DepositOwnerChanged
+		);
+	});
+}
+
+#[test]
+fn compound_rewards_should_fail_when_origin_is_not_signed() {
+	with_auto_compounded_deposit().build().execute_with(|| {
+		//Arrange
+		enable_auto_compounding();
+		set_block_number(COMPOUNDING_START + CompoundingPeriod::get());
+
+		//Act and assert
+		assert_noop!(LiquidityMining::compound_rewards(Origin::none(), 1), BadOrigin);
+	});
+}
+
+#[test]
+fn on_idle_should_compound_rewards_of_due_deposits() {
+	with_auto_compounded_deposit().build().execute_with(|| {
+		//Arrange
+		enable_auto_compounding();
+		let now = COMPOUNDING_START + CompoundingPeriod::get();
+		set_block_number(now);
+
+		//Act
+		LiquidityMining::on_idle(now, Weight::MAX);
+
+		//Assert
+		assert_last_event!(crate::Event::RewardsCompounded {
+			who: CHARLIE,
+			deposit_id: 1,
+			shares_amount: 10_000_000 * ONE,
+		}
+		.into());
+
+		pretty_assertions::assert_eq!(crate::CompoundingCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn on_idle_should_disable_auto_compounding_when_deposit_owner_changed() {
+	with_auto_compounded_deposit().build().execute_with(|| {
+		//Arrange
+		enable_auto_compounding();
+		let now = COMPOUNDING_START + CompoundingPeriod::get();
+		set_block_number(now);
+		NFTS.with(|v| v.borrow_mut().insert(1, BOB));
+
+		//Act
+		LiquidityMining::on_idle(now, Weight::MAX);
+
+		//Assert
+		pretty_assertions::assert_eq!(crate::AutoCompound::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn on_idle_should_not_compound_when_remaining_weight_is_not_sufficient() {
+	with_auto_compounded_deposit().build().execute_with(|| {
+		//Arrange
+		enable_auto_compounding();
+		let now = COMPOUNDING_START + CompoundingPeriod::get();
+		set_block_number(now);
+
+		//Act
+		pretty_assertions::assert_eq!(LiquidityMining::on_idle(now, Weight::zero()), Weight::zero());
+
+		//Assert
+		pretty_assertions::assert_eq!(
+			crate::AutoCompound::<Test>::get(1).unwrap().last_compounded_at,
+			COMPOUNDING_START
+		);
+	});
+}
@@ -0,0 +1,154 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn create_global_farm_with_bond_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 500_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let total_rewards: Balance = 400_000 * ONE;
+			let planned_yielding_periods: BlockNumber = 1_000_000_000_u64;
+			let yield_per_period = Perquintill::from_percent(20);
+			let max_reward_per_period: Balance = total_rewards.checked_div(planned_yielding_periods.into()).unwrap();
+
+			//Act
+			assert_ok!(LiquidityMining::create_global_farm_with_bond(
+				Origin::signed(ALICE),
+				total_rewards,
+				planned_yielding_periods,
+				20_000,
+				BSX,
+				BSX,
+				yield_per_period,
+				3,
+				One::one()
+			));
+
+			//Assert
+			assert_eq!(crate::FarmBonds::<Test>::get(1), Some((ALICE, FarmCreationBond::get())));
+			assert_eq!(Tokens::reserved_balance(BSX, &ALICE), FarmCreationBond::get());
+
+			assert!(has_event(
+				crate::Event::GlobalFarmCreated {
+					id: 1,
+					owner: ALICE,
+					total_rewards,
+					reward_currency: BSX,
+					yield_per_period,
+					planned_yielding_periods,
+					blocks_per_period: 20_000,
+					incentivized_asset: BSX,
+					max_reward_per_period,
+					min_deposit: 3,
+					price_adjustment: One::one(),
+				}
+				.into()
+			));
+
+			assert_last_event!(crate::Event::FarmBondReserved {
+				global_farm_id: 1,
+				who: ALICE,
+				amount: FarmCreationBond::get(),
+			}
+			.into());
+		});
+}
+
+#[test]
+fn create_global_farm_with_bond_should_fail_when_bond_cant_be_reserved() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, FarmCreationBond::get() - 1)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::create_global_farm_with_bond(
+					Origin::signed(ALICE),
+					400_000 * ONE,
+					1_000_000_000_u64,
+					20_000,
+					BSX,
+					BSX,
+					Perquintill::from_percent(20),
+					3,
+					One::one()
+				),
+				Error::<Test>::InsufficientBondBalance
+			);
+		});
+}
+
+#[test]
+fn create_global_farm_with_bond_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 500_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::create_global_farm_with_bond(
+					Origin::none(),
+					400_000 * ONE,
+					1_000_000_000_u64,
+					20_000,
+					BSX,
+					BSX,
+					Perquintill::from_percent(20),
+					3,
+					One::one()
+				),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn terminate_global_farm_should_release_bond() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 500_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::create_global_farm_with_bond(
+				Origin::signed(ALICE),
+				400_000 * ONE,
+				1_000_000_000_u64,
+				20_000,
+				BSX,
+				BSX,
+				Perquintill::from_percent(20),
+				3,
+				One::one()
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::terminate_global_farm(Origin::signed(ALICE), 1));
+
+			//Assert
+			assert_eq!(crate::FarmBonds::<Test>::get(1), None);
+			assert_eq!(Tokens::reserved_balance(BSX, &ALICE), 0);
+
+			assert_last_event!(crate::Event::FarmBondReleased {
+				global_farm_id: 1,
+				who: ALICE,
+				amount: FarmCreationBond::get(),
+			}
+			.into());
+		});
+}
@@ -64,9 +64,12 @@ fn create_yield_farm_should_work_when_global_farm_exist() {
 				yield_farm_id: 2,
 				multiplier,
 				loyalty_curve,
+				pool_type: PoolType::XYK,
 				asset_pair: BSX_ACA_ASSET_PAIR,
 			}
 			.into());
+
+			assert_eq!(crate::PoolTypes::<Test>::get(BSX_ACA_AMM), PoolType::XYK);
 		})
 }
 
@@ -105,3 +108,116 @@ fn create_yield_farm_should_fail_when_amm_pool_doesnt_exists() {
 			);
 		});
 }
+
+#[test]
+fn create_yield_farm_should_fail_when_farm_has_yield_farm_for_pool_of_different_type() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_ACA_AMM, BSX_ACA_SHARE_ID, BSX_ACA_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			10_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(20),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			crate::FarmPoolTypes::<Test>::insert(ALICE_FARM, crate::ordered_pair(BSX, ACA), PoolType::LBP);
+
+			//Act and assert
+			assert_noop!(
+				LiquidityMining::create_yield_farm(
+					Origin::signed(ALICE),
+					ALICE_FARM,
+					BSX_ACA_ASSET_PAIR,
+					One::one(),
+					Some(LoyaltyCurve::default())
+				),
+				Error::<Test>::PoolTypeMismatch
+			);
+		});
+}
+
+#[test]
+fn create_yield_farm_for_pool_type_should_work_when_global_farm_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_ACA_AMM, BSX_ACA_SHARE_ID, BSX_ACA_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			10_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(20),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(LiquidityMining::create_yield_farm_for_pool_type(
+				Origin::signed(ALICE),
+				ALICE_FARM,
+				PoolType::XYK,
+				BSX_ACA_ASSET_PAIR,
+				One::one(),
+				None
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::YieldFarmCreated {
+				global_farm_id: ALICE_FARM,
+				yield_farm_id: 2,
+				multiplier: One::one(),
+				loyalty_curve: None,
+				pool_type: PoolType::XYK,
+				asset_pair: BSX_ACA_ASSET_PAIR,
+			}
+			.into());
+
+			assert_eq!(
+				crate::FarmPoolTypes::<Test>::get(ALICE_FARM, crate::ordered_pair(BSX, ACA)),
+				Some(PoolType::XYK)
+			);
+		})
+}
+
+#[test]
+fn create_yield_farm_for_pool_type_should_fail_when_pool_type_is_not_supported() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_ACA_AMM, BSX_ACA_SHARE_ID, BSX_ACA_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			10_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(20),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::create_yield_farm_for_pool_type(
+					Origin::signed(ALICE),
+					ALICE_FARM,
+					PoolType::LBP,
+					BSX_ACA_ASSET_PAIR,
+					One::one(),
+					Some(LoyaltyCurve::default())
+				),
+				Error::<Test>::PoolTypeNotSupported
+			);
+		});
+}
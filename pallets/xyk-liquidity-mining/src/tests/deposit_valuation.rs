@@ -0,0 +1,69 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn deposit_valuation_should_return_shares_liquidity_and_claimable_rewards() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+			(BSX_KSM_AMM, BSX, 1_000 * ONE),
+			(BSX_KSM_AMM, KSM, 2_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(10_000);
+
+			//Act and assert
+			pretty_assertions::assert_eq!(
+				LiquidityMining::deposit_valuation(1),
+				Some(DepositValuation {
+					owner: CHARLIE,
+					amm_pool_id: BSX_KSM_AMM,
+					lp_token: BSX_KSM_SHARE_ID,
+					shares: 100 * ONE,
+					liquidity: vec![(BSX, 1_000 * ONE), (KSM, 2_000 * ONE)],
+					rewards: vec![(1, 2, BSX, 20_000_000 * ONE)],
+				})
+			);
+		});
+}
+
+#[test]
+fn deposit_valuation_should_return_none_when_deposit_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_eq!(LiquidityMining::deposit_valuation(1), None);
+	});
+}
@@ -0,0 +1,147 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn emergency_withdraw_should_unlock_shares_and_destroy_deposit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_yield_farm(BOB, 2, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 3, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_000);
+
+			assert_ok!(LiquidityMining::redeposit_shares(
+				Origin::signed(CHARLIE),
+				2,
+				4,
+				BSX_KSM_ASSET_PAIR,
+				1
+			));
+
+			let charlie_bsx_balance = Tokens::free_balance(BSX, &CHARLIE);
+			let charlie_lp_token_balance = Tokens::free_balance(BSX_KSM_SHARE_ID, &CHARLIE);
+
+			//Act
+			assert_ok!(LiquidityMining::emergency_withdraw(Origin::signed(CHARLIE), 1));
+
+			//Assert
+			pretty_assertions::assert_eq!(
+				has_event(
+					crate::Event::SharesEmergencyWithdrawn {
+						who: CHARLIE,
+						deposit_id: 1,
+						lp_token: BSX_KSM_SHARE_ID,
+						amount: 100 * ONE,
+					}
+					.into(),
+				),
+				true
+			);
+
+			assert_last_event!(crate::Event::DepositDestroyed {
+				who: CHARLIE,
+				deposit_id: 1
+			}
+			.into());
+
+			pretty_assertions::assert_eq!(
+				Tokens::free_balance(BSX_KSM_SHARE_ID, &CHARLIE),
+				charlie_lp_token_balance + 100 * ONE
+			);
+			pretty_assertions::assert_eq!(
+				Tokens::free_balance(BSX_KSM_SHARE_ID, &LiquidityMining::account_id()),
+				0
+			);
+
+			//NOTE: rewards are forfeited.
+			pretty_assertions::assert_eq!(Tokens::free_balance(BSX, &CHARLIE), charlie_bsx_balance);
+
+			assert!(DummyLiquidityMining::yield_farm_ids(1).is_empty());
+			assert_eq!(DummyNFT::owner(&LM_NFT_COLLECTION, &1), None::<AccountId>);
+		});
+}
+
+#[test]
+fn emergency_withdraw_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::emergency_withdraw(Origin::signed(BOB), 1),
+				Error::<Test>::NotDepositOwner
+			);
+		});
+}
+
+#[test]
+fn emergency_withdraw_should_fail_when_deposit_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::emergency_withdraw(Origin::signed(CHARLIE), 1),
+			Error::<Test>::CantFindDepositOwner
+		);
+	});
+}
@@ -0,0 +1,180 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn with_deposit() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+}
+
+#[test]
+fn enable_auto_compounding_should_work_when_origin_is_deposit_owner() {
+	with_deposit().build().execute_with(|| {
+		//Arrange
+		set_block_number(1_000);
+
+		//Act
+		assert_ok!(LiquidityMining::enable_auto_compounding(
+			Origin::signed(CHARLIE),
+			1,
+			BSX_KSM_ASSET_PAIR
+		));
+
+		//Assert
+		pretty_assertions::assert_eq!(
+			crate::AutoCompound::<Test>::get(1).unwrap(),
+			AutoCompoundingData {
+				owner: CHARLIE,
+				pool_type: PoolType::XYK,
+				assets: (BSX, KSM),
+				last_compounded_at: 1_000,
+			}
+		);
+
+		assert_last_event!(crate::Event::AutoCompoundingEnabled {
+			who: CHARLIE,
+			deposit_id: 1
+		}
+		.into());
+	});
+}
+
+#[test]
+fn enable_auto_compounding_should_fail_when_already_enabled() {
+	with_deposit().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LiquidityMining::enable_auto_compounding(
+			Origin::signed(CHARLIE),
+			1,
+			BSX_KSM_ASSET_PAIR
+		));
+
+		//Act and assert
+		assert_noop!(
+			LiquidityMining::enable_auto_compounding(Origin::signed(CHARLIE), 1, BSX_KSM_ASSET_PAIR),
+			Error::<Test>::AutoCompoundingAlreadyEnabled
+		);
+	});
+}
+
+#[test]
+fn enable_auto_compounding_should_fail_when_origin_is_not_deposit_owner() {
+	with_deposit().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::enable_auto_compounding(Origin::signed(BOB), 1, BSX_KSM_ASSET_PAIR),
+			Error::<Test>::NotDepositOwner
+		);
+	});
+}
+
+#[test]
+fn enable_auto_compounding_should_fail_when_xyk_pool_does_not_exist() {
+	with_deposit().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::enable_auto_compounding(Origin::signed(CHARLIE), 1, BSX_DOT_ASSET_PAIR),
+			Error::<Test>::XykPoolDoesntExist
+		);
+	});
+}
+
+#[test]
+fn enable_auto_compounding_should_fail_when_asset_pair_is_not_used_by_deposit() {
+	with_deposit()
+		.with_amm_pool(BSX_ACA_AMM, BSX_ACA_SHARE_ID, BSX_ACA_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::enable_auto_compounding(Origin::signed(CHARLIE), 1, BSX_ACA_ASSET_PAIR),
+				Error::<Test>::InvalidAssetPair
+			);
+		});
+}
+
+#[test]
+fn disable_auto_compounding_should_work_when_auto_compounding_is_enabled() {
+	with_deposit().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LiquidityMining::enable_auto_compounding(
+			Origin::signed(CHARLIE),
+			1,
+			BSX_KSM_ASSET_PAIR
+		));
+
+		//Act
+		assert_ok!(LiquidityMining::disable_auto_compounding(Origin::signed(CHARLIE), 1));
+
+		//Assert
+		pretty_assertions::assert_eq!(crate::AutoCompound::<Test>::get(1), None);
+
+		assert_last_event!(crate::Event::AutoCompoundingDisabled {
+			who: CHARLIE,
+			deposit_id: 1
+		}
+		.into());
+	});
+}
+
+#[test]
+fn disable_auto_compounding_should_fail_when_auto_compounding_is_not_enabled() {
+	with_deposit().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::disable_auto_compounding(Origin::signed(CHARLIE), 1),
+			Error::<Test>::AutoCompoundingNotEnabled
+		);
+	});
+}
+
+#[test]
+fn withdraw_shares_should_disable_auto_compounding_when_deposit_is_destroyed() {
+	with_deposit().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LiquidityMining::enable_auto_compounding(
+			Origin::signed(CHARLIE),
+			1,
+			BSX_KSM_ASSET_PAIR
+		));
+
+		//Act
+		assert_ok!(LiquidityMining::withdraw_shares(
+			Origin::signed(CHARLIE),
+			1,
+			2,
+			BSX_KSM_ASSET_PAIR
+		));
+
+		//Assert
+		pretty_assertions::assert_eq!(crate::AutoCompound::<Test>::get(1), None);
+	});
+}
@@ -0,0 +1,263 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn exit_farms_and_remove_liquidity_should_work_when_all_farm_entries_are_withdrawn() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 100 * ONE),
+			(BSX_KSM_AMM, BSX, 1_000 * ONE),
+			(BSX_KSM_AMM, KSM, 1_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_yield_farm(BOB, 2, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 3, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_000);
+			assert_ok!(LiquidityMining::redeposit_shares(
+				Origin::signed(CHARLIE),
+				2,
+				4,
+				BSX_KSM_ASSET_PAIR,
+				1
+			));
+
+			set_block_number(2_000);
+
+			//Act
+			assert_ok!(LiquidityMining::exit_farms_and_remove_liquidity(
+				Origin::signed(CHARLIE),
+				1,
+				BSX_KSM_ASSET_PAIR,
+				vec![3, 4].try_into().unwrap(),
+			));
+
+			//Assert
+			for (global_farm_id, yield_farm_id) in [(1, 3), (2, 4)] {
+				pretty_assertions::assert_eq!(
+					has_event(
+						crate::Event::RewardClaimed {
+							global_farm_id,
+							yield_farm_id,
+							who: CHARLIE,
+							claimed: 20_000_000 * ONE,
+							reward_currency: BSX,
+							deposit_id: 1,
+							beneficiary: CHARLIE,
+						}
+						.into(),
+					),
+					true
+				);
+
+				pretty_assertions::assert_eq!(
+					has_event(
+						crate::Event::SharesWithdrawn {
+							global_farm_id,
+							yield_farm_id,
+							who: CHARLIE,
+							lp_token: BSX_KSM_SHARE_ID,
+							amount: 100 * ONE,
+							deposit_id: 1,
+						}
+						.into(),
+					),
+					true
+				);
+			}
+
+			pretty_assertions::assert_eq!(
+				has_event(
+					crate::Event::DepositDestroyed {
+						who: CHARLIE,
+						deposit_id: 1
+					}
+					.into()
+				),
+				true
+			);
+
+			pretty_assertions::assert_eq!(DummyNFT::owner(&LM_NFT_COLLECTION, &1), None::<AccountId>);
+			pretty_assertions::assert_eq!(Tokens::free_balance(BSX_KSM_SHARE_ID, &CHARLIE), 0);
+			pretty_assertions::assert_eq!(
+				Tokens::free_balance(BSX_KSM_SHARE_ID, &LiquidityMining::account_id()),
+				0
+			);
+			pretty_assertions::assert_eq!(Tokens::free_balance(BSX, &CHARLIE), 100 * ONE);
+			pretty_assertions::assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 100 * ONE);
+		});
+}
+
+#[test]
+fn exit_farms_and_remove_liquidity_should_fail_when_deposit_is_not_destroyed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 100 * ONE),
+			(BSX_KSM_AMM, BSX, 1_000 * ONE),
+			(BSX_KSM_AMM, KSM, 1_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_yield_farm(BOB, 2, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 3, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_000);
+			assert_ok!(LiquidityMining::redeposit_shares(
+				Origin::signed(CHARLIE),
+				2,
+				4,
+				BSX_KSM_ASSET_PAIR,
+				1
+			));
+
+			//Act and assert
+			assert_noop!(
+				LiquidityMining::exit_farms_and_remove_liquidity(
+					Origin::signed(CHARLIE),
+					1,
+					BSX_KSM_ASSET_PAIR,
+					vec![3].try_into().unwrap(),
+				),
+				Error::<Test>::DepositNotFullyWithdrawn
+			);
+		});
+}
+
+#[test]
+fn exit_farms_and_remove_liquidity_should_fail_when_no_farm_entries_are_specified() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 100 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::exit_farms_and_remove_liquidity(
+					Origin::signed(CHARLIE),
+					1,
+					BSX_KSM_ASSET_PAIR,
+					vec![].try_into().unwrap(),
+				),
+				Error::<Test>::NoFarmEntriesSpecified
+			);
+		});
+}
+
+#[test]
+fn exit_farms_and_remove_liquidity_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 100 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			const NOT_OWNER: u128 = BOB;
+
+			assert_noop!(
+				LiquidityMining::exit_farms_and_remove_liquidity(
+					Origin::signed(NOT_OWNER),
+					1,
+					BSX_KSM_ASSET_PAIR,
+					vec![2].try_into().unwrap(),
+				),
+				Error::<Test>::NotDepositOwner
+			);
+		});
+}
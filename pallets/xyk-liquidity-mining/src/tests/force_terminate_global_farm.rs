@@ -0,0 +1,158 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn force_terminate_global_farm_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 500_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::create_global_farm_with_bond(
+				Origin::signed(ALICE),
+				400_000 * ONE,
+				1_000_000_000_u64,
+				20_000,
+				BSX,
+				BSX,
+				Perquintill::from_percent(20),
+				3,
+				One::one()
+			));
+			assert_ok!(LiquidityMining::create_yield_farm(
+				Origin::signed(ALICE),
+				1,
+				BSX_KSM_ASSET_PAIR,
+				One::one(),
+				None
+			));
+
+			let yield_farms = vec![(2, BSX_KSM_ASSET_PAIR)].try_into().unwrap();
+
+			//Act
+			assert_ok!(LiquidityMining::force_terminate_global_farm(
+				Origin::root(),
+				1,
+				yield_farms
+			));
+
+			//Assert
+			assert_eq!(crate::FarmBonds::<Test>::get(1), None);
+			assert_eq!(Tokens::reserved_balance(BSX, &ALICE), 0);
+			assert_eq!(
+				Tokens::free_balance(BSX, &ALICE),
+				500_000 * ONE - FarmCreationBond::get()
+			);
+
+			assert!(has_event(
+				crate::Event::YieldFarmStopped {
+					global_farm_id: 1,
+					yield_farm_id: 2,
+					who: ALICE,
+					asset_pair: BSX_KSM_ASSET_PAIR,
+				}
+				.into()
+			));
+			assert!(has_event(
+				crate::Event::YieldFarmTerminated {
+					global_farm_id: 1,
+					yield_farm_id: 2,
+					who: ALICE,
+					asset_pair: BSX_KSM_ASSET_PAIR,
+				}
+				.into()
+			));
+
+			assert_last_event!(crate::Event::GlobalFarmForceTerminated {
+				global_farm_id: 1,
+				owner: ALICE,
+				slashed_bond: FarmCreationBond::get(),
+			}
+			.into());
+		});
+}
+
+#[test]
+fn force_terminate_global_farm_should_not_slash_when_farm_has_no_bond() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(LiquidityMining::force_terminate_global_farm(
+				Origin::root(),
+				1,
+				BoundedVec::new()
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::GlobalFarmForceTerminated {
+				global_farm_id: 1,
+				owner: BOB,
+				slashed_bond: 0,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn force_terminate_global_farm_should_fail_when_origin_is_not_allowed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::force_terminate_global_farm(Origin::signed(BOB), 1, BoundedVec::new()),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn force_terminate_global_farm_should_fail_when_global_farm_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::force_terminate_global_farm(Origin::root(), 1, BoundedVec::new()),
+			Error::<Test>::GlobalFarmNotFound
+		);
+	});
+}
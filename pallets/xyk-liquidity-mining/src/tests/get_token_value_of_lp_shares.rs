@@ -18,6 +18,7 @@
 //NOTE: This function is provided as callback for other pallets.
 
 use super::*;
+use hydradx_traits::AMM;
 
 #[test]
 fn get_token_value_of_lp_shares_should_return_valued_of_correct_token_when_amm_exists() {
@@ -90,3 +91,23 @@ fn get_token_value_of_lp_shares_should_whould_fail_when_cannot_get_assets_for_am
 			);
 		});
 }
+
+#[test]
+fn get_token_value_of_lp_shares_should_fail_when_pool_type_is_not_supported() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE), (ALICE, BSX_KSM_SHARE_ID, 100 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			let amm_pool_id = DummyAMM::get_pair_id(BSX_KSM_ASSET_PAIR);
+
+			//Arrange
+			crate::PoolTypes::<Test>::insert(amm_pool_id, PoolType::LBP);
+
+			//Act & Assert
+			assert_noop!(
+				LiquidityMining::get_token_value_of_lp_shares(BSX, amm_pool_id, 1_000),
+				Error::<Test>::CantGetXykAssets
+			);
+		});
+}
@@ -0,0 +1,117 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn merge_deposits_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE), (ALICE, BSX_KSM_SHARE_ID, 100 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(ALICE, 1, 2, BSX_KSM_ASSET_PAIR, 60 * ONE)
+		.with_deposit(ALICE, 1, 2, BSX_KSM_ASSET_PAIR, 40 * ONE)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(LiquidityMining::merge_deposits(Origin::signed(ALICE), 1, 2));
+
+			//Assert
+			assert_eq!(DEPOSITS.with(|v| v.borrow().get(&1).unwrap().shares_amount), 100 * ONE);
+			assert!(DEPOSITS.with(|v| v.borrow().get(&2).is_none()));
+
+			let nft_owner: Option<AccountId> = DummyNFT::owner(&LM_NFT_COLLECTION, &2);
+			assert_eq!(nft_owner, None);
+
+			assert_last_event!(crate::Event::DepositsMerged {
+				who: ALICE,
+				deposit_id: 1,
+				merged_deposit_id: 2,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn merge_deposits_should_fail_when_deposit_is_merged_with_itself() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE), (ALICE, BSX_KSM_SHARE_ID, 100 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(ALICE, 1, 2, BSX_KSM_ASSET_PAIR, 60 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::merge_deposits(Origin::signed(ALICE), 1, 1),
+				Error::<Test>::CantMergeDepositWithItself
+			);
+		});
+}
+
+#[test]
+fn merge_deposits_should_fail_when_origin_is_not_owner_of_both_deposits() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX_KSM_SHARE_ID, 100 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 100 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(ALICE, 1, 2, BSX_KSM_ASSET_PAIR, 60 * ONE)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 40 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::merge_deposits(Origin::signed(ALICE), 1, 2),
+				Error::<Test>::NotDepositOwner
+			);
+		});
+}
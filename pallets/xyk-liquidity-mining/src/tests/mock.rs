@@ -22,13 +22,13 @@ use crate as liq_mining;
 use crate::Config;
 use frame_support::{
 	parameter_types,
-	traits::{Everything, Nothing},
+	traits::{Contains, Everything, Nothing},
 	weights::RuntimeDbWeight,
 	PalletId,
 };
 
 use frame_system as system;
-use hydradx_traits::{nft::CreateTypedCollection, pools::DustRemovalAccountWhitelist, AMMPosition, AMM};
+use hydradx_traits::{nft::CreateTypedCollection, pools::DustRemovalAccountWhitelist, AMMPosition, Source, AMM};
 use orml_traits::parameter_type_with_key;
 use pallet_liquidity_mining::{FarmMultiplier, YieldFarmId};
 use pallet_nft::CollectionType;
@@ -71,6 +71,8 @@ pub const INITIAL_WRITE_WEIGHT: u64 = 1;
 
 pub const LM_NFT_COLLECTION: primitives::CollectionId = 1;
 
+pub const XYK_ORACLE_SOURCE: Source = *b"snek/xyk";
+
 pub const BSX_KSM_ASSET_PAIR: AssetPair = AssetPair {
 	asset_in: BSX,
 	asset_out: KSM,
@@ -162,34 +164,36 @@ thread_local! {
 	pub static DEPOSIT_ID: RefCell<DepositId> = const {RefCell::new(0) };
 
 	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>>= const {RefCell::new(Vec::new()) };
+
+	pub static LISTED_DEPOSITS: RefCell<Vec<DepositId>>= const {RefCell::new(Vec::new()) };
 }
 #[derive(Copy, Clone)]
 pub struct DymmyGlobalFarm {
 	total_rewards: Balance,
-	_planned_yielding_periods: PeriodOf<Test>,
+	planned_yielding_periods: PeriodOf<Test>,
 	_blocks_per_period: BlockNumber,
 	incentivized_asset: AssetId,
 	reward_currency: AssetId,
-	_owner: AccountId,
+	owner: AccountId,
 	_yield_per_period: Perquintill,
 	_min_deposit: Balance,
 	price_adjustment: FixedU128,
-	_max_reward_per_period: Balance,
+	max_reward_per_period: Balance,
 }
 
 #[derive(Clone, Debug)]
 pub struct DummyYieldFarm {
 	_global_farm_id: u32,
-	multiplier: FarmMultiplier,
+	pub multiplier: FarmMultiplier,
 	amm_pool_id: AccountId,
 	_assets: Vec<AssetId>,
-	stopped: bool,
+	pub stopped: bool,
 }
 
 #[derive(Copy, Clone)]
 pub struct DummyDeposit {
 	amm_pool_id: AccountId,
-	shares_amount: Balance,
+	pub shares_amount: Balance,
 	entries: u32,
 }
 
@@ -197,8 +201,9 @@ pub struct DummyDeposit {
 pub struct DummyFarmEntry {
 	_yield_farm_id: u32,
 	global_farm_id: u32,
-	_valued_shares: Balance,
+	valued_shares: Balance,
 	last_claimed: BlockNumber,
+	pub entered_at: BlockNumber,
 }
 
 pub struct DummyAMM;
@@ -327,6 +332,109 @@ impl AMMPosition<AssetId, Balance> for DummyAMM {
 	}
 }
 
+impl AMMLiquidity<AccountId, AssetPair, Balance> for DummyAMM {
+	fn add_liquidity(
+		who: &AccountId,
+		pool_type: PoolType<AssetId>,
+		asset_pair: AssetPair,
+		amount_a: Balance,
+		amount_b_max_limit: Balance,
+	) -> Result<Balance, DispatchError> {
+		ensure!(pool_type == PoolType::XYK, "Dummy pool type is not supported");
+		ensure!(DummyAMM::exists(asset_pair), "Dummy pool doesn't exist");
+
+		//NOTE: dummy pool is always 1:1 and mints 1 share for each unit of `asset_in`.
+		let amount_b = amount_a;
+		ensure!(amount_b <= amount_b_max_limit, "Dummy asset b limit exceeded");
+
+		let amm_pool_id = DummyAMM::get_pair_id(asset_pair);
+		Tokens::transfer(asset_pair.asset_in, who, &amm_pool_id, amount_a)?;
+		Tokens::transfer(asset_pair.asset_out, who, &amm_pool_id, amount_b)?;
+		Tokens::deposit(DummyAMM::get_share_token(asset_pair), who, amount_a)?;
+
+		Ok(amount_a)
+	}
+
+	fn remove_liquidity(
+		who: &AccountId,
+		pool_type: PoolType<AssetId>,
+		asset_pair: AssetPair,
+		shares_amount: Balance,
+	) -> DispatchResult {
+		ensure!(pool_type == PoolType::XYK, "Dummy pool type is not supported");
+
+		let amm_pool_id = DummyAMM::get_pair_id(asset_pair);
+
+		Tokens::withdraw(DummyAMM::get_share_token(asset_pair), who, shares_amount)?;
+		Tokens::transfer(asset_pair.asset_in, &amm_pool_id, who, shares_amount)?;
+		Tokens::transfer(asset_pair.asset_out, &amm_pool_id, who, shares_amount)
+	}
+
+	fn add_liquidity_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn remove_liquidity_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl AssetSwap<AccountId, AssetId, Balance> for DummyAMM {
+	fn sell(
+		who: &AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	) -> Result<Balance, DispatchError> {
+		let asset_pair = AssetPair { asset_in, asset_out };
+		let reversed_asset_pair = AssetPair {
+			asset_in: asset_out,
+			asset_out: asset_in,
+		};
+		ensure!(
+			DummyAMM::exists(asset_pair) || DummyAMM::exists(reversed_asset_pair),
+			"Dummy pool doesn't exist"
+		);
+
+		//NOTE: dummy pool is always 1:1.
+		let amount_out = amount_in;
+		ensure!(amount_out >= min_amount_out, "Dummy trading limit reached");
+
+		let amm_pool_id = if DummyAMM::exists(asset_pair) {
+			DummyAMM::get_pair_id(asset_pair)
+		} else {
+			DummyAMM::get_pair_id(reversed_asset_pair)
+		};
+		Tokens::transfer(asset_in, who, &amm_pool_id, amount_in)?;
+		Tokens::transfer(asset_out, &amm_pool_id, who, amount_out)?;
+
+		Ok(amount_out)
+	}
+
+	fn oracle_price(asset_in: AssetId, asset_out: AssetId) -> Option<(Balance, Balance)> {
+		let asset_pair = AssetPair { asset_in, asset_out };
+		let reversed_asset_pair = AssetPair {
+			asset_in: asset_out,
+			asset_out: asset_in,
+		};
+
+		(DummyAMM::exists(asset_pair) || DummyAMM::exists(reversed_asset_pair)).then_some((1, 1))
+	}
+
+	fn oracle_pairs(asset_in: AssetId, asset_out: AssetId) -> Vec<(Source, AssetId, AssetId)> {
+		vec![(XYK_ORACLE_SOURCE, asset_in, asset_out)]
+	}
+
+	fn sell_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn oracle_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 parameter_types! {
 	pub const WarehouseLMPalletId: PalletId = PalletId(*b"WhouseLm");
 	pub const MinDeposit: Balance = 1;
@@ -335,6 +443,15 @@ parameter_types! {
 	#[derive(PartialEq, Eq)]
 	pub const MaxEntriesPerDeposit: u8 = 10;
 	pub const MaxYieldFarmsPerGlobalFarm: u8 = 5;
+	pub const MaxFarmEntriesPerDeposit: u32 = 5;
+	pub const MaxDepositsPerClaim: u32 = 10;
+	pub const MaxCompoundingSlippage: Permill = Permill::from_percent(5);
+	pub const CompoundingPeriod: u64 = 100;
+	pub const MaxScheduledYieldFarms: u32 = 5;
+	pub const MaxPendingDeposits: u32 = 5;
+	pub const BondAssetId: AssetId = BSX;
+	pub const FarmCreationBond: Balance = 1_000 * ONE;
+	pub MaxGlobalFarmYieldFarms: u32 = MaxYieldFarmsPerGlobalFarm::get() as u32;
 	pub const NftCollectionId: primitives::CollectionId = LM_NFT_COLLECTION;
 	pub const ReserveClassIdUpTo: u128 = 2;
 }
@@ -343,13 +460,37 @@ impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Tokens;
 	type CreateOrigin = frame_system::EnsureRoot<AccountId>;
+	type ForceTerminateOrigin = frame_system::EnsureRoot<AccountId>;
+	type BondAssetId = BondAssetId;
+	type FarmCreationBond = FarmCreationBond;
+	type MaxYieldFarmsPerGlobalFarm = MaxGlobalFarmYieldFarms;
+	type BlockNumberProvider = MockBlockNumberProvider;
 	type WeightInfo = ();
 	type PalletId = LMPalletId;
-	type AMM = DummyAMM;
+	type AMM = XykPools<DummyAMM>;
 	type NftCollectionId = NftCollectionId;
 	type NFTHandler = DummyNFT;
 	type LiquidityMiningHandler = DummyLiquidityMining;
 	type NonDustableWhitelistHandler = Whitelist;
+	type AMMLiquidityHandler = DummyAMM;
+	type MaxFarmEntriesPerDeposit = MaxFarmEntriesPerDeposit;
+	type MaxDepositsPerClaim = MaxDepositsPerClaim;
+	type Swap = DummyAMM;
+	type DepositSharesHandler = DummyLiquidityMining;
+	type DepositSplitMergeHandler = DummyLiquidityMining;
+	type ListedDeposits = DummyListedDeposits;
+	type MaxCompoundingSlippage = MaxCompoundingSlippage;
+	type CompoundingPeriod = CompoundingPeriod;
+	type MaxScheduledYieldFarms = MaxScheduledYieldFarms;
+	type MaxPendingDeposits = MaxPendingDeposits;
+}
+
+pub struct DummyListedDeposits;
+
+impl Contains<DepositId> for DummyListedDeposits {
+	fn contains(deposit_id: &DepositId) -> bool {
+		LISTED_DEPOSITS.with(|v| v.borrow().contains(deposit_id))
+	}
 }
 
 pub struct DummyNFT;
@@ -493,15 +634,15 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 				farm_id,
 				DymmyGlobalFarm {
 					total_rewards,
-					_planned_yielding_periods: planned_yielding_periods,
+					planned_yielding_periods,
 					_blocks_per_period: blocks_per_period,
 					incentivized_asset,
 					reward_currency,
-					_owner: owner,
+					owner,
 					_yield_per_period: yield_per_period,
 					_min_deposit: min_deposit,
 					price_adjustment,
-					_max_reward_per_period: max_reward_per_period,
+					max_reward_per_period,
 				},
 			);
 		});
@@ -683,8 +824,9 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 				DummyFarmEntry {
 					global_farm_id,
 					_yield_farm_id: yield_farm_id,
-					_valued_shares: valued_shares,
+					valued_shares,
 					last_claimed: MockBlockNumberProvider::get(),
+					entered_at: MockBlockNumberProvider::get(),
 				},
 			);
 		});
@@ -722,8 +864,9 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 				DummyFarmEntry {
 					_yield_farm_id: yield_farm_id,
 					global_farm_id,
-					_valued_shares: valued_shares,
+					valued_shares,
 					last_claimed: MockBlockNumberProvider::get(),
+					entered_at: MockBlockNumberProvider::get(),
 				},
 			)
 		});
@@ -767,6 +910,8 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 			*deposit
 		});
 
+		DEPOSIT_ENTRIES.with(|v| v.borrow_mut().remove(&(deposit_id, yield_farm_id)));
+
 		let withdrawn_amount = deposit.shares_amount;
 
 		let mut destroyed = false;
@@ -787,6 +932,200 @@ impl hydradx_traits::liquidity_mining::Mutate<AccountId, AssetId, BlockNumber> f
 	}
 }
 
+impl DepositYieldFarms for DummyLiquidityMining {
+	fn yield_farm_ids(deposit_id: DepositId) -> Vec<YieldFarmId> {
+		let mut yield_farm_ids: Vec<YieldFarmId> = DEPOSIT_ENTRIES.with(|v| {
+			v.borrow()
+				.keys()
+				.filter(|(id, _)| *id == deposit_id)
+				.map(|(_, yield_farm_id)| *yield_farm_id)
+				.collect()
+		});
+		yield_farm_ids.sort();
+
+		yield_farm_ids
+	}
+}
+
+impl DepositLpShares<AccountId> for DummyLiquidityMining {
+	fn deposit_lp_shares(deposit_id: DepositId) -> Option<(AccountId, Balance)> {
+		DEPOSITS.with(|v| {
+			v.borrow()
+				.get(&deposit_id)
+				.map(|deposit| (deposit.amm_pool_id, deposit.shares_amount))
+		})
+	}
+}
+
+impl GlobalFarmOwner<AccountId> for DummyLiquidityMining {
+	fn global_farm_owner(global_farm_id: GlobalFarmId) -> Option<AccountId> {
+		GLOBAL_FARMS.with(|v| v.borrow().get(&global_farm_id).map(|g_f| g_f.owner))
+	}
+}
+
+impl GlobalFarmAssets<AssetId> for DummyLiquidityMining {
+	fn global_farm_assets(global_farm_id: GlobalFarmId) -> Option<(AssetId, AssetId)> {
+		GLOBAL_FARMS.with(|v| {
+			v.borrow()
+				.get(&global_farm_id)
+				.map(|g_f| (g_f.incentivized_asset, g_f.reward_currency))
+		})
+	}
+}
+
+impl GlobalFarmTopUp<AccountId, PeriodOf<Test>> for DummyLiquidityMining {
+	fn top_up_global_farm(
+		_who: &AccountId,
+		global_farm_id: GlobalFarmId,
+		amount: Balance,
+		extra_periods: PeriodOf<Test>,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		GLOBAL_FARMS.with(|v| {
+			let mut p = v.borrow_mut();
+
+			let global_farm = p.get_mut(&global_farm_id).unwrap();
+
+			global_farm.total_rewards += amount;
+			global_farm.planned_yielding_periods += extra_periods;
+			global_farm.max_reward_per_period = global_farm
+				.total_rewards
+				.checked_div(global_farm.planned_yielding_periods.into())
+				.unwrap();
+
+			Ok(global_farm.max_reward_per_period)
+		})
+	}
+}
+
+impl EmergencyWithdraw<AccountId> for DummyLiquidityMining {
+	fn emergency_withdraw(deposit_id: DepositId) -> Option<(AccountId, Balance)> {
+		let deposit = DEPOSITS.with(|v| v.borrow_mut().remove(&deposit_id))?;
+
+		DEPOSIT_ENTRIES.with(|v| v.borrow_mut().retain(|(id, _), _| *id != deposit_id));
+
+		Some((deposit.amm_pool_id, deposit.shares_amount))
+	}
+}
+
+impl YieldFarmEntryStart<BlockNumber> for DummyLiquidityMining {
+	fn set_entry_start(deposit_id: DepositId, yield_farm_id: YieldFarmId, start_at: BlockNumber) -> DispatchResult {
+		DEPOSIT_ENTRIES.with(|v| {
+			let mut p = v.borrow_mut();
+			let entry = p
+				.get_mut(&(deposit_id, yield_farm_id))
+				.ok_or("Dummy farm entry not found")?;
+
+			entry.entered_at = entry.entered_at.min(start_at);
+
+			Ok(())
+		})
+	}
+}
+
+impl SplitMergeDeposits for DummyLiquidityMining {
+	fn split_deposit(deposit_id: DepositId, shares_amount: Balance) -> Result<DepositId, DispatchError> {
+		let new_deposit_id = get_next_deposit_id();
+
+		let (amm_pool_id, shares_before_split, entries) = DEPOSITS.with(|v| {
+			let mut p = v.borrow_mut();
+			let deposit = p.get_mut(&deposit_id).ok_or("Dummy deposit not found")?;
+
+			ensure!(deposit.shares_amount > shares_amount, "Dummy invalid split amount");
+
+			let shares_before_split = deposit.shares_amount;
+			deposit.shares_amount -= shares_amount;
+
+			Ok::<_, DispatchError>((deposit.amm_pool_id, shares_before_split, deposit.entries))
+		})?;
+
+		DEPOSITS.with(|v| {
+			v.borrow_mut().insert(
+				new_deposit_id,
+				DummyDeposit {
+					amm_pool_id,
+					shares_amount,
+					entries,
+				},
+			);
+		});
+
+		DEPOSIT_ENTRIES.with(|v| {
+			let mut p = v.borrow_mut();
+			let entries: Vec<(u32, DummyFarmEntry)> = p
+				.iter()
+				.filter(|((id, _), _)| *id == deposit_id)
+				.map(|((_, yield_farm_id), entry)| (*yield_farm_id, *entry))
+				.collect();
+
+			for (yield_farm_id, mut entry) in entries {
+				let moved_valued_shares = entry.valued_shares * shares_amount / shares_before_split;
+
+				p.get_mut(&(deposit_id, yield_farm_id)).unwrap().valued_shares -= moved_valued_shares;
+
+				entry.valued_shares = moved_valued_shares;
+				p.insert((new_deposit_id, yield_farm_id), entry);
+			}
+		});
+
+		Ok(new_deposit_id)
+	}
+
+	fn merge_deposits(deposit_id: DepositId, other_deposit_id: DepositId) -> DispatchResult {
+		let yield_farm_ids = <Self as DepositYieldFarms>::yield_farm_ids(deposit_id);
+		ensure!(
+			yield_farm_ids == <Self as DepositYieldFarms>::yield_farm_ids(other_deposit_id),
+			"Dummy incompatible entries"
+		);
+
+		DEPOSITS.with(|v| {
+			let mut p = v.borrow_mut();
+			let other_deposit = p.remove(&other_deposit_id).ok_or("Dummy deposit not found")?;
+			let deposit = p.get_mut(&deposit_id).ok_or("Dummy deposit not found")?;
+
+			ensure!(
+				deposit.amm_pool_id == other_deposit.amm_pool_id,
+				"Dummy invalid amm pool"
+			);
+			deposit.shares_amount += other_deposit.shares_amount;
+
+			Ok::<_, DispatchError>(())
+		})?;
+
+		DEPOSIT_ENTRIES.with(|v| {
+			let mut p = v.borrow_mut();
+
+			for yield_farm_id in yield_farm_ids {
+				let other_entry = p.remove(&(other_deposit_id, yield_farm_id)).unwrap();
+				p.get_mut(&(deposit_id, yield_farm_id)).unwrap().valued_shares += other_entry.valued_shares;
+			}
+		});
+
+		Ok(())
+	}
+}
+
+impl IncreaseDepositShares<AccountId> for DummyLiquidityMining {
+	fn increase_deposit_shares<F>(
+		deposit_id: DepositId,
+		amm_pool_id: AccountId,
+		shares_amount: Balance,
+		_get_token_value_of_lp_shares: F,
+	) -> DispatchResult
+	where
+		F: Fn(AssetId, AccountId, Balance) -> Result<Balance, DispatchError>,
+	{
+		DEPOSITS.with(|v| {
+			let mut p = v.borrow_mut();
+			let deposit = p.get_mut(&deposit_id).ok_or("Dummy deposit not found")?;
+
+			ensure!(deposit.amm_pool_id == amm_pool_id, "Dummy invalid amm pool");
+			deposit.shares_amount += shares_amount;
+
+			Ok(())
+		})
+	}
+}
+
 //NOTE: this is and should not be used anywhere. This exists only to make trait bellow happy. Trait
 //bellow is not really used. Basilisk is using `DefaultPriceAdjustment` implementation.
 struct FakeGlobalFarm;
@@ -40,17 +40,35 @@ pub fn has_event(event: mock::RuntimeEvent) -> bool {
 	System::events().iter().any(|record| record.event == event)
 }
 
+pub mod add_liquidity_and_join_farms;
+pub mod cancel_pending_deposit;
+pub mod claim_all;
 pub mod claim_rewards;
+pub mod claim_rewards_to;
+pub mod compound_rewards;
 pub mod create_global_farm;
+pub mod create_global_farm_with_bond;
 pub mod create_yield_farm;
 pub mod deposit_shares;
+pub mod deposit_valuation;
+pub mod emergency_withdraw;
+pub mod enable_auto_compounding;
+pub mod exit_farms_and_remove_liquidity;
+pub mod force_terminate_global_farm;
 pub mod get_token_value_of_lp_shares;
+pub mod merge_deposits;
 pub mod mock;
 pub mod redeposit_shares;
 pub mod resume_yield_farm;
+pub mod schedule_yield_farm;
+pub mod set_price_adjustment_mode;
+pub mod set_reward_beneficiary;
+pub mod split_deposit;
+pub mod start_yield_farm;
 pub mod stop_yield_farm;
 pub mod terminate_global_farm;
 pub mod terminate_yield_farm;
+pub mod top_up_global_farm;
 pub mod update_global_farm;
 pub mod update_yield_farm;
 pub mod withdraw_shares;
@@ -0,0 +1,334 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn schedule_yield_farm_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(100);
+
+			//Act
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_KSM_ASSET_PAIR,
+				FarmMultiplier::from(2),
+				None,
+				1_000
+			));
+
+			//Assert
+			assert_eq!(
+				crate::ScheduledYieldFarms::<Test>::get(1, 2),
+				Some(ScheduledYieldFarm {
+					start_at: 1_000,
+					multiplier: FarmMultiplier::from(2),
+					amm_pool_id: BSX_KSM_AMM,
+					pending_deposits: 0,
+				})
+			);
+			assert_eq!(
+				crate::ScheduledYieldFarmStarts::<Test>::get().into_inner(),
+				vec![(1_000, 1, 2)]
+			);
+			assert_eq!(crate::YieldFarmStarts::<Test>::get(1, 2), Some(1_000));
+			assert!(YIELD_FARMS.with(|v| v.borrow().get(&2).unwrap().stopped));
+
+			assert_last_event!(crate::Event::YieldFarmScheduled {
+				global_farm_id: 1,
+				yield_farm_id: 2,
+				start_at: 1_000,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn schedule_yield_farm_should_fail_when_start_block_is_not_in_future() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			set_block_number(1_000);
+
+			assert_noop!(
+				LiquidityMining::schedule_yield_farm(
+					Origin::signed(BOB),
+					1,
+					PoolType::XYK,
+					BSX_KSM_ASSET_PAIR,
+					One::one(),
+					None,
+					1_000
+				),
+				Error::<Test>::InvalidStartBlock
+			);
+		});
+}
+
+#[test]
+fn deposit_shares_should_create_pending_deposit_when_yield_farm_is_not_started() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE), (ALICE, BSX_KSM_SHARE_ID, 100 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(100);
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_KSM_ASSET_PAIR,
+				One::one(),
+				None,
+				1_000
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::deposit_shares(
+				Origin::signed(ALICE),
+				1,
+				2,
+				BSX_KSM_ASSET_PAIR,
+				50 * ONE
+			));
+
+			//Assert
+			assert_eq!(crate::PendingDeposits::<Test>::get((1, 2), ALICE), 50 * ONE);
+			assert_eq!(
+				Tokens::total_balance(BSX_KSM_SHARE_ID, &LiquidityMining::account_id()),
+				50 * ONE
+			);
+			let nft_owner: Option<AccountId> = DummyNFT::owner(&LM_NFT_COLLECTION, &1);
+			assert_eq!(nft_owner, None);
+
+			assert_last_event!(crate::Event::PendingSharesDeposited {
+				global_farm_id: 1,
+				yield_farm_id: 2,
+				who: ALICE,
+				amount: 50 * ONE,
+				lp_token: BSX_KSM_SHARE_ID,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn schedule_yield_farm_should_keep_starts_sorted_by_start_block() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_amm_pool(BSX_ACA_AMM, BSX_ACA_SHARE_ID, BSX_ACA_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(100);
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_KSM_ASSET_PAIR,
+				One::one(),
+				None,
+				1_000
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_ACA_ASSET_PAIR,
+				One::one(),
+				None,
+				500
+			));
+
+			//Assert
+			assert_eq!(
+				crate::ScheduledYieldFarmStarts::<Test>::get().into_inner(),
+				vec![(500, 1, 3), (1_000, 1, 2)]
+			);
+		});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_max_pending_deposits_was_reached() {
+	let mut endowed_accounts = vec![(BOB, BSX, 1_000_000 * ONE)];
+	let max_pending_deposits = <Test as Config>::MaxPendingDeposits::get() as AccountId;
+	for who in 100..=100 + max_pending_deposits {
+		endowed_accounts.push((who, BSX_KSM_SHARE_ID, 100 * ONE));
+	}
+
+	ExtBuilder::default()
+		.with_endowed_accounts(endowed_accounts)
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(100);
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_KSM_ASSET_PAIR,
+				One::one(),
+				None,
+				1_000
+			));
+
+			for who in 100..100 + max_pending_deposits {
+				assert_ok!(LiquidityMining::deposit_shares(
+					Origin::signed(who),
+					1,
+					2,
+					BSX_KSM_ASSET_PAIR,
+					10 * ONE
+				));
+			}
+
+			//Act & Assert
+			//NOTE: increasing existing pending deposit is allowed.
+			assert_ok!(LiquidityMining::deposit_shares(
+				Origin::signed(100),
+				1,
+				2,
+				BSX_KSM_ASSET_PAIR,
+				10 * ONE
+			));
+
+			assert_noop!(
+				LiquidityMining::deposit_shares(
+					Origin::signed(100 + max_pending_deposits),
+					1,
+					2,
+					BSX_KSM_ASSET_PAIR,
+					10 * ONE
+				),
+				Error::<Test>::TooManyPendingDeposits
+			);
+		});
+}
+
+#[test]
+fn terminate_yield_farm_should_remove_yield_farm_start_when_yield_farm_is_scheduled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(100);
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_KSM_ASSET_PAIR,
+				One::one(),
+				None,
+				1_000
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::terminate_yield_farm(
+				Origin::signed(BOB),
+				1,
+				2,
+				BSX_KSM_ASSET_PAIR
+			));
+
+			//Assert
+			assert_eq!(crate::ScheduledYieldFarms::<Test>::get(1, 2), None);
+			assert!(crate::ScheduledYieldFarmStarts::<Test>::get().is_empty());
+			assert_eq!(crate::YieldFarmStarts::<Test>::get(1, 2), None);
+		});
+}
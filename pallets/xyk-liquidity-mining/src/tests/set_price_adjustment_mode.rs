@@ -0,0 +1,280 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn set_price_adjustment_mode_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Oracle
+			));
+
+			//Assert
+			assert_eq!(crate::PriceAdjustmentModes::<Test>::get(1), PriceAdjustmentMode::Oracle);
+
+			assert_last_event!(crate::Event::PriceAdjustmentModeUpdated {
+				global_farm_id: 1,
+				who: BOB,
+				mode: PriceAdjustmentMode::Oracle,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn set_price_adjustment_mode_should_allow_switching_back_to_manual() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Oracle
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Manual
+			));
+
+			//Assert
+			assert_eq!(crate::PriceAdjustmentModes::<Test>::get(1), PriceAdjustmentMode::Manual);
+			assert_ok!(LiquidityMining::update_global_farm(
+				Origin::signed(BOB),
+				1,
+				FixedU128::from_float(0.5_f64)
+			));
+		});
+}
+
+#[test]
+fn set_price_adjustment_mode_should_fail_when_origin_is_not_farm_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::set_price_adjustment_mode(Origin::signed(ALICE), 1, PriceAdjustmentMode::Oracle),
+				Error::<Test>::NotGlobalFarmOwner
+			);
+		});
+}
+
+#[test]
+fn set_price_adjustment_mode_should_fail_when_global_farm_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::set_price_adjustment_mode(Origin::signed(BOB), 1, PriceAdjustmentMode::Oracle),
+			Error::<Test>::GlobalFarmNotFound
+		);
+	});
+}
+
+#[test]
+fn set_price_adjustment_mode_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::set_price_adjustment_mode(Origin::none(), 1, PriceAdjustmentMode::Oracle),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn set_price_adjustment_mode_should_capture_oracle_pricing_entries_when_mode_is_oracle() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			KSM,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Oracle
+			));
+
+			//Assert
+			assert_eq!(
+				crate::OraclePricingEntries::<Test>::get(1).into_inner(),
+				vec![(XYK_ORACLE_SOURCE, KSM, BSX)]
+			);
+			assert!(LiquidityMining::is_oracle_pricing_entry(XYK_ORACLE_SOURCE, BSX, KSM));
+			assert!(!LiquidityMining::is_oracle_pricing_entry(XYK_ORACLE_SOURCE, BSX, DOT));
+		});
+}
+
+#[test]
+fn set_price_adjustment_mode_should_release_oracle_pricing_entries_when_mode_is_manual() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			KSM,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Oracle
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Manual
+			));
+
+			//Assert
+			assert!(crate::OraclePricingEntries::<Test>::get(1).is_empty());
+			assert!(!LiquidityMining::is_oracle_pricing_entry(XYK_ORACLE_SOURCE, KSM, BSX));
+		});
+}
+
+#[test]
+fn set_price_adjustment_mode_should_count_oracle_pricing_entry_shared_by_more_farms() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE), (ALICE, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			KSM,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			KSM,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Oracle
+			));
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(ALICE),
+				2,
+				PriceAdjustmentMode::Oracle
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Manual
+			));
+
+			//Assert
+			assert!(LiquidityMining::is_oracle_pricing_entry(XYK_ORACLE_SOURCE, KSM, BSX));
+			assert_eq!(
+				crate::OraclePricedEntries::<Test>::get((XYK_ORACLE_SOURCE, (BSX, KSM))),
+				1
+			);
+		});
+}
@@ -0,0 +1,333 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn set_reward_beneficiary_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(LiquidityMining::set_reward_beneficiary(
+				Origin::signed(CHARLIE),
+				1,
+				Some(BOB)
+			));
+
+			//Assert
+			assert_eq!(crate::RewardBeneficiaries::<Test>::get(1), Some((CHARLIE, BOB)));
+
+			assert_last_event!(crate::Event::RewardBeneficiarySet {
+				who: CHARLIE,
+				deposit_id: 1,
+				beneficiary: Some(BOB),
+			}
+			.into());
+		});
+}
+
+#[test]
+fn claim_rewards_should_pay_rewards_to_beneficiary_when_beneficiary_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::set_reward_beneficiary(
+				Origin::signed(CHARLIE),
+				1,
+				Some(BOB)
+			));
+
+			set_block_number(10_000);
+
+			//Act
+			assert_ok!(LiquidityMining::claim_rewards(Origin::signed(CHARLIE), 1, 2));
+
+			//Assert
+			assert_last_event!(crate::Event::RewardClaimed {
+				global_farm_id: 1,
+				yield_farm_id: 2,
+				who: CHARLIE,
+				claimed: 20_000_000 * ONE,
+				reward_currency: BSX,
+				deposit_id: 1,
+				beneficiary: BOB,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn set_reward_beneficiary_should_remove_beneficiary_when_none_is_provided() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::set_reward_beneficiary(
+				Origin::signed(CHARLIE),
+				1,
+				Some(BOB)
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::set_reward_beneficiary(
+				Origin::signed(CHARLIE),
+				1,
+				None
+			));
+
+			//Assert
+			assert_eq!(crate::RewardBeneficiaries::<Test>::get(1), None);
+		});
+}
+
+#[test]
+fn set_reward_beneficiary_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::set_reward_beneficiary(Origin::signed(BOB), 1, Some(BOB)),
+				Error::<Test>::NotDepositOwner
+			);
+		});
+}
+
+fn with_beneficiary_deposit() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+			(BSX_KSM_AMM, BSX, 1_000 * ONE),
+			(BSX_KSM_AMM, KSM, 1_000 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+}
+
+fn assert_rewards_claimed_to_bob() {
+	pretty_assertions::assert_eq!(
+		has_event(
+			crate::Event::RewardClaimed {
+				global_farm_id: 1,
+				yield_farm_id: 2,
+				who: CHARLIE,
+				claimed: 20_000_000 * ONE,
+				reward_currency: BSX,
+				deposit_id: 1,
+				beneficiary: BOB,
+			}
+			.into(),
+		),
+		true
+	);
+}
+
+#[test]
+fn claim_all_should_pay_rewards_to_beneficiary_when_beneficiary_is_set() {
+	with_beneficiary_deposit().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LiquidityMining::set_reward_beneficiary(
+			Origin::signed(CHARLIE),
+			1,
+			Some(BOB)
+		));
+
+		set_block_number(10_000);
+
+		//Act
+		assert_ok!(LiquidityMining::claim_all(
+			Origin::signed(CHARLIE),
+			vec![1].try_into().unwrap()
+		));
+
+		//Assert
+		assert_rewards_claimed_to_bob();
+	});
+}
+
+#[test]
+fn withdraw_shares_should_pay_rewards_to_beneficiary_when_beneficiary_is_set() {
+	with_beneficiary_deposit().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LiquidityMining::set_reward_beneficiary(
+			Origin::signed(CHARLIE),
+			1,
+			Some(BOB)
+		));
+
+		set_block_number(10_000);
+
+		//Act
+		assert_ok!(LiquidityMining::withdraw_shares(
+			Origin::signed(CHARLIE),
+			1,
+			2,
+			BSX_KSM_ASSET_PAIR
+		));
+
+		//Assert
+		assert_rewards_claimed_to_bob();
+
+		assert_eq!(crate::RewardBeneficiaries::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn exit_farms_and_remove_liquidity_should_pay_rewards_to_beneficiary_when_beneficiary_is_set() {
+	with_beneficiary_deposit().build().execute_with(|| {
+		//Arrange
+		assert_ok!(LiquidityMining::set_reward_beneficiary(
+			Origin::signed(CHARLIE),
+			1,
+			Some(BOB)
+		));
+
+		set_block_number(10_000);
+
+		//Act
+		assert_ok!(LiquidityMining::exit_farms_and_remove_liquidity(
+			Origin::signed(CHARLIE),
+			1,
+			BSX_KSM_ASSET_PAIR,
+			vec![2].try_into().unwrap(),
+		));
+
+		//Assert
+		assert_rewards_claimed_to_bob();
+	});
+}
+
+#[test]
+fn withdraw_shares_should_pay_rewards_to_owner_when_beneficiary_was_set_by_previous_owner() {
+	with_beneficiary_deposit().build().execute_with(|| {
+		//Arrange
+		crate::RewardBeneficiaries::<Test>::insert(1, (ALICE, BOB));
+
+		set_block_number(10_000);
+
+		//Act
+		assert_ok!(LiquidityMining::withdraw_shares(
+			Origin::signed(CHARLIE),
+			1,
+			2,
+			BSX_KSM_ASSET_PAIR
+		));
+
+		//Assert
+		pretty_assertions::assert_eq!(
+			has_event(
+				crate::Event::RewardClaimed {
+					global_farm_id: 1,
+					yield_farm_id: 2,
+					who: CHARLIE,
+					claimed: 20_000_000 * ONE,
+					reward_currency: BSX,
+					deposit_id: 1,
+					beneficiary: CHARLIE,
+				}
+				.into(),
+			),
+			true
+		);
+	});
+}
@@ -0,0 +1,406 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::Hooks;
+
+#[test]
+fn start_yield_farm_should_work_when_start_block_was_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(100);
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_KSM_ASSET_PAIR,
+				FarmMultiplier::from(2),
+				None,
+				1_000
+			));
+
+			set_block_number(1_000);
+
+			//Act
+			assert_ok!(LiquidityMining::start_yield_farm(
+				Origin::signed(CHARLIE),
+				1,
+				2,
+				BSX_KSM_ASSET_PAIR
+			));
+
+			//Assert
+			assert_eq!(crate::ScheduledYieldFarms::<Test>::get(1, 2), None);
+			assert!(crate::ScheduledYieldFarmStarts::<Test>::get().is_empty());
+			assert_eq!(
+				YIELD_FARMS.with(|v| v.borrow().get(&2).unwrap().multiplier),
+				FarmMultiplier::from(2)
+			);
+
+			assert_last_event!(crate::Event::YieldFarmStarted {
+				global_farm_id: 1,
+				yield_farm_id: 2,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn start_yield_farm_should_fail_when_start_block_was_not_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(100);
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_KSM_ASSET_PAIR,
+				One::one(),
+				None,
+				1_000
+			));
+
+			set_block_number(999);
+
+			//Act and assert
+			assert_noop!(
+				LiquidityMining::start_yield_farm(Origin::signed(CHARLIE), 1, 2, BSX_KSM_ASSET_PAIR),
+				Error::<Test>::YieldFarmNotStarted
+			);
+		});
+}
+
+#[test]
+fn start_yield_farm_should_fail_when_yield_farm_is_not_scheduled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::start_yield_farm(Origin::signed(CHARLIE), 1, 2, BSX_KSM_ASSET_PAIR),
+				Error::<Test>::ScheduledYieldFarmNotFound
+			);
+		});
+}
+
+fn schedule_bsx_ksm_yield_farm_with_pending_deposits() {
+	set_block_number(100);
+	assert_ok!(LiquidityMining::schedule_yield_farm(
+		Origin::signed(BOB),
+		1,
+		PoolType::XYK,
+		BSX_KSM_ASSET_PAIR,
+		FarmMultiplier::from(2),
+		None,
+		1_000
+	));
+
+	assert_ok!(LiquidityMining::deposit_shares(
+		Origin::signed(ALICE),
+		1,
+		2,
+		BSX_KSM_ASSET_PAIR,
+		50 * ONE
+	));
+	assert_ok!(LiquidityMining::deposit_shares(
+		Origin::signed(CHARLIE),
+		1,
+		2,
+		BSX_KSM_ASSET_PAIR,
+		20 * ONE
+	));
+}
+
+#[test]
+fn on_initialize_should_start_yield_farm_and_enter_pending_deposits_when_start_block_was_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX_KSM_SHARE_ID, 100 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 100 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_bsx_ksm_yield_farm_with_pending_deposits();
+
+			set_block_number(1_000);
+
+			//Act
+			LiquidityMining::on_initialize(1_000);
+
+			//Assert
+			assert_eq!(crate::ScheduledYieldFarms::<Test>::get(1, 2), None);
+			assert!(crate::ScheduledYieldFarmStarts::<Test>::get().is_empty());
+			assert_eq!(crate::PendingDeposits::<Test>::iter_prefix((1, 2)).count(), 0);
+
+			let mut deposits: Vec<(AccountId, Balance)> = [1, 2]
+				.into_iter()
+				.map(|deposit_id| {
+					let owner: AccountId = DummyNFT::owner(&LM_NFT_COLLECTION, &deposit_id).unwrap();
+					(
+						owner,
+						<DummyLiquidityMining as DepositLpShares<AccountId>>::deposit_lp_shares(deposit_id)
+							.unwrap()
+							.1,
+					)
+				})
+				.collect();
+			deposits.sort();
+			pretty_assertions::assert_eq!(deposits, vec![(ALICE, 50 * ONE), (CHARLIE, 20 * ONE)]);
+
+			//NOTE: LP shares were locked when pending deposits were created.
+			assert_eq!(
+				Tokens::total_balance(BSX_KSM_SHARE_ID, &LiquidityMining::account_id()),
+				70 * ONE
+			);
+
+			assert_last_event!(crate::Event::YieldFarmStarted {
+				global_farm_id: 1,
+				yield_farm_id: 2,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn on_initialize_should_not_start_yield_farm_when_start_block_was_not_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX_KSM_SHARE_ID, 100 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 100 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_bsx_ksm_yield_farm_with_pending_deposits();
+
+			set_block_number(999);
+
+			//Act
+			LiquidityMining::on_initialize(999);
+
+			//Assert
+			assert!(crate::ScheduledYieldFarms::<Test>::contains_key(1, 2));
+			assert_eq!(
+				crate::ScheduledYieldFarmStarts::<Test>::get().into_inner(),
+				vec![(1_000, 1, 2)]
+			);
+			assert_eq!(crate::PendingDeposits::<Test>::get((1, 2), ALICE), 50 * ONE);
+			assert_eq!(DummyNFT::owner(&LM_NFT_COLLECTION, &1), None);
+		});
+}
+
+#[test]
+fn on_initialize_should_reserve_weight_for_start_only_when_yield_farm_starts() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(100);
+			let idle_weight = LiquidityMining::on_initialize(100);
+
+			assert_ok!(LiquidityMining::schedule_yield_farm(
+				Origin::signed(BOB),
+				1,
+				PoolType::XYK,
+				BSX_KSM_ASSET_PAIR,
+				One::one(),
+				None,
+				1_000
+			));
+
+			//Act & Assert
+			set_block_number(999);
+			assert_eq!(LiquidityMining::on_initialize(999), idle_weight);
+
+			set_block_number(1_000);
+			assert!(LiquidityMining::on_initialize(1_000).all_gt(idle_weight));
+			assert_eq!(crate::ScheduledYieldFarms::<Test>::get(1, 2), None);
+		});
+}
+
+#[test]
+fn resume_yield_farm_should_enter_pending_deposits_when_yield_farm_is_scheduled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX_KSM_SHARE_ID, 100 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 100 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_bsx_ksm_yield_farm_with_pending_deposits();
+
+			//Act
+			assert_ok!(LiquidityMining::resume_yield_farm(
+				Origin::signed(BOB),
+				1,
+				2,
+				BSX_KSM_ASSET_PAIR,
+				One::one()
+			));
+
+			//Assert
+			assert_eq!(crate::ScheduledYieldFarms::<Test>::get(1, 2), None);
+			assert!(crate::ScheduledYieldFarmStarts::<Test>::get().is_empty());
+			assert_eq!(crate::PendingDeposits::<Test>::iter_prefix((1, 2)).count(), 0);
+			assert!(DummyNFT::owner(&LM_NFT_COLLECTION, &1).is_some());
+			assert!(DummyNFT::owner(&LM_NFT_COLLECTION, &2).is_some());
+			assert_eq!(crate::YieldFarmStarts::<Test>::get(1, 2), Some(100));
+		});
+}
+
+#[test]
+fn start_yield_farm_should_enter_pending_deposits_as_of_start_block_when_start_is_delayed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(ALICE, BSX_KSM_SHARE_ID, 100 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 100 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			schedule_bsx_ksm_yield_farm_with_pending_deposits();
+
+			set_block_number(1_050);
+
+			//Act
+			assert_ok!(LiquidityMining::start_yield_farm(
+				Origin::signed(CHARLIE),
+				1,
+				2,
+				BSX_KSM_ASSET_PAIR
+			));
+
+			//Assert
+			assert_eq!(crate::YieldFarmStarts::<Test>::get(1, 2), Some(1_000));
+			for deposit_id in [1, 2] {
+				assert_eq!(
+					DEPOSIT_ENTRIES.with(|v| v.borrow().get(&(deposit_id, 2)).unwrap().entered_at),
+					1_000
+				);
+			}
+		});
+}
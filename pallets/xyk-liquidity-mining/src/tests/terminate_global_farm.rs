@@ -46,6 +46,39 @@ fn terminate_global_farm_should_work() {
 		});
 }
 
+#[test]
+fn terminate_global_farm_should_release_oracle_pricing_entries() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			KSM,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Oracle
+			));
+
+			//Act
+			assert_ok!(LiquidityMining::terminate_global_farm(Origin::signed(BOB), 1));
+
+			//Assert
+			assert!(crate::OraclePricingEntries::<Test>::get(1).is_empty());
+			assert!(!LiquidityMining::is_oracle_pricing_entry(XYK_ORACLE_SOURCE, KSM, BSX));
+		});
+}
+
 #[test]
 fn terminate_global_farm_should_fail_when_origin_is_not_signed() {
 	ExtBuilder::default()
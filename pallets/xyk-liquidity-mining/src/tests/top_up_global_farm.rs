@@ -0,0 +1,123 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn top_up_global_farm_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(LiquidityMining::top_up_global_farm(
+				Origin::signed(BOB),
+				1,
+				100_000 * ONE,
+				5_000
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::GlobalFarmToppedUp {
+				global_farm_id: 1,
+				who: BOB,
+				amount: 100_000 * ONE,
+				extra_periods: 5_000,
+				max_reward_per_period: 600_000 * ONE / 25_000,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_origin_is_not_farm_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE), (ALICE, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::top_up_global_farm(Origin::signed(ALICE), 1, 100_000 * ONE, 5_000),
+				Error::<Test>::NotGlobalFarmOwner
+			);
+		});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_nothing_is_topped_up() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::top_up_global_farm(Origin::signed(BOB), 1, 0, 0),
+				Error::<Test>::NothingToTopUp
+			);
+		});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_global_farm_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::top_up_global_farm(Origin::signed(BOB), 1, 100_000 * ONE, 5_000),
+			Error::<Test>::GlobalFarmNotFound
+		);
+	});
+}
+
+#[test]
+fn top_up_global_farm_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::top_up_global_farm(Origin::none(), 1, 100_000 * ONE, 5_000),
+			BadOrigin
+		);
+	});
+}
@@ -77,3 +77,35 @@ fn update_global_farm_should_fail_when_origin_is_not_signed() {
 			);
 		});
 }
+
+#[test]
+fn update_global_farm_should_fail_when_price_adjustment_is_oracle_driven() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			assert_ok!(LiquidityMining::set_price_adjustment_mode(
+				Origin::signed(BOB),
+				1,
+				PriceAdjustmentMode::Oracle
+			));
+
+			//Act and assert
+			assert_noop!(
+				LiquidityMining::update_global_farm(Origin::signed(BOB), 1, FixedU128::from_float(0.268_756_6)),
+				Error::<Test>::PriceAdjustmentIsOracleDriven
+			);
+		});
+}
@@ -112,3 +112,33 @@ fn update_yield_farm_should_fail_when_amm_pool_does_not_exist() {
 			);
 		});
 }
+
+#[test]
+fn update_yield_farm_should_fail_when_pool_type_stored_on_farm_is_not_supported() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, BSX, 1_000_000 * ONE)])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(BOB, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			crate::FarmPoolTypes::<Test>::insert(1, crate::ordered_pair(BSX, KSM), PoolType::LBP);
+
+			//Act and assert
+			assert_noop!(
+				LiquidityMining::update_yield_farm(Origin::signed(BOB), 1, BSX_KSM_ASSET_PAIR, FixedU128::from(10_001)),
+				Error::<Test>::PoolTypeNotSupported
+			);
+		});
+}
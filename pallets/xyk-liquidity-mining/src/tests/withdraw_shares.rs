@@ -88,6 +88,7 @@ fn withdraw_shares_should_work() {
 						claimed: 20_000_000 * ONE,
 						reward_currency: BSX,
 						deposit_id: 1,
+						beneficiary: CHARLIE,
 					}
 					.into(),
 				),
@@ -139,6 +140,7 @@ fn withdraw_shares_should_work() {
 						claimed: 20_000_000 * ONE,
 						reward_currency: BSX,
 						deposit_id: 1,
+						beneficiary: CHARLIE,
 					}
 					.into(),
 				),
@@ -472,3 +474,38 @@ fn withdraw_shares_should_work_when_yield_farm_is_not_claimable() {
 			);
 		});
 }
+
+#[test]
+fn withdraw_shares_should_fail_when_deposit_is_listed_for_sale() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, BSX_KSM_SHARE_ID, 200 * ONE),
+		])
+		.with_amm_pool(BSX_KSM_AMM, BSX_KSM_SHARE_ID, BSX_KSM_ASSET_PAIR)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			BSX,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, BSX_KSM_ASSET_PAIR)
+		.with_deposit(CHARLIE, 1, 2, BSX_KSM_ASSET_PAIR, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(10_000);
+			LISTED_DEPOSITS.with(|v| v.borrow_mut().push(1));
+
+			//Act and assert
+			assert_noop!(
+				LiquidityMining::withdraw_shares(Origin::signed(CHARLIE), 1, 2, BSX_KSM_ASSET_PAIR),
+				Error::<Test>::DepositIsListedForSale
+			);
+		});
+}
@@ -0,0 +1,815 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	sp_runtime::{
+		traits::{BlockNumberProvider, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Get, Saturating, Zero},
+		ArithmeticError, DispatchError, FixedPointNumber, FixedU128,
+	},
+	weights::Weight,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use hydradx_traits::{
+	liquidity_mining::{GlobalFarmId, Mutate as LiquidityMiningMutate, YieldFarmId},
+	router::PoolType,
+	AMMPosition, Source, AMM,
+};
+use orml_traits::MultiCurrency;
+use pallet_xyk::types::{AssetId, AssetPair, Balance};
+use pallet_xyk::weights::WeightInfo as XykWeights;
+use primitives::CollectionId as DepositId;
+use sp_arithmetic::{helpers_128bit::multiply_by_rational_with_rounding, Rounding};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// AMM pools liq. mining can run for. Pool is identified by its `pool_type` and `asset_pair`.
+pub trait AMMPools<AccountId> {
+	/// Return `true` if pool of `pool_type` exists for `asset_pair`. Pools of not supported
+	/// `pool_type` don't exist.
+	fn exists(pool_type: PoolType<AssetId>, asset_pair: AssetPair) -> bool;
+
+	/// Return account id of the pool or `None` if `pool_type` is not supported. This function
+	/// doesn't check pool existence.
+	fn get_pool_id(pool_type: PoolType<AssetId>, asset_pair: AssetPair) -> Option<AccountId>;
+
+	/// Return share token of the pool or `None` if `pool_type` is not supported. Pool existence
+	/// must be checked before calling this function.
+	fn get_share_token(pool_type: PoolType<AssetId>, asset_pair: AssetPair) -> Option<AssetId>;
+
+	/// Return assets of the pool with `pool_id` or `None` if `pool_type` is not supported.
+	fn get_pool_assets(pool_type: PoolType<AssetId>, pool_id: &AccountId) -> Option<Vec<AssetId>>;
+
+	/// Return value of `shares_amount` of the pool's shares in the `asset` currency.
+	fn get_token_value_of_shares(
+		pool_type: PoolType<AssetId>,
+		pool_id: &AccountId,
+		asset: AssetId,
+		shares_amount: Balance,
+	) -> Result<Balance, DispatchError>;
+}
+
+/// `AMMPools` implementation for XYK pools. Other pool types are not supported.
+pub struct XykPools<A>(PhantomData<A>);
+
+impl<AccountId, A> AMMPools<AccountId> for XykPools<A>
+where
+	A: AMM<AccountId, AssetId, AssetPair, Balance> + AMMPosition<AssetId, Balance, Error = DispatchError>,
+{
+	fn exists(pool_type: PoolType<AssetId>, asset_pair: AssetPair) -> bool {
+		match pool_type {
+			PoolType::XYK => A::exists(asset_pair),
+			_ => false,
+		}
+	}
+
+	fn get_pool_id(pool_type: PoolType<AssetId>, asset_pair: AssetPair) -> Option<AccountId> {
+		match pool_type {
+			PoolType::XYK => Some(A::get_pair_id(asset_pair)),
+			_ => None,
+		}
+	}
+
+	fn get_share_token(pool_type: PoolType<AssetId>, asset_pair: AssetPair) -> Option<AssetId> {
+		match pool_type {
+			PoolType::XYK => Some(A::get_share_token(asset_pair)),
+			_ => None,
+		}
+	}
+
+	fn get_pool_assets(pool_type: PoolType<AssetId>, pool_id: &AccountId) -> Option<Vec<AssetId>> {
+		match pool_type {
+			PoolType::XYK => A::get_pool_assets(pool_id),
+			_ => None,
+		}
+	}
+
+	fn get_token_value_of_shares(
+		pool_type: PoolType<AssetId>,
+		pool_id: &AccountId,
+		asset: AssetId,
+		shares_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let assets =
+			Self::get_pool_assets(pool_type, pool_id).ok_or(DispatchError::Other("Can't get assets of the pool"))?;
+
+		let (liquidity_a, liquidity_b) = A::get_liquidity_behind_shares(assets[0], assets[1], shares_amount)?;
+
+		if assets[0] == asset {
+			return Ok(liquidity_a);
+		}
+
+		Ok(liquidity_b)
+	}
+}
+
+/// Liquidity provisioning used by liq. mining to add/remove liquidity on behalf of the user.
+pub trait AMMLiquidity<AccountId, AssetPair, Balance> {
+	/// Add liquidity to the pool of `pool_type` identified by `asset_pair` and return amount of
+	/// minted shares.
+	fn add_liquidity(
+		who: &AccountId,
+		pool_type: PoolType<AssetId>,
+		asset_pair: AssetPair,
+		amount_a: Balance,
+		amount_b_max_limit: Balance,
+	) -> Result<Balance, DispatchError>;
+
+	/// Remove `shares_amount` of liquidity from the pool of `pool_type` identified by `asset_pair`.
+	fn remove_liquidity(
+		who: &AccountId,
+		pool_type: PoolType<AssetId>,
+		asset_pair: AssetPair,
+		shares_amount: Balance,
+	) -> DispatchResult;
+
+	fn add_liquidity_weight() -> Weight;
+
+	fn remove_liquidity_weight() -> Weight;
+}
+
+/// `AMMLiquidity` implementation backed by `pallet_xyk`. Other pool types are not supported.
+pub struct XykLiquidity<T>(PhantomData<T>);
+
+impl<T: pallet_xyk::Config> AMMLiquidity<T::AccountId, AssetPair, Balance> for XykLiquidity<T> {
+	fn add_liquidity(
+		who: &T::AccountId,
+		pool_type: PoolType<AssetId>,
+		asset_pair: AssetPair,
+		amount_a: Balance,
+		amount_b_max_limit: Balance,
+	) -> Result<Balance, DispatchError> {
+		ensure!(
+			pool_type == PoolType::XYK,
+			DispatchError::Other("Pool type is not supported")
+		);
+
+		let share_token = pallet_xyk::Pallet::<T>::get_share_token(asset_pair);
+		let shares_before = <T as pallet_xyk::Config>::Currency::free_balance(share_token, who);
+
+		pallet_xyk::Pallet::<T>::add_liquidity(
+			RawOrigin::Signed(who.clone()).into(),
+			asset_pair.asset_in,
+			asset_pair.asset_out,
+			amount_a,
+			amount_b_max_limit,
+		)?;
+
+		let shares_after = <T as pallet_xyk::Config>::Currency::free_balance(share_token, who);
+
+		Ok(shares_after.saturating_sub(shares_before))
+	}
+
+	fn remove_liquidity(
+		who: &T::AccountId,
+		pool_type: PoolType<AssetId>,
+		asset_pair: AssetPair,
+		shares_amount: Balance,
+	) -> DispatchResult {
+		ensure!(
+			pool_type == PoolType::XYK,
+			DispatchError::Other("Pool type is not supported")
+		);
+
+		pallet_xyk::Pallet::<T>::remove_liquidity(
+			RawOrigin::Signed(who.clone()).into(),
+			asset_pair.asset_in,
+			asset_pair.asset_out,
+			shares_amount,
+		)
+	}
+
+	fn add_liquidity_weight() -> Weight {
+		<T as pallet_xyk::Config>::WeightInfo::add_liquidity()
+	}
+
+	fn remove_liquidity_weight() -> Weight {
+		<T as pallet_xyk::Config>::WeightInfo::remove_liquidity()
+	}
+}
+
+/// Yield farm entries of the liq. mining deposits.
+pub trait DepositYieldFarms {
+	/// Return ids of all yield farms the deposit is entered into.
+	fn yield_farm_ids(deposit_id: DepositId) -> Vec<YieldFarmId>;
+}
+
+impl<T: pallet_liquidity_mining::Config<I>, I: 'static> DepositYieldFarms for pallet_liquidity_mining::Pallet<T, I> {
+	fn yield_farm_ids(deposit_id: DepositId) -> Vec<YieldFarmId> {
+		pallet_liquidity_mining::Deposit::<T, I>::get(deposit_id)
+			.map(|deposit| {
+				deposit
+					.yield_farm_entries
+					.iter()
+					.map(|entry| entry.yield_farm_id)
+					.collect()
+			})
+			.unwrap_or_default()
+	}
+}
+
+/// LP shares of the liq. mining deposits.
+pub trait DepositLpShares<AmmPoolId> {
+	/// Return AMM pool id and amount of LP shares of the deposit or `None` if deposit doesn't
+	/// exist.
+	fn deposit_lp_shares(deposit_id: DepositId) -> Option<(AmmPoolId, Balance)>;
+}
+
+impl<T: pallet_liquidity_mining::Config<I>, I: 'static> DepositLpShares<T::AmmPoolId>
+	for pallet_liquidity_mining::Pallet<T, I>
+{
+	fn deposit_lp_shares(deposit_id: DepositId) -> Option<(T::AmmPoolId, Balance)> {
+		pallet_liquidity_mining::Deposit::<T, I>::get(deposit_id).map(|deposit| (deposit.amm_pool_id, deposit.shares))
+	}
+}
+
+/// Global farms of the liquidity mining.
+pub trait GlobalFarmOwner<AccountId> {
+	/// Return owner of the global farm or `None` if global farm doesn't exist.
+	fn global_farm_owner(global_farm_id: GlobalFarmId) -> Option<AccountId>;
+}
+
+impl<T: pallet_liquidity_mining::Config<I>, I: 'static> GlobalFarmOwner<T::AccountId>
+	for pallet_liquidity_mining::Pallet<T, I>
+{
+	fn global_farm_owner(global_farm_id: GlobalFarmId) -> Option<T::AccountId> {
+		pallet_liquidity_mining::GlobalFarm::<T, I>::get(global_farm_id).map(|global_farm| global_farm.owner)
+	}
+}
+
+/// Assets of the global farms.
+pub trait GlobalFarmAssets<AssetId> {
+	/// Return incentivized asset and reward currency of the global farm or `None` if global farm
+	/// doesn't exist.
+	fn global_farm_assets(global_farm_id: GlobalFarmId) -> Option<(AssetId, AssetId)>;
+}
+
+impl<T: pallet_liquidity_mining::Config<I>, I: 'static> GlobalFarmAssets<T::AssetId>
+	for pallet_liquidity_mining::Pallet<T, I>
+{
+	fn global_farm_assets(global_farm_id: GlobalFarmId) -> Option<(T::AssetId, T::AssetId)> {
+		pallet_liquidity_mining::GlobalFarm::<T, I>::get(global_farm_id)
+			.map(|global_farm| (global_farm.incentivized_asset, global_farm.reward_currency))
+	}
+}
+
+/// Top-up of the global farm's rewards.
+pub trait GlobalFarmTopUp<AccountId, Period> {
+	/// Transfer `amount` of global farm's reward currency from `who` to the global farm, extend
+	/// global farm's planned yielding periods by `extra_periods` and return recalculated
+	/// `max_reward_per_period`.
+	fn top_up_global_farm(
+		who: &AccountId,
+		global_farm_id: GlobalFarmId,
+		amount: Balance,
+		extra_periods: Period,
+	) -> Result<Balance, DispatchError>;
+}
+
+impl<T: pallet_liquidity_mining::Config<I>, I: 'static> GlobalFarmTopUp<T::AccountId, BlockNumberFor<T>>
+	for pallet_liquidity_mining::Pallet<T, I>
+{
+	fn top_up_global_farm(
+		who: &T::AccountId,
+		global_farm_id: GlobalFarmId,
+		amount: Balance,
+		extra_periods: BlockNumberFor<T>,
+	) -> Result<Balance, DispatchError> {
+		let global_farm = pallet_liquidity_mining::GlobalFarm::<T, I>::get(global_farm_id)
+			.ok_or(pallet_liquidity_mining::Error::<T, I>::GlobalFarmNotFound)?;
+
+		let global_farm_account = Self::farm_account_id(global_farm_id)?;
+		T::MultiCurrency::transfer(global_farm.reward_currency, who, &global_farm_account, amount)?;
+
+		let planned_yielding_periods = global_farm
+			.planned_yielding_periods
+			.checked_add(&extra_periods)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		//NOTE: `max_reward_per_period` is recalculated from the rewards left in the global farm.
+		<Self as LiquidityMiningMutate<T::AccountId, T::AssetId, BlockNumberFor<T>>>::update_global_farm(
+			global_farm_id,
+			planned_yielding_periods,
+			global_farm.yield_per_period,
+			global_farm.min_deposit,
+		)?;
+
+		pallet_liquidity_mining::GlobalFarm::<T, I>::get(global_farm_id)
+			.map(|global_farm| global_farm.max_reward_per_period)
+			.ok_or(pallet_liquidity_mining::Error::<T, I>::GlobalFarmNotFound.into())
+	}
+}
+
+/// Removal of the liq. mining deposits without reward calculations.
+pub trait EmergencyWithdraw<AmmPoolId> {
+	/// Remove the deposit from all its yield farms and return its AMM pool id and amount of LP
+	/// shares or `None` if deposit doesn't exist. Unclaimed rewards of the deposit are forfeited.
+	///
+	/// This function must not depend on the farms' reward accounting so deposit can be removed
+	/// even if farms are in an inconsistent state.
+	fn emergency_withdraw(deposit_id: DepositId) -> Option<(AmmPoolId, Balance)>;
+}
+
+impl<T: pallet_liquidity_mining::Config<I>, I: 'static> EmergencyWithdraw<T::AmmPoolId>
+	for pallet_liquidity_mining::Pallet<T, I>
+{
+	fn emergency_withdraw(deposit_id: DepositId) -> Option<(T::AmmPoolId, Balance)> {
+		let deposit = pallet_liquidity_mining::Deposit::<T, I>::take(deposit_id)?;
+
+		//NOTE: only farms' share counters are updated, rewards and rpvs are not touched. Missing
+		//farms are skipped and all the arithmetic saturates so this can't fail on broken farms.
+		for entry in deposit.yield_farm_entries.iter() {
+			let yield_farm_key = (deposit.amm_pool_id.clone(), entry.global_farm_id, entry.yield_farm_id);
+			let Some(mut yield_farm) = pallet_liquidity_mining::YieldFarm::<T, I>::get(yield_farm_key.clone()) else {
+				continue;
+			};
+
+			yield_farm.total_shares = yield_farm.total_shares.saturating_sub(deposit.shares);
+			yield_farm.total_valued_shares = yield_farm.total_valued_shares.saturating_sub(entry.valued_shares);
+			yield_farm.entries_count = yield_farm.entries_count.saturating_sub(1);
+
+			let is_active = yield_farm.state == pallet_liquidity_mining::FarmState::Active;
+			let shares_z = yield_farm.multiplier.saturating_mul_int(entry.valued_shares);
+			let is_removed = yield_farm.state == pallet_liquidity_mining::FarmState::Terminated
+				&& yield_farm.entries_count.is_zero();
+
+			if is_removed {
+				pallet_liquidity_mining::YieldFarm::<T, I>::remove(yield_farm_key);
+			} else {
+				pallet_liquidity_mining::YieldFarm::<T, I>::insert(yield_farm_key, yield_farm);
+			}
+
+			//NOTE: stopped and terminated yield farms are not part of global farm's shares.
+			pallet_liquidity_mining::GlobalFarm::<T, I>::mutate_exists(entry.global_farm_id, |maybe_global_farm| {
+				let Some(global_farm) = maybe_global_farm else {
+					return;
+				};
+
+				if is_active {
+					global_farm.total_shares_z = global_farm.total_shares_z.saturating_sub(shares_z);
+				}
+
+				//NOTE: terminated yield farm is removed with its last deposit and terminated global
+				//farm is removed with its last yield farm.
+				if is_removed {
+					global_farm.total_yield_farms_count = global_farm.total_yield_farms_count.saturating_sub(1);
+
+					if global_farm.state == pallet_liquidity_mining::FarmState::Terminated
+						&& global_farm.total_yield_farms_count.is_zero()
+					{
+						*maybe_global_farm = None;
+					}
+				}
+			});
+		}
+
+		Some((deposit.amm_pool_id, deposit.shares))
+	}
+}
+
+/// Start of the liq. mining farm entries of the scheduled yield farms.
+pub trait YieldFarmEntryStart<BlockNumber> {
+	/// Set the period of `start_at` block as the period deposit's entry in the yield farm was
+	/// entered at so entry's loyalty is counted from the yield farm's start and not from the
+	/// period the deposit was actually entered in.
+	fn set_entry_start(deposit_id: DepositId, yield_farm_id: YieldFarmId, start_at: BlockNumber) -> DispatchResult;
+}
+
+impl<T: pallet_liquidity_mining::Config<I>, I: 'static> YieldFarmEntryStart<BlockNumberFor<T>>
+	for pallet_liquidity_mining::Pallet<T, I>
+{
+	fn set_entry_start(
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+		start_at: BlockNumberFor<T>,
+	) -> DispatchResult {
+		pallet_liquidity_mining::Deposit::<T, I>::try_mutate(deposit_id, |maybe_deposit| -> DispatchResult {
+			let deposit = maybe_deposit
+				.as_mut()
+				.ok_or(DispatchError::Other("Deposit not found"))?;
+
+			let entry = deposit
+				.yield_farm_entries
+				.iter_mut()
+				.find(|entry| entry.yield_farm_id == yield_farm_id)
+				.ok_or(pallet_liquidity_mining::Error::<T, I>::YieldFarmEntryNotFound)?;
+
+			let global_farm = pallet_liquidity_mining::GlobalFarm::<T, I>::get(entry.global_farm_id)
+				.ok_or(pallet_liquidity_mining::Error::<T, I>::GlobalFarmNotFound)?;
+
+			//NOTE: period is calculated the same way as in the liq. mining, from the block number of
+			//`T::BlockNumberProvider`.
+			let start_period = start_at
+				.checked_div(&global_farm.blocks_per_period)
+				.ok_or(ArithmeticError::DivisionByZero)?;
+
+			//NOTE: entry can only be moved back to the start, never to the future.
+			if start_period < entry.entered_at {
+				entry.entered_at = start_period;
+			}
+
+			Ok(())
+		})
+	}
+}
+
+/// Asset swaps used to convert claimed rewards to the pool's assets when auto-compounding.
+pub trait AssetSwap<AccountId, AssetId, Balance> {
+	/// Sell `amount_in` of `asset_in` for `asset_out` and return received amount of `asset_out`.
+	fn sell(
+		who: &AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	) -> Result<Balance, DispatchError>;
+
+	/// Return oracle price of `asset_in` denominated in `asset_out` as `(n, d)` tuple.
+	fn oracle_price(asset_in: AssetId, asset_out: AssetId) -> Option<(Balance, Balance)>;
+
+	/// Return oracle entries `(source, asset_a, asset_b)` used to price `asset_in` in `asset_out`.
+	fn oracle_pairs(asset_in: AssetId, asset_out: AssetId) -> Vec<(Source, AssetId, AssetId)>;
+
+	/// Weight of the worst case sell including oracle price query.
+	fn sell_weight() -> Weight;
+
+	/// Weight of the worst case oracle price query.
+	fn oracle_price_weight() -> Weight;
+}
+
+/// Swaps are not supported so auto-compounding is disabled.
+impl<AccountId, AssetId, Balance> AssetSwap<AccountId, AssetId, Balance> for () {
+	fn sell(
+		_who: &AccountId,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_in: Balance,
+		_min_amount_out: Balance,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("Asset swaps are not supported"))
+	}
+
+	fn oracle_price(_asset_in: AssetId, _asset_out: AssetId) -> Option<(Balance, Balance)> {
+		None
+	}
+
+	fn oracle_pairs(_asset_in: AssetId, _asset_out: AssetId) -> Vec<(Source, AssetId, AssetId)> {
+		Vec::new()
+	}
+
+	fn sell_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn oracle_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Increase of LP shares of the existing liq. mining deposit.
+pub trait IncreaseDepositShares<AmmPoolId> {
+	/// Add `shares_amount` of LP shares to the deposit and update all its yield farm entries.
+	/// Rewards earned by the deposit before the increase are preserved and added shares share
+	/// the deposit's loyalty.
+	fn increase_deposit_shares<F>(
+		deposit_id: DepositId,
+		amm_pool_id: AmmPoolId,
+		shares_amount: Balance,
+		get_token_value_of_lp_shares: F,
+	) -> DispatchResult
+	where
+		F: Fn(AssetId, AmmPoolId, Balance) -> Result<Balance, DispatchError>;
+}
+
+/// Liq. mining doesn't support increase of deposit's shares so auto-compounding is disabled.
+impl<AmmPoolId> IncreaseDepositShares<AmmPoolId> for () {
+	fn increase_deposit_shares<F>(
+		_deposit_id: DepositId,
+		_amm_pool_id: AmmPoolId,
+		_shares_amount: Balance,
+		_get_token_value_of_lp_shares: F,
+	) -> DispatchResult
+	where
+		F: Fn(AssetId, AmmPoolId, Balance) -> Result<Balance, DispatchError>,
+	{
+		Err(DispatchError::Other("Increase of deposit shares is not supported"))
+	}
+}
+
+impl<T, I: 'static> IncreaseDepositShares<T::AmmPoolId> for pallet_liquidity_mining::Pallet<T, I>
+where
+	T: pallet_liquidity_mining::Config<I, AssetId = AssetId>,
+{
+	fn increase_deposit_shares<F>(
+		deposit_id: DepositId,
+		amm_pool_id: T::AmmPoolId,
+		shares_amount: Balance,
+		get_token_value_of_lp_shares: F,
+	) -> DispatchResult
+	where
+		F: Fn(AssetId, T::AmmPoolId, Balance) -> Result<Balance, DispatchError>,
+	{
+		pallet_liquidity_mining::Deposit::<T, I>::try_mutate(deposit_id, |maybe_deposit| -> DispatchResult {
+			let deposit = maybe_deposit
+				.as_mut()
+				.ok_or(DispatchError::Other("Deposit not found"))?;
+			ensure!(
+				deposit.amm_pool_id == amm_pool_id,
+				DispatchError::Other("AMM pool id mismatch")
+			);
+
+			deposit.shares = deposit
+				.shares
+				.checked_add(shares_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			for entry in deposit.yield_farm_entries.iter_mut() {
+				let mut global_farm = pallet_liquidity_mining::GlobalFarm::<T, I>::get(entry.global_farm_id)
+					.ok_or(pallet_liquidity_mining::Error::<T, I>::GlobalFarmNotFound)?;
+				let yield_farm_key = (amm_pool_id.clone(), entry.global_farm_id, entry.yield_farm_id);
+				let mut yield_farm = pallet_liquidity_mining::YieldFarm::<T, I>::get(yield_farm_key.clone())
+					.ok_or(pallet_liquidity_mining::Error::<T, I>::YieldFarmNotFound)?;
+
+				let is_active = yield_farm.state == pallet_liquidity_mining::FarmState::Active;
+				if is_active {
+					//NOTE: rewards for the periods before the increase must be distributed with
+					//the old valued shares so farm must be synced e.g. by claiming the rewards.
+					let current_period = T::BlockNumberProvider::current_block_number()
+						.checked_div(&global_farm.blocks_per_period)
+						.ok_or(ArithmeticError::DivisionByZero)?;
+					ensure!(
+						yield_farm.updated_at == current_period,
+						DispatchError::Other("Yield farm is not synced")
+					);
+				}
+
+				let added_valued_shares =
+					get_token_value_of_lp_shares(global_farm.incentivized_asset, amm_pool_id.clone(), shares_amount)?;
+				let valued_shares = entry
+					.valued_shares
+					.checked_add(added_valued_shares)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				//NOTE: `accumulated_rpvs` of the entry is moved so the rewards earned before the
+				//increase are preserved: `(rpvs - entry_rpvs') * valued_shares' = (rpvs - entry_rpvs) * valued_shares`.
+				//Product is rounded down so the deposit never earns more than the farm distributed.
+				let rpvs_diff = yield_farm
+					.accumulated_rpvs
+					.checked_sub(&entry.accumulated_rpvs)
+					.ok_or(ArithmeticError::Underflow)?;
+				let valued_shares_ratio = FixedU128::checked_from_rational(entry.valued_shares, valued_shares)
+					.ok_or(ArithmeticError::Overflow)?;
+				let scaled_rpvs_diff = rpvs_diff
+					.checked_mul(&valued_shares_ratio)
+					.ok_or(ArithmeticError::Overflow)?;
+				entry.accumulated_rpvs = yield_farm
+					.accumulated_rpvs
+					.checked_sub(&scaled_rpvs_diff)
+					.ok_or(ArithmeticError::Underflow)?;
+				entry.valued_shares = valued_shares;
+
+				yield_farm.total_shares = yield_farm
+					.total_shares
+					.checked_add(shares_amount)
+					.ok_or(ArithmeticError::Overflow)?;
+				yield_farm.total_valued_shares = yield_farm
+					.total_valued_shares
+					.checked_add(added_valued_shares)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				//NOTE: stopped and terminated yield farms are not part of global farm's shares.
+				if is_active {
+					let added_shares_z = yield_farm
+						.multiplier
+						.checked_mul_int(added_valued_shares)
+						.ok_or(ArithmeticError::Overflow)?;
+					global_farm.total_shares_z = global_farm
+						.total_shares_z
+						.checked_add(added_shares_z)
+						.ok_or(ArithmeticError::Overflow)?;
+
+					pallet_liquidity_mining::GlobalFarm::<T, I>::insert(entry.global_farm_id, global_farm);
+				}
+
+				pallet_liquidity_mining::YieldFarm::<T, I>::insert(yield_farm_key, yield_farm);
+			}
+
+			Ok(())
+		})
+	}
+}
+
+/// Split and merge of the liq. mining deposits.
+pub trait SplitMergeDeposits {
+	/// Move `shares_amount` of deposit's LP shares to a new deposit and return its id. New
+	/// deposit has the same yield farm entries with proportional valued shares and the same
+	/// loyalty state.
+	fn split_deposit(deposit_id: DepositId, shares_amount: Balance) -> Result<DepositId, DispatchError>;
+
+	/// Move LP shares and yield farm entries of `other_deposit_id` to `deposit_id` and remove
+	/// `other_deposit_id`. Deposits must be in the same pool and entered into the same yield farms.
+	fn merge_deposits(deposit_id: DepositId, other_deposit_id: DepositId) -> DispatchResult;
+}
+
+/// Liq. mining doesn't support split and merge of the deposits.
+impl SplitMergeDeposits for () {
+	fn split_deposit(_deposit_id: DepositId, _shares_amount: Balance) -> Result<DepositId, DispatchError> {
+		Err(DispatchError::Other("Split of deposits is not supported"))
+	}
+
+	fn merge_deposits(_deposit_id: DepositId, _other_deposit_id: DepositId) -> DispatchResult {
+		Err(DispatchError::Other("Merge of deposits is not supported"))
+	}
+}
+
+impl<T, I: 'static> SplitMergeDeposits for pallet_liquidity_mining::Pallet<T, I>
+where
+	T: pallet_liquidity_mining::Config<I>,
+{
+	fn split_deposit(deposit_id: DepositId, shares_amount: Balance) -> Result<DepositId, DispatchError> {
+		let mut deposit = pallet_liquidity_mining::Deposit::<T, I>::get(deposit_id)
+			.ok_or(DispatchError::Other("Deposit not found"))?;
+		ensure!(
+			!shares_amount.is_zero() && shares_amount < deposit.shares,
+			DispatchError::Other("Invalid split amount")
+		);
+
+		let deposit_shares = deposit.shares;
+		let remaining_shares = deposit_shares.saturating_sub(shares_amount);
+		let mut new_deposit = deposit.clone();
+		new_deposit.shares = shares_amount;
+		deposit.shares = remaining_shares;
+
+		//NOTE: entries keep `accumulated_rpvs` and loyalty so rewards of both deposits add up to
+		//the rewards of the original deposit. Moved amounts are rounded down.
+		for (entry, new_entry) in deposit
+			.yield_farm_entries
+			.iter_mut()
+			.zip(new_deposit.yield_farm_entries.iter_mut())
+		{
+			let min_deposit = pallet_liquidity_mining::GlobalFarm::<T, I>::get(entry.global_farm_id)
+				.map(|global_farm| global_farm.min_deposit)
+				.unwrap_or_default();
+			ensure!(
+				shares_amount >= min_deposit && remaining_shares >= min_deposit,
+				DispatchError::Other("Split deposit is below min. deposit")
+			);
+
+			let moved_valued_shares =
+				multiply_by_rational_with_rounding(entry.valued_shares, shares_amount, deposit_shares, Rounding::Down)
+					.ok_or(ArithmeticError::Overflow)?;
+			let moved_claimed_rewards = multiply_by_rational_with_rounding(
+				entry.accumulated_claimed_rewards,
+				moved_valued_shares,
+				entry.valued_shares,
+				Rounding::Down,
+			)
+			.unwrap_or_default();
+
+			new_entry.valued_shares = moved_valued_shares;
+			new_entry.accumulated_claimed_rewards = moved_claimed_rewards;
+			entry.valued_shares = entry.valued_shares.saturating_sub(moved_valued_shares);
+			entry.accumulated_claimed_rewards = entry.accumulated_claimed_rewards.saturating_sub(moved_claimed_rewards);
+
+			pallet_liquidity_mining::YieldFarm::<T, I>::try_mutate(
+				(deposit.amm_pool_id.clone(), entry.global_farm_id, entry.yield_farm_id),
+				|maybe_yield_farm| -> DispatchResult {
+					let yield_farm = maybe_yield_farm
+						.as_mut()
+						.ok_or(pallet_liquidity_mining::Error::<T, I>::YieldFarmNotFound)?;
+
+					yield_farm.entries_count = yield_farm
+						.entries_count
+						.checked_add(1)
+						.ok_or(ArithmeticError::Overflow)?;
+
+					Ok(())
+				},
+			)?;
+		}
+
+		let new_deposit_id =
+			pallet_liquidity_mining::DepositSequencer::<T, I>::try_mutate(|id| -> Result<DepositId, DispatchError> {
+				*id = id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+				Ok(*id)
+			})?;
+
+		pallet_liquidity_mining::Deposit::<T, I>::insert(deposit_id, deposit);
+		pallet_liquidity_mining::Deposit::<T, I>::insert(new_deposit_id, new_deposit);
+
+		Ok(new_deposit_id)
+	}
+
+	fn merge_deposits(deposit_id: DepositId, other_deposit_id: DepositId) -> DispatchResult {
+		let other_deposit = pallet_liquidity_mining::Deposit::<T, I>::get(other_deposit_id)
+			.ok_or(DispatchError::Other("Deposit not found"))?;
+
+		pallet_liquidity_mining::Deposit::<T, I>::try_mutate(deposit_id, |maybe_deposit| -> DispatchResult {
+			let deposit = maybe_deposit
+				.as_mut()
+				.ok_or(DispatchError::Other("Deposit not found"))?;
+			ensure!(
+				deposit.amm_pool_id == other_deposit.amm_pool_id
+					&& deposit.yield_farm_entries.len() == other_deposit.yield_farm_entries.len(),
+				DispatchError::Other("Incompatible deposits")
+			);
+
+			deposit.shares = deposit
+				.shares
+				.checked_add(other_deposit.shares)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			for entry in deposit.yield_farm_entries.iter_mut() {
+				let other_entry = other_deposit
+					.yield_farm_entries
+					.iter()
+					.find(|other_entry| other_entry.yield_farm_id == entry.yield_farm_id)
+					.ok_or(DispatchError::Other("Incompatible deposits"))?;
+
+				//NOTE: loyalty of entries entered in different periods can't be merged without
+				//changing the rewards.
+				ensure!(
+					entry.entered_at == other_entry.entered_at
+						&& entry.stopped_at_creation == other_entry.stopped_at_creation,
+					DispatchError::Other("Incompatible deposits")
+				);
+
+				let valued_shares = entry
+					.valued_shares
+					.checked_add(other_entry.valued_shares)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				//NOTE: `accumulated_rpvs` of the merged entry is valued shares weighted average so
+				//`(rpvs - entry_rpvs') * valued_shares' = (rpvs - entry_rpvs) * valued_shares + (rpvs - other_rpvs) * other_valued_shares`.
+				//It's rounded up so merged deposit never earns more than both deposits.
+				if !valued_shares.is_zero() {
+					let entry_rpvs = entry.accumulated_rpvs.into_inner();
+					let other_rpvs = other_entry.accumulated_rpvs.into_inner();
+					let rpvs_diff = entry_rpvs.abs_diff(other_rpvs);
+
+					let merged_rpvs = if other_rpvs > entry_rpvs {
+						multiply_by_rational_with_rounding(
+							rpvs_diff,
+							other_entry.valued_shares,
+							valued_shares,
+							Rounding::Up,
+						)
+						.and_then(|shift| entry_rpvs.checked_add(shift))
+					} else {
+						multiply_by_rational_with_rounding(
+							rpvs_diff,
+							other_entry.valued_shares,
+							valued_shares,
+							Rounding::Down,
+						)
+						.and_then(|shift| entry_rpvs.checked_sub(shift))
+					}
+					.ok_or(ArithmeticError::Overflow)?;
+
+					entry.accumulated_rpvs = FixedU128::from_inner(merged_rpvs);
+				}
+
+				entry.valued_shares = valued_shares;
+				entry.accumulated_claimed_rewards = entry
+					.accumulated_claimed_rewards
+					.checked_add(other_entry.accumulated_claimed_rewards)
+					.ok_or(ArithmeticError::Overflow)?;
+				entry.updated_at = entry.updated_at.max(other_entry.updated_at);
+
+				pallet_liquidity_mining::YieldFarm::<T, I>::try_mutate(
+					(deposit.amm_pool_id.clone(), entry.global_farm_id, entry.yield_farm_id),
+					|maybe_yield_farm| -> DispatchResult {
+						let yield_farm = maybe_yield_farm
+							.as_mut()
+							.ok_or(pallet_liquidity_mining::Error::<T, I>::YieldFarmNotFound)?;
+
+						yield_farm.entries_count = yield_farm.entries_count.saturating_sub(1);
+
+						Ok(())
+					},
+				)?;
+			}
+
+			Ok(())
+		})?;
+
+		pallet_liquidity_mining::Deposit::<T, I>::remove(other_deposit_id);
+
+		Ok(())
+	}
+}
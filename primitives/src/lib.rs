@@ -88,6 +88,9 @@ pub type CollectionId = u128;
 /// NFT Item ID
 pub type ItemId = u128;
 
+/// Identifier of the named reserves
+pub type NamedReserveIdentifier = [u8; 8];
+
 parameter_types! {
 	/// NFT metadata limit
 	pub const UniquesStringLimit: u32 = 72;
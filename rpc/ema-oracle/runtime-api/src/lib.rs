@@ -0,0 +1,89 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the EMA oracle prices.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use hydradx_traits::{router::Trade, OraclePeriod, Source};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Price as a rational number `n / d`.
+#[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Price<Balance> {
+	pub n: Balance,
+	pub d: Balance,
+}
+
+/// Price of the oracle entry.
+#[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OraclePrice<Balance, BlockNumber> {
+	pub price: Price<Balance>,
+	/// Number of blocks since the oracle entry was initialized.
+	pub oracle_age: BlockNumber,
+}
+
+/// Oracle entry tracked by the EMA oracle.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OracleEntry<AssetId, Balance, BlockNumber> {
+	pub source: Source,
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	pub period: OraclePeriod,
+	/// Price of the `asset_a` denominated in the `asset_b`.
+	pub price: Price<Balance>,
+	/// Liquidity of the `asset_a` in the pool.
+	pub liquidity_a: Balance,
+	/// Liquidity of the `asset_b` in the pool.
+	pub liquidity_b: Balance,
+	/// Number of blocks since the oracle entry was initialized.
+	pub oracle_age: BlockNumber,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to query prices of the EMA oracle.
+	pub trait EmaOracleApi<AssetId, Balance, BlockNumber>
+	where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Return price of the `asset_a` denominated in the `asset_b` tracked by the oracle of the
+		/// `source` for the `period` or `None` if the price is not available.
+		fn get_price(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+			source: Source,
+		) -> Option<OraclePrice<Balance, BlockNumber>>;
+
+		/// Return price of the `route`'s first asset in denominated in its last asset out, made of
+		/// the oracle prices of the route's trades, or `None` if any of the prices is not available.
+		fn get_route_price(route: Vec<Trade<AssetId>>, period: OraclePeriod) -> Option<Price<Balance>>;
+
+		/// Return all entries tracked by the oracle updated to the current block.
+		fn oracle_entries() -> Vec<OracleEntry<AssetId, Balance, BlockNumber>>;
+	}
+}
@@ -0,0 +1,149 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the EMA oracle prices.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use hydradx_traits::{router::Trade, OraclePeriod, Source};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use basilisk_ema_oracle_runtime_api::{EmaOracleApi as EmaOracleRuntimeApi, OracleEntry, OraclePrice, Price};
+
+#[rpc(client, server)]
+pub trait EmaOracleApi<BlockHash, AssetId, Balance, BlockNumber> {
+	/// Get price of the `asset_a` denominated in the `asset_b` tracked by the oracle of the `source`.
+	#[method(name = "emaOracle_getPrice")]
+	fn get_price(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<OraclePrice<Balance, BlockNumber>>>;
+
+	/// Get oracle price of the `route`.
+	#[method(name = "emaOracle_getRoutePrice")]
+	fn get_route_price(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		period: OraclePeriod,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Price<Balance>>>;
+
+	/// Get all entries tracked by the oracle.
+	#[method(name = "emaOracle_entries")]
+	fn oracle_entries(&self, at: Option<BlockHash>) -> RpcResult<Vec<OracleEntry<AssetId, Balance, BlockNumber>>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query prices of the EMA oracle.
+pub struct EmaOracle<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> EmaOracle<C, Block> {
+	/// Create new `EmaOracle` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the oracle price.",
+		Some(format!("{:?}", e)),
+	)
+}
+
+impl<C, Block, AssetId, Balance, BlockNumber> EmaOracleApiServer<<Block as BlockT>::Hash, AssetId, Balance, BlockNumber>
+	for EmaOracle<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn get_price(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<OraclePrice<Balance, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client
+			.runtime_api()
+			.get_price(at, asset_a, asset_b, period, source)
+			.map_err(runtime_error)
+	}
+
+	fn get_route_price(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		period: OraclePeriod,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Price<Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client
+			.runtime_api()
+			.get_route_price(at, route, period)
+			.map_err(runtime_error)
+	}
+
+	fn oracle_entries(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<OracleEntry<AssetId, Balance, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client.runtime_api().oracle_entries(at).map_err(runtime_error)
+	}
+}
@@ -0,0 +1,94 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the router quotes.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use hydradx_traits::router::{PoolType, Trade};
+use scale_info::TypeInfo;
+use sp_arithmetic::{FixedU128, Permill};
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Quote of a single trade of the route.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TradeQuote<AssetId, Balance> {
+	pub pool: PoolType<AssetId>,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	/// Spot price of the `asset_out` denominated in the `asset_in` including the pool's fee.
+	pub spot_price_with_fee: FixedU128,
+	/// Fee charged by the pool.
+	pub fee: Permill,
+}
+
+/// Quote of the whole route.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RouteQuote<AssetId, Balance> {
+	pub route: Vec<Trade<AssetId>>,
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	pub trades: Vec<TradeQuote<AssetId, Balance>>,
+	/// Relative difference between the quoted amount and the amount which would be traded at the
+	/// route's spot price with fees.
+	pub price_impact: FixedU128,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to quote trades executed by the router.
+	pub trait RouterApi<AssetId, Balance>
+	where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Return quote of selling `amount_in` through the `route` or `None` if the route can't
+		/// be traded.
+		fn quote_sell(route: Vec<Trade<AssetId>>, amount_in: Balance) -> Option<RouteQuote<AssetId, Balance>>;
+
+		/// Return quote of buying `amount_out` through the `route` or `None` if the route can't
+		/// be traded.
+		fn quote_buy(route: Vec<Trade<AssetId>>, amount_out: Balance) -> Option<RouteQuote<AssetId, Balance>>;
+
+		/// Return quote of the route with the biggest amount out of selling `amount_in` of the
+		/// `asset_in` for the `asset_out`. Routes are searched across all XYK, LBP and stableswap pools and
+		/// can have at most `max_hops` trades.
+		fn best_sell_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			max_hops: u32,
+		) -> Option<RouteQuote<AssetId, Balance>>;
+
+		/// Return quote of the route with the smallest amount in of buying `amount_out` of the
+		/// `asset_out` for the `asset_in`. Routes are searched across all XYK, LBP and stableswap pools and
+		/// can have at most `max_hops` trades.
+		fn best_buy_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			max_hops: u32,
+		) -> Option<RouteQuote<AssetId, Balance>>;
+	}
+}
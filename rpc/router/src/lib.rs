@@ -0,0 +1,184 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the router quotes.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use hydradx_traits::router::Trade;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use basilisk_router_runtime_api::{RouteQuote, RouterApi as RouterRuntimeApi, TradeQuote};
+
+#[rpc(client, server)]
+pub trait RouterApi<BlockHash, AssetId, Balance> {
+	/// Quote selling `amount_in` through the `route`.
+	#[method(name = "router_quoteSell")]
+	fn quote_sell(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		amount_in: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RouteQuote<AssetId, Balance>>>;
+
+	/// Quote buying `amount_out` through the `route`.
+	#[method(name = "router_quoteBuy")]
+	fn quote_buy(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		amount_out: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RouteQuote<AssetId, Balance>>>;
+
+	/// Search for the route with the biggest amount out of selling `amount_in`.
+	#[method(name = "router_bestSellRoute")]
+	fn best_sell_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		max_hops: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RouteQuote<AssetId, Balance>>>;
+
+	/// Search for the route with the smallest amount in of buying `amount_out`.
+	#[method(name = "router_bestBuyRoute")]
+	fn best_buy_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_hops: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RouteQuote<AssetId, Balance>>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to quote trades executed by the router.
+pub struct Router<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Router<C, Block> {
+	/// Create new `Router` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the router quote.",
+		Some(format!("{:?}", e)),
+	)
+}
+
+impl<C, Block, AssetId, Balance> RouterApiServer<<Block as BlockT>::Hash, AssetId, Balance> for Router<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RouterRuntimeApi<Block, AssetId, Balance>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn quote_sell(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		amount_in: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RouteQuote<AssetId, Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client
+			.runtime_api()
+			.quote_sell(at, route, amount_in)
+			.map_err(runtime_error)
+	}
+
+	fn quote_buy(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		amount_out: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RouteQuote<AssetId, Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client
+			.runtime_api()
+			.quote_buy(at, route, amount_out)
+			.map_err(runtime_error)
+	}
+
+	fn best_sell_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		max_hops: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RouteQuote<AssetId, Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client
+			.runtime_api()
+			.best_sell_route(at, asset_in, asset_out, amount_in, max_hops)
+			.map_err(runtime_error)
+	}
+
+	fn best_buy_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_hops: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RouteQuote<AssetId, Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		self.client
+			.runtime_api()
+			.best_buy_route(at, asset_in, asset_out, amount_out, max_hops)
+			.map_err(runtime_error)
+	}
+}
@@ -1,13 +1,14 @@
 use frame_support::sp_runtime::DispatchResult;
-use frame_support::traits::BalanceStatus;
+use frame_support::traits::{BalanceStatus, Get};
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydra_dx_math::{
 	ema::EmaPrice,
 	support::rational::{round_u512_to_rational, Rounding},
 };
 use hydradx_traits::{
-	router::{PoolType, Trade},
-	AggregatedPriceOracle, OraclePeriod, PriceOracle,
+	router::{ExecutorError, PoolType, Trade, TradeExecution},
+	AggregatedPriceOracle, OnCreatePoolHandler, OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod, PriceOracle,
+	Source, AMM,
 };
 use orml_traits::currency::TransferAll;
 use orml_traits::{
@@ -15,9 +16,10 @@ use orml_traits::{
 	NamedMultiReservableCurrency,
 };
 use pallet_ema_oracle::OracleError;
+use pallet_stableswap::{traits::StableswapHooks, types::PoolState};
 use primitive_types::U512;
-use primitives::BlockNumber;
-use sp_runtime::DispatchError;
+use primitives::{AccountId, AssetId, Balance, BlockNumber};
+use sp_runtime::{traits::Zero, DispatchError, FixedU128, Saturating};
 use sp_std::{marker::PhantomData, vec::Vec};
 
 pub struct OrmlTokensAdapter<T>(PhantomData<T>);
@@ -311,19 +313,14 @@ where
 			let asset_a = trade.asset_in;
 			let asset_b = trade.asset_out;
 			let price = match trade.pool {
-				PoolType::XYK => {
-					let price_result = AggregatedPriceGetter::get_price(
-						asset_a,
-						asset_b,
-						period,
-						crate::XYKOracleSourceIdentifier::get(),
-					);
-
-					match price_result {
-						Ok(price) => price.0,
-						Err(OracleError::SameAsset) => EmaPrice::from(1),
-						Err(_) => return None,
-					}
+				PoolType::XYK => Self::source_price(asset_a, asset_b, period, crate::XYKOracleSourceIdentifier::get())?,
+				PoolType::LBP => Self::source_price(asset_a, asset_b, period, crate::LBPOracleSourceIdentifier::get())?,
+				// oracle tracks the price of each pool asset in the pool's share asset
+				// so the price of the trade is made of the two prices
+				PoolType::Stableswap(pool_id) => {
+					let source = crate::StableswapOracleSourceIdentifier::get();
+					prices.push(Self::source_price(asset_a, pool_id, period, source)?);
+					Self::source_price(pool_id, asset_b, period, source)?
 				}
 				_ => return None,
 			};
@@ -348,3 +345,565 @@ where
 		Some(EmaPrice::new(rat_as_u128.0, rat_as_u128.1))
 	}
 }
+
+impl<AssetId, AggregatedPriceGetter> OraclePriceProvider<AssetId, AggregatedPriceGetter>
+where
+	AggregatedPriceGetter: AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice, Error = OracleError>,
+{
+	fn source_price(asset_a: AssetId, asset_b: AssetId, period: OraclePeriod, source: Source) -> Option<EmaPrice> {
+		match AggregatedPriceGetter::get_price(asset_a, asset_b, period, source) {
+			Ok(price) => Some(price.0),
+			Err(OracleError::SameAsset) => Some(EmaPrice::from(1)),
+			Err(_) => None,
+		}
+	}
+
+	/// Oracle entries `(source, asset_a, asset_b)` read by `price` to price the `route`.
+	pub fn oracle_entries(route: &[Trade<AssetId>]) -> Vec<(Source, AssetId, AssetId)>
+	where
+		AssetId: Copy,
+	{
+		let mut entries = Vec::with_capacity(route.len());
+		for trade in route {
+			match trade.pool {
+				PoolType::XYK => {
+					entries.push((crate::XYKOracleSourceIdentifier::get(), trade.asset_in, trade.asset_out))
+				}
+				PoolType::LBP => {
+					entries.push((crate::LBPOracleSourceIdentifier::get(), trade.asset_in, trade.asset_out))
+				}
+				PoolType::Stableswap(pool_id) => {
+					let source = crate::StableswapOracleSourceIdentifier::get();
+					entries.push((source, trade.asset_in, pool_id));
+					entries.push((source, pool_id, trade.asset_out));
+				}
+				_ => {}
+			}
+		}
+
+		entries
+	}
+}
+
+/// Record the swap executed in the current block in the trade stats.
+fn note_swap(asset_in: AssetId, asset_out: AssetId, amount_in: Balance, amount_out: Balance, fee: (AssetId, Balance)) {
+	crate::TradeStats::note_swap(&pallet_trade_stats::Swap {
+		asset_in,
+		asset_out,
+		amount_in,
+		amount_out,
+		fees: sp_std::vec![fee],
+	});
+}
+
+/// AMM handler of the XYK pools. Trades and liquidity changes are ingested into the EMA oracle and
+/// trades are recorded in the trade stats.
+pub struct XykAmmHandler;
+
+impl OnCreatePoolHandler<AssetId> for XykAmmHandler {
+	fn on_create_pool(asset_a: AssetId, asset_b: AssetId) -> DispatchResult {
+		pallet_ema_oracle::OnActivityHandler::<crate::Runtime>::on_create_pool(asset_a, asset_b)
+	}
+}
+
+impl OnTradeHandler<AssetId, Balance, EmaPrice> for XykAmmHandler {
+	fn on_trade(
+		source: Source,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+		liquidity_in: Balance,
+		liquidity_out: Balance,
+		price: EmaPrice,
+		external_routed_liquidity: Option<Balance>,
+	) -> Result<frame_support::weights::Weight, (frame_support::weights::Weight, DispatchError)> {
+		//NOTE: fee of the trade stays in the pool, so it's the difference between the amount out
+		//without the fee, calculated from the liquidity before the trade, and the actual amount out.
+		let amount_out_without_fee = hydra_dx_math::xyk::calculate_out_given_in(
+			liquidity_in.saturating_sub(amount_in),
+			liquidity_out.saturating_add(amount_out),
+			amount_in,
+		)
+		.unwrap_or(amount_out);
+		note_swap(
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			(asset_out, amount_out_without_fee.saturating_sub(amount_out)),
+		);
+
+		let note_weight = crate::TradeStats::note_swap_weight();
+
+		EmaOracleIngestion::on_trade(
+			source,
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			liquidity_in,
+			liquidity_out,
+			price,
+			external_routed_liquidity,
+		)
+		.map(|weight| weight.saturating_add(note_weight))
+		.map_err(|(weight, err)| (weight.saturating_add(note_weight), err))
+	}
+
+	fn on_trade_weight() -> frame_support::weights::Weight {
+		EmaOracleIngestion::on_trade_weight().saturating_add(crate::TradeStats::note_swap_weight())
+	}
+}
+
+impl OnLiquidityChangedHandler<AssetId, Balance, EmaPrice> for XykAmmHandler {
+	fn on_liquidity_changed(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: EmaPrice,
+		shares_issuance: Option<Balance>,
+	) -> Result<frame_support::weights::Weight, (frame_support::weights::Weight, DispatchError)> {
+		EmaOracleIngestion::on_liquidity_changed(
+			source,
+			asset_a,
+			asset_b,
+			amount_a,
+			amount_b,
+			liquidity_a,
+			liquidity_b,
+			price,
+			shares_issuance,
+		)
+	}
+
+	fn on_liquidity_changed_weight() -> frame_support::weights::Weight {
+		EmaOracleIngestion::on_liquidity_changed_weight()
+	}
+}
+
+/// LBP trade execution used by the router. Trades executed through the router are ingested into
+/// the EMA oracle under the `LBPOracleSourceIdentifier` source and recorded in the trade stats.
+///
+/// NOTE: `pallet_lbp` has no trade hook, so the oracle is fed at the router only. Direct `sell` and
+/// `buy` calls of `pallet_lbp` are allowed but are not ingested into the oracle.
+pub struct LbpOracleFeed;
+
+impl LbpOracleFeed {
+	fn pool_balances(asset_in: AssetId, asset_out: AssetId) -> (AccountId, Balance, Balance) {
+		let pool_id = crate::LBP::get_pair_id(pallet_lbp::AssetPair { asset_in, asset_out });
+
+		(
+			pool_id.clone(),
+			crate::Currencies::free_balance(asset_in, &pool_id),
+			crate::Currencies::free_balance(asset_out, &pool_id),
+		)
+	}
+
+	/// Feed the oracle with the trade which changed the pool's balances from the given ones.
+	fn on_trade(
+		asset_in: AssetId,
+		asset_out: AssetId,
+		(pool_id, liquidity_in_before, liquidity_out_before): (AccountId, Balance, Balance),
+	) {
+		let liquidity_in = crate::Currencies::free_balance(asset_in, &pool_id);
+		let liquidity_out = crate::Currencies::free_balance(asset_out, &pool_id);
+
+		let amount_in = liquidity_in.saturating_sub(liquidity_in_before);
+		let amount_out = liquidity_out_before.saturating_sub(liquidity_out);
+
+		if amount_in.is_zero() || amount_out.is_zero() {
+			return;
+		}
+
+		//NOTE: spot price of the LBP pool depends on the pool's weights so price of the executed
+		//trade is used instead of the ratio of the pool's liquidity.
+		//Oracle errors must not fail the trade.
+		let _ = EmaOracleIngestion::on_trade(
+			crate::LBPOracleSourceIdentifier::get(),
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			liquidity_in,
+			liquidity_out,
+			EmaPrice::new(amount_in, amount_out),
+			None,
+		);
+
+		//NOTE: fee is paid in the accumulated asset of the pool and transferred to the fee collector.
+		let fee = pallet_lbp::PoolData::<crate::Runtime>::get(&pool_id)
+			.and_then(|pool| {
+				let fee_amount = if pool.assets.0 == asset_in {
+					amount_in
+				} else {
+					amount_out
+				};
+				Some((
+					pool.assets.0,
+					hydra_dx_math::fee::calculate_pool_trade_fee(fee_amount, pool.fee)?,
+				))
+			})
+			.unwrap_or((asset_in, Balance::zero()));
+		note_swap(asset_in, asset_out, amount_in, amount_out, fee);
+	}
+
+	pub fn on_trade_weight() -> frame_support::weights::Weight {
+		EmaOracleIngestion::on_trade_weight()
+			.saturating_add(crate::TradeStats::note_swap_weight())
+			.saturating_add(<crate::Runtime as frame_system::Config>::DbWeight::get().reads(1))
+	}
+}
+
+impl TradeExecution<crate::RuntimeOrigin, AccountId, AssetId, Balance> for LbpOracleFeed {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		<crate::LBP as TradeExecution<crate::RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+			pool_type, asset_in, asset_out, amount_in,
+		)
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		<crate::LBP as TradeExecution<crate::RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_buy(
+			pool_type, asset_in, asset_out, amount_out,
+		)
+	}
+
+	fn execute_sell(
+		who: crate::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::LBP {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let balances_before = Self::pool_balances(asset_in, asset_out);
+		<crate::LBP as TradeExecution<crate::RuntimeOrigin, AccountId, AssetId, Balance>>::execute_sell(
+			who, pool_type, asset_in, asset_out, amount_in, min_limit,
+		)?;
+		Self::on_trade(asset_in, asset_out, balances_before);
+
+		Ok(())
+	}
+
+	fn execute_buy(
+		who: crate::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::LBP {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let balances_before = Self::pool_balances(asset_in, asset_out);
+		<crate::LBP as TradeExecution<crate::RuntimeOrigin, AccountId, AssetId, Balance>>::execute_buy(
+			who, pool_type, asset_in, asset_out, amount_out, max_limit,
+		)?;
+		Self::on_trade(asset_in, asset_out, balances_before);
+
+		Ok(())
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<AssetId>,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		<crate::LBP as TradeExecution<crate::RuntimeOrigin, AccountId, AssetId, Balance>>::get_liquidity_depth(
+			pool_type, asset_a, asset_b,
+		)
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<AssetId>,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		<crate::LBP as TradeExecution<crate::RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+			pool_type, asset_a, asset_b,
+		)
+	}
+}
+
+/// Stableswap hooks which ingest the trades and liquidity changes of the stableswap pools into the
+/// EMA oracle under the `StableswapOracleSourceIdentifier` source. Price of every asset in the pool
+/// is tracked against the pool's share asset.
+pub struct StableswapOracleFeed;
+
+impl StableswapOracleFeed {
+	fn feed_oracle(pool_id: AssetId, state: &PoolState<AssetId>) -> DispatchResult {
+		for (idx, asset) in state.assets.iter().enumerate() {
+			let (Some(share_price), Some(liquidity)) = (state.share_prices.get(idx), state.after.get(idx)) else {
+				continue;
+			};
+
+			//NOTE: a trade changes the price of every asset in the pool, not only of the traded ones,
+			//so all entries are updated as liquidity changes which don't track any volume.
+			//Oracle errors must not fail the trade or the liquidity change.
+			let _ = EmaOracleIngestion::on_liquidity_changed(
+				crate::StableswapOracleSourceIdentifier::get(),
+				*asset,
+				pool_id,
+				Balance::zero(),
+				Balance::zero(),
+				*liquidity,
+				state.issuance_after,
+				EmaPrice::new(share_price.0, share_price.1),
+				Some(state.issuance_after),
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Record the trade in the trade stats. Fee is charged from the amount out and stays in the
+	/// pool, so it's estimated from the amount out and the fee of the pool.
+	fn note_swap(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, state: &PoolState<AssetId>) {
+		let idx = |asset| state.assets.iter().position(|a| *a == asset);
+		let (Some(idx_in), Some(idx_out)) = (idx(asset_in), idx(asset_out)) else {
+			return;
+		};
+		let amount = |before: Option<&Balance>, after: Option<&Balance>| {
+			before.zip(after).map(|(before, after)| before.abs_diff(*after))
+		};
+		let (Some(amount_in), Some(amount_out)) = (
+			amount(state.before.get(idx_in), state.after.get(idx_in)),
+			amount(state.before.get(idx_out), state.after.get(idx_out)),
+		) else {
+			return;
+		};
+
+		let fee = pallet_stableswap::Pools::<crate::Runtime>::get(pool_id)
+			.map(|pool| pool.fee.mul_floor(amount_out))
+			.unwrap_or_default();
+		note_swap(asset_in, asset_out, amount_in, amount_out, (asset_out, fee));
+	}
+}
+
+impl StableswapHooks<AssetId> for StableswapOracleFeed {
+	fn on_liquidity_changed(pool_id: AssetId, state: PoolState<AssetId>) -> DispatchResult {
+		Self::feed_oracle(pool_id, &state)
+	}
+
+	fn on_trade(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, state: PoolState<AssetId>) -> DispatchResult {
+		Self::note_swap(pool_id, asset_in, asset_out, &state);
+		Self::feed_oracle(pool_id, &state)
+	}
+
+	fn on_liquidity_changed_weight(n: usize) -> frame_support::weights::Weight {
+		EmaOracleIngestion::on_liquidity_changed_weight().saturating_mul(n as u64)
+	}
+
+	fn on_trade_weight(n: usize) -> frame_support::weights::Weight {
+		Self::on_liquidity_changed_weight(n)
+			.saturating_add(crate::TradeStats::note_swap_weight())
+			.saturating_add(<crate::Runtime as frame_system::Config>::DbWeight::get().reads(1))
+	}
+}
+
+/// Ingestion of the trades and liquidity changes into the EMA oracle. Entries dropped by the oracle
+/// whitelist are counted by `OracleWhitelist` before the entry is handed over to the oracle.
+pub struct EmaOracleIngestion;
+
+impl OnTradeHandler<AssetId, Balance, EmaPrice> for EmaOracleIngestion {
+	fn on_trade(
+		source: Source,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+		liquidity_in: Balance,
+		liquidity_out: Balance,
+		price: EmaPrice,
+		external_routed_liquidity: Option<Balance>,
+	) -> Result<frame_support::weights::Weight, (frame_support::weights::Weight, DispatchError)> {
+		crate::OracleWhitelist::note_entry(source, asset_in, asset_out);
+
+		pallet_ema_oracle::OnActivityHandler::<crate::Runtime>::on_trade(
+			source,
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			liquidity_in,
+			liquidity_out,
+			price,
+			external_routed_liquidity,
+		)
+		.map(|weight| weight.saturating_add(crate::OracleWhitelist::note_entry_weight()))
+		.map_err(|(weight, err)| (weight.saturating_add(crate::OracleWhitelist::note_entry_weight()), err))
+	}
+
+	fn on_trade_weight() -> frame_support::weights::Weight {
+		pallet_ema_oracle::OnActivityHandler::<crate::Runtime>::on_trade_weight()
+			.saturating_add(crate::OracleWhitelist::contains_weight())
+			.saturating_add(crate::OracleWhitelist::note_entry_weight())
+	}
+}
+
+impl OnLiquidityChangedHandler<AssetId, Balance, EmaPrice> for EmaOracleIngestion {
+	fn on_liquidity_changed(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: EmaPrice,
+		shares_issuance: Option<Balance>,
+	) -> Result<frame_support::weights::Weight, (frame_support::weights::Weight, DispatchError)> {
+		crate::OracleWhitelist::note_entry(source, asset_a, asset_b);
+
+		pallet_ema_oracle::OnActivityHandler::<crate::Runtime>::on_liquidity_changed(
+			source,
+			asset_a,
+			asset_b,
+			amount_a,
+			amount_b,
+			liquidity_a,
+			liquidity_b,
+			price,
+			shares_issuance,
+		)
+		.map(|weight| weight.saturating_add(crate::OracleWhitelist::note_entry_weight()))
+		.map_err(|(weight, err)| (weight.saturating_add(crate::OracleWhitelist::note_entry_weight()), err))
+	}
+
+	fn on_liquidity_changed_weight() -> frame_support::weights::Weight {
+		pallet_ema_oracle::OnActivityHandler::<crate::Runtime>::on_liquidity_changed_weight()
+			.saturating_add(crate::OracleWhitelist::contains_weight())
+			.saturating_add(crate::OracleWhitelist::note_entry_weight())
+	}
+}
+
+/// Entries accumulated by the EMA oracle in the current block.
+pub struct EmaOracleAccumulator;
+
+impl pallet_oracle_whitelist::OracleAccumulator for EmaOracleAccumulator {
+	fn entries_count() -> u32 {
+		pallet_ema_oracle::Accumulator::<crate::Runtime>::decode_len().unwrap_or_default() as u32
+	}
+
+	fn contains(source: Source, asset_a: AssetId, asset_b: AssetId) -> bool {
+		let pair = if asset_a < asset_b {
+			(asset_a, asset_b)
+		} else {
+			(asset_b, asset_a)
+		};
+		pallet_ema_oracle::Accumulator::<crate::Runtime>::get().contains_key(&(source, pair))
+	}
+}
+
+/// Oracle pairs used to convert the transaction fees paid in the accepted currencies to the native
+/// asset. These pairs are tracked with priority without being whitelisted.
+pub struct FeePaymentOraclePairs;
+
+impl frame_support::traits::Contains<(Source, AssetId, AssetId)> for FeePaymentOraclePairs {
+	fn contains((source, asset_a, asset_b): &(Source, AssetId, AssetId)) -> bool {
+		if *source != crate::XYKOracleSourceIdentifier::get() {
+			return false;
+		}
+
+		let core_asset = crate::NativeAssetId::get();
+		let fee_asset = if *asset_a == core_asset {
+			asset_b
+		} else if *asset_b == core_asset {
+			asset_a
+		} else {
+			return false;
+		};
+
+		pallet_transaction_multi_payment::pallet::AcceptedCurrencies::<crate::Runtime>::contains_key(fee_asset)
+	}
+}
+
+/// Max. number of the accepted currencies whose stored fee payment routes are derived by
+/// `FeePaymentRouteOraclePairs`.
+pub const MAX_FEE_PAYMENT_ROUTES: u32 = 10;
+
+/// Oracle pairs of the hops of the routes stored in the router, which are used to swap the
+/// accepted currencies without a direct XYK pool to the native asset when the fee is paid.
+/// These pairs are tracked with priority without being whitelisted.
+///
+/// NOTE: pairs are stored by `OracleWhitelist::refresh_priority_pairs`, which has to be called when
+/// an accepted currency is added or removed or when a stored route of an accepted currency changes.
+pub struct FeePaymentRouteOraclePairs;
+
+impl pallet_oracle_whitelist::DerivedPriorityPairs for FeePaymentRouteOraclePairs {
+	fn derive() -> Option<Vec<(Source, AssetId, AssetId)>> {
+		let core_asset = crate::NativeAssetId::get();
+
+		let currencies: Vec<AssetId> =
+			pallet_transaction_multi_payment::pallet::AcceptedCurrencies::<crate::Runtime>::iter_keys()
+				.take(MAX_FEE_PAYMENT_ROUTES.saturating_add(1) as usize)
+				.collect();
+		// routes of all accepted currencies have to be derived, not an arbitrary subset of them
+		if currencies.len() > MAX_FEE_PAYMENT_ROUTES as usize {
+			return None;
+		}
+
+		Some(
+			currencies
+				.into_iter()
+				.filter_map(|currency| crate::assets::XykPaymentAssetSupport::stored_route(currency, core_asset))
+				.flat_map(|route| OraclePriceProvider::<AssetId, crate::EmaOracle>::oracle_entries(&route))
+				.collect(),
+		)
+	}
+
+	fn derive_weight() -> frame_support::weights::Weight {
+		// every accepted currency, its direct XYK pool and its stored route are read
+		<crate::Runtime as frame_system::Config>::DbWeight::get()
+			.reads(MAX_FEE_PAYMENT_ROUTES.saturating_mul(3).saturating_add(1).into())
+	}
+}
+
+/// Oracle pairs used to price the liquidity mining farms in the `Oracle` price adjustment mode.
+/// These pairs are tracked with priority without being whitelisted.
+pub struct FarmPricingOraclePairs;
+
+impl frame_support::traits::Contains<(Source, AssetId, AssetId)> for FarmPricingOraclePairs {
+	fn contains((source, asset_a, asset_b): &(Source, AssetId, AssetId)) -> bool {
+		crate::XYKLiquidityMining::is_oracle_pricing_entry(*source, *asset_a, *asset_b)
+	}
+}
+
+/// Fee currency of the accounts set in the multi payment pallet. Native asset is not stored.
+pub struct MultiPaymentAccountCurrency;
+
+impl pallet_fee_currency_selection::AccountFeeCurrency<AccountId> for MultiPaymentAccountCurrency {
+	fn get(who: &AccountId) -> AssetId {
+		crate::MultiTransactionPayment::get_currency(who).unwrap_or_else(crate::NativeAssetId::get)
+	}
+
+	fn set(who: &AccountId, currency: AssetId) {
+		if currency == crate::NativeAssetId::get() {
+			pallet_transaction_multi_payment::pallet::AccountCurrencyMap::<crate::Runtime>::remove(who);
+		} else {
+			pallet_transaction_multi_payment::pallet::AccountCurrencyMap::<crate::Runtime>::insert(who, currency);
+		}
+	}
+}
@@ -25,7 +25,7 @@ use frame_support::{
 	},
 	weights::WeightToFee as _,
 };
-use hydradx_traits::NativePriceOracle;
+use hydradx_traits::{router::Trade, NativePriceOracle, OraclePeriod, Source};
 use polkadot_xcm::{IntoVersion, VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
 use primitives::constants::chain::CORE_ASSET_ID;
 use sp_api::impl_runtime_apis;
@@ -177,6 +177,76 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_xyk_liquidity_mining_runtime_api::XykLiquidityMiningApi<Block, AccountId> for Runtime {
+		fn deposit_valuation(
+			deposit_id: primitives::CollectionId,
+		) -> Option<pallet_xyk_liquidity_mining::DepositValuation<AccountId>> {
+			XYKLiquidityMining::deposit_valuation(deposit_id)
+		}
+	}
+
+	impl basilisk_ema_oracle_runtime_api::EmaOracleApi<Block, AssetId, Balance, BlockNumber> for Runtime {
+		fn get_price(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+			source: Source,
+		) -> Option<basilisk_ema_oracle_runtime_api::OraclePrice<Balance, BlockNumber>> {
+			oracle_api::get_price(asset_a, asset_b, period, source)
+		}
+
+		fn get_route_price(
+			route: Vec<Trade<AssetId>>,
+			period: OraclePeriod,
+		) -> Option<basilisk_ema_oracle_runtime_api::Price<Balance>> {
+			oracle_api::get_route_price(route, period)
+		}
+
+		fn oracle_entries() -> Vec<basilisk_ema_oracle_runtime_api::OracleEntry<AssetId, Balance, BlockNumber>> {
+			oracle_api::oracle_entries()
+		}
+	}
+
+	impl pallet_trade_stats_runtime_api::TradeStatsApi<Block, BlockNumber> for Runtime {
+		fn pair_stats(asset_a: AssetId, asset_b: AssetId, window: BlockNumber) -> pallet_trade_stats::TradeStats {
+			TradeStats::pair_stats(asset_a, asset_b, window)
+		}
+	}
+
+	impl basilisk_router_runtime_api::RouterApi<Block, AssetId, Balance> for Runtime {
+		fn quote_sell(
+			route: Vec<Trade<AssetId>>,
+			amount_in: Balance,
+		) -> Option<basilisk_router_runtime_api::RouteQuote<AssetId, Balance>> {
+			router_api::quote_sell(route, amount_in)
+		}
+
+		fn quote_buy(
+			route: Vec<Trade<AssetId>>,
+			amount_out: Balance,
+		) -> Option<basilisk_router_runtime_api::RouteQuote<AssetId, Balance>> {
+			router_api::quote_buy(route, amount_out)
+		}
+
+		fn best_sell_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			max_hops: u32,
+		) -> Option<basilisk_router_runtime_api::RouteQuote<AssetId, Balance>> {
+			router_api::best_sell_route(asset_in, asset_out, amount_in, max_hops)
+		}
+
+		fn best_buy_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			max_hops: u32,
+		) -> Option<basilisk_router_runtime_api::RouteQuote<AssetId, Balance>> {
+			router_api::best_buy_route(asset_in, asset_out, amount_out, max_hops)
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -306,6 +376,12 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_list_benchmark!(list, extra, pallet_route_executor, benchmarking::route_executor);
 			orml_list_benchmark!(list, extra, pallet_marketplace, benchmarking::marketplace);
+			orml_list_benchmark!(list, extra, pallet_dca, benchmarking::dca);
+			orml_list_benchmark!(list, extra, pallet_limit_orders, benchmarking::limit_orders);
+			orml_list_benchmark!(list, extra, pallet_otc, benchmarking::otc);
+			orml_list_benchmark!(list, extra, pallet_trade_stats, benchmarking::trade_stats);
+			orml_list_benchmark!(list, extra, pallet_oracle_whitelist, benchmarking::oracle_whitelist);
+			orml_list_benchmark!(list, extra, pallet_fee_currency_selection, benchmarking::fee_currency_selection);
 			let storage_info = AllPalletsWithSystem::storage_info();
 
 			(list, storage_info)
@@ -444,6 +520,12 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_add_benchmark!(params, batches, pallet_route_executor, benchmarking::route_executor);
 			orml_add_benchmark!(params, batches, pallet_marketplace, benchmarking::marketplace);
+			orml_add_benchmark!(params, batches, pallet_dca, benchmarking::dca);
+			orml_add_benchmark!(params, batches, pallet_limit_orders, benchmarking::limit_orders);
+			orml_add_benchmark!(params, batches, pallet_otc, benchmarking::otc);
+			orml_add_benchmark!(params, batches, pallet_trade_stats, benchmarking::trade_stats);
+			orml_add_benchmark!(params, batches, pallet_oracle_whitelist, benchmarking::oracle_whitelist);
+			orml_add_benchmark!(params, batches, pallet_fee_currency_selection, benchmarking::fee_currency_selection);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
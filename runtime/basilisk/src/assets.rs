@@ -19,26 +19,35 @@ use super::*;
 use crate::governance::origins::GeneralAdmin;
 use crate::system::NativeAssetId;
 
+use hydradx_adapters::RelayChainBlockHashProvider;
 use hydradx_traits::{
 	fee::{InspectTransactionFeeCurrency, SwappablePaymentAssetTrader},
-	router::{inverse_route, AmmTradeWeights, PoolType, Trade},
-	AssetKind, AssetPairAccountIdFor, LockedBalance, OnTradeHandler, OraclePeriod, Source, AMM,
+	router::{
+		inverse_route, AmmTradeWeights, AssetPair as RouterAssetPair, ExecutorError, PoolType, RouteProvider, Trade,
+		TradeExecution,
+	},
+	AccountIdFor, AssetKind, AssetPairAccountIdFor, LockedBalance, OnTradeHandler, OraclePeriod, PriceOracle, Source,
+	AMM,
 };
 use pallet_currencies::fungibles::FungibleCurrencies;
 use pallet_currencies::BasicCurrencyAdapter;
 use pallet_lbp::weights::WeightInfo as LbpWeights;
 use pallet_route_executor::weights::WeightInfo as RouterWeights;
+use pallet_stableswap::{traits::StableswapHooks, weights::WeightInfo as StableswapWeights};
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKilled};
 use pallet_xyk::weights::WeightInfo as XykWeights;
 use primitives::constants::{
 	chain::{CORE_ASSET_ID, DISCOUNTED_FEE, MAX_IN_RATIO, MAX_OUT_RATIO, MIN_POOL_LIQUIDITY, MIN_TRADING_LIMIT},
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
+	time::{DAYS, HOURS},
 };
 
 use frame_support::{
-	parameter_types,
+	ensure, parameter_types,
 	sp_runtime::{
-		app_crypto::sp_core::crypto::UncheckedFrom, traits::Zero, ArithmeticError, DispatchError, DispatchResult,
+		app_crypto::sp_core::crypto::UncheckedFrom,
+		traits::{One, Zero},
+		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedU128, Percent, Permill,
 	},
 	traits::{
 		AsEnsureOriginWithArg, Contains, Currency, Defensive, EitherOf, EnsureOrigin, Get, Imbalance, LockIdentifier,
@@ -49,6 +58,7 @@ use frame_support::{
 use frame_system::{EnsureRoot, RawOrigin};
 use orml_tokens::CurrencyAdapter;
 use orml_traits::{currency::MutationHooks, MultiCurrency};
+use sp_std::{num::NonZeroU16, ops::RangeInclusive};
 
 pub struct RelayChainAssetId;
 impl Get<AssetId> for RelayChainAssetId {
@@ -92,7 +102,7 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = weights::pallet_balances::BasiliskWeight<Runtime>;
 	type MaxLocks = MaxLocks;
 	type MaxReserves = MaxReserves;
-	type ReserveIdentifier = ();
+	type ReserveIdentifier = NamedReserveIdentifier;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -128,7 +138,7 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyHooks = CurrencyHooks;
 	type MaxLocks = MaxLocks;
 	type MaxReserves = MaxReserves;
-	type ReserveIdentifier = ();
+	type ReserveIdentifier = NamedReserveIdentifier;
 	type DustRemovalWhitelist = DustRemovalWhitelist;
 }
 
@@ -299,6 +309,8 @@ parameter_types! {
 	pub const RegistryStrLimit: u32 = 32;
 	pub const DiscountedFee: (u32, u32) = DISCOUNTED_FEE;
 	pub const XYKOracleSourceIdentifier: Source = *b"snek/xyk";
+	pub const LBPOracleSourceIdentifier: Source = *b"snek/lbp";
+	pub const StableswapOracleSourceIdentifier: Source = *b"snek/sts";
 }
 
 impl pallet_xyk::Config for Runtime {
@@ -315,11 +327,22 @@ impl pallet_xyk::Config for Runtime {
 	type MaxOutRatio = MaxOutRatio;
 	type OracleSource = XYKOracleSourceIdentifier;
 	type CanCreatePool = hydradx_adapters::xyk::AllowPoolCreation<Runtime, AssetRegistry>;
-	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type AMMHandler = adapter::XykAmmHandler;
 	type DiscountedFee = DiscountedFee;
 	type NonDustableWhitelistHandler = Duster;
 }
 
+parameter_types! {
+	pub const TradeStatsBucketLength: BlockNumber = HOURS;
+	pub const MaxTradeStatsBuckets: u32 = 24;
+}
+
+impl pallet_trade_stats::Config for Runtime {
+	type BucketLength = TradeStatsBucketLength;
+	type MaxBuckets = MaxTradeStatsBuckets;
+	type WeightInfo = weights::pallet_trade_stats::BasiliskWeight<Runtime>;
+}
+
 pub struct MultiCurrencyLockedBalance<T>(PhantomData<T>);
 
 impl<T: orml_tokens::Config + pallet_balances::Config + frame_system::Config>
@@ -369,9 +392,54 @@ impl pallet_lbp::Config for Runtime {
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
 }
 
+pub struct StableswapAccountIdConstructor<T: frame_system::Config>(PhantomData<T>);
+impl<T: frame_system::Config> AccountIdFor<AssetId> for StableswapAccountIdConstructor<T>
+where
+	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+	type AccountId = T::AccountId;
+
+	fn from_assets(asset: &AssetId, identifier: Option<&[u8]>) -> Self::AccountId {
+		let name = Self::name(asset, identifier);
+		T::AccountId::unchecked_from(<T::Hashing as frame_support::sp_runtime::traits::Hash>::hash(&name[..]))
+	}
+
+	fn name(asset: &AssetId, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf = identifier.map_or_else(Vec::new, |v| v.to_vec());
+		buf.extend_from_slice(&asset.to_le_bytes());
+		buf
+	}
+}
+
+parameter_types! {
+	pub StableswapAmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+}
+
+/// Share token of a stableswap pool is an asset of `AssetKind::StableSwap` kind and its id is the id
+/// of the pool. The share token has to be registered in `AssetInspection` before the pool is created,
+/// `Stableswap::create_pool` fails with `ShareAssetNotRegistered` otherwise, so a pool can't exist
+/// without its share token.
+impl pallet_stableswap::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BlockNumberProvider = System;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type ShareAccountId = StableswapAccountIdConstructor<Runtime>;
+	type AssetInspection = AssetRegistry;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type UpdateTradabilityOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type AmplificationRange = StableswapAmplificationRange;
+	type MinTradingLimit = MinTradingLimit;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type DustAccountHandler = Duster;
+	type Hooks = adapter::StableswapOracleFeed;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = benchmarking::BenchmarkHelper;
+	type WeightInfo = weights::pallet_stableswap::BasiliskWeight<Runtime>;
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 use codec::Decode;
-use frame_support::traits::Everything;
 use hydradx_traits::evm::EvmAddress;
 
 pub struct RootAsVestingPallet;
@@ -469,18 +537,159 @@ impl pallet_uniques::Config for Runtime {
 parameter_types! {
 	pub const LMPalletId: PalletId = PalletId(*b"LiqMinId");
 	pub const LiquidityMiningNftCollectionId: primitives::CollectionId = 1;
+	pub MaxLiquidityMiningFarmEntries: u32 = MaxEntriesPerDeposit::get() as u32;
+	pub const MaxDepositsPerClaim: u32 = 20;
+	pub const MaxCompoundingSlippage: Permill = Permill::from_percent(3);
+	pub const CompoundingPeriod: BlockNumber = DAYS;
+	pub const MaxScheduledYieldFarms: u32 = 10;
+	pub const MaxPendingDeposits: u32 = 20;
+	pub const CompoundingOraclePeriod: OraclePeriod = OraclePeriod::Short;
+	pub const PriceAdjustmentOraclePeriod: OraclePeriod = OraclePeriod::Day;
+	pub const FarmCreationBond: Balance = 100_000 * UNITS;
+	pub MaxLiquidityMiningYieldFarms: u32 = MaxYieldFarmsPerGlobalFarm::get() as u32;
+}
+
+/// Liq. mining deposits listed for sale on the marketplace.
+pub struct ListedLiquidityMiningDeposits;
+
+impl Contains<primitives::CollectionId> for ListedLiquidityMiningDeposits {
+	fn contains(deposit_id: &primitives::CollectionId) -> bool {
+		Marketplace::prices(LiquidityMiningNftCollectionId::get(), deposit_id).is_some()
+	}
+}
+
+/// Swaps of claimed liq. mining rewards executed by the router and priced by the EMA oracle.
+pub struct LiquidityMiningRewardsSwap;
+
+impl pallet_xyk_liquidity_mining::AssetSwap<AccountId, AssetId, Balance> for LiquidityMiningRewardsSwap {
+	fn sell(
+		who: &AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	) -> Result<Balance, DispatchError> {
+		let route = Router::get_route(RouterAssetPair::new(asset_in, asset_out));
+		let balance_before = Currencies::free_balance(asset_out, who);
+
+		Router::sell(
+			RuntimeOrigin::signed(who.clone()),
+			asset_in,
+			asset_out,
+			amount_in,
+			min_amount_out,
+			route,
+		)?;
+
+		Ok(Currencies::free_balance(asset_out, who).saturating_sub(balance_before))
+	}
+
+	fn oracle_price(asset_in: AssetId, asset_out: AssetId) -> Option<(Balance, Balance)> {
+		let route = Router::get_route(RouterAssetPair::new(asset_in, asset_out));
+		let price = <Runtime as pallet_route_executor::Config>::OraclePriceProvider::price(
+			&route,
+			CompoundingOraclePeriod::get(),
+		)?;
+
+		Some((price.n, price.d))
+	}
+
+	fn oracle_pairs(asset_in: AssetId, asset_out: AssetId) -> Vec<(Source, AssetId, AssetId)> {
+		let route = Router::get_route(RouterAssetPair::new(asset_in, asset_out));
+
+		adapter::OraclePriceProvider::<AssetId, EmaOracle>::oracle_entries(&route)
+	}
+
+	fn sell_weight() -> Weight {
+		// worst case route with max number of trades in the most expensive pool
+		let route = vec![
+			Trade {
+				pool: PoolType::LBP,
+				asset_in: CORE_ASSET_ID,
+				asset_out: CORE_ASSET_ID,
+			};
+			pallet_route_executor::MAX_NUMBER_OF_TRADES as usize
+		];
+
+		// route is queried for the swap and for the oracle price
+		RouterWeightInfo::sell_weight(&route).saturating_add(Self::oracle_price_weight().saturating_mul(2))
+	}
+
+	fn oracle_price_weight() -> Weight {
+		weights::pallet_route_executor::BasiliskWeight::<Runtime>::get_route().saturating_add(
+			weights::pallet_route_executor::BasiliskWeight::<Runtime>::get_oracle_price_for_xyk()
+				.saturating_mul(pallet_route_executor::MAX_NUMBER_OF_TRADES.into()),
+		)
+	}
+}
+
+/// Price adjustment of the global farms. Farms in the `Oracle` mode use EMA oracle price of the
+/// incentivized asset in the reward currency, other farms use price adjustment set by the owner.
+pub struct LiquidityMiningPriceAdjustment;
+
+impl
+	hydradx_traits::liquidity_mining::PriceAdjustment<
+		warehouse_liquidity_mining::GlobalFarmData<Runtime, XYKLiquidityMiningInstance>,
+	> for LiquidityMiningPriceAdjustment
+{
+	type Error = DispatchError;
+	type PriceAdjustment = sp_runtime::FixedU128;
+
+	fn get(
+		global_farm: &warehouse_liquidity_mining::GlobalFarmData<Runtime, XYKLiquidityMiningInstance>,
+	) -> Result<Self::PriceAdjustment, Self::Error> {
+		if pallet_xyk_liquidity_mining::PriceAdjustmentModes::<Runtime>::get(global_farm.id)
+			!= pallet_xyk_liquidity_mining::PriceAdjustmentMode::Oracle
+		{
+			return Ok(global_farm.price_adjustment);
+		}
+
+		if global_farm.incentivized_asset == global_farm.reward_currency {
+			return Ok(sp_runtime::FixedU128::one());
+		}
+
+		let route = Router::get_route(RouterAssetPair::new(
+			global_farm.incentivized_asset,
+			global_farm.reward_currency,
+		));
+
+		// Last price set by the owner is used if oracle price is not available so farms are not
+		// blocked by missing oracle entries.
+		Ok(<Runtime as pallet_route_executor::Config>::OraclePriceProvider::price(
+			&route,
+			PriceAdjustmentOraclePeriod::get(),
+		)
+		.and_then(|price| sp_runtime::FixedU128::checked_from_rational(price.n, price.d))
+		.unwrap_or(global_farm.price_adjustment))
+	}
 }
 
 impl pallet_xyk_liquidity_mining::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Currencies;
-	type AMM = XYK;
+	type AMM = pallet_xyk_liquidity_mining::XykPools<XYK>;
 	type CreateOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type ForceTerminateOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type BondAssetId = NativeAssetId;
+	type FarmCreationBond = FarmCreationBond;
+	type MaxYieldFarmsPerGlobalFarm = MaxLiquidityMiningYieldFarms;
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
 	type PalletId = LMPalletId;
 	type NftCollectionId = LiquidityMiningNftCollectionId;
 	type NFTHandler = NFT;
 	type LiquidityMiningHandler = XYKWarehouseLM;
 	type NonDustableWhitelistHandler = Duster;
+	type AMMLiquidityHandler = pallet_xyk_liquidity_mining::XykLiquidity<Runtime>;
+	type MaxFarmEntriesPerDeposit = MaxLiquidityMiningFarmEntries;
+	type MaxDepositsPerClaim = MaxDepositsPerClaim;
+	type Swap = LiquidityMiningRewardsSwap;
+	type DepositSharesHandler = XYKWarehouseLM;
+	type DepositSplitMergeHandler = XYKWarehouseLM;
+	type ListedDeposits = ListedLiquidityMiningDeposits;
+	type MaxCompoundingSlippage = MaxCompoundingSlippage;
+	type CompoundingPeriod = CompoundingPeriod;
+	type MaxScheduledYieldFarms = MaxScheduledYieldFarms;
+	type MaxPendingDeposits = MaxPendingDeposits;
 	type WeightInfo = weights::pallet_xyk_liquidity_mining::BasiliskWeight<Runtime>;
 }
 
@@ -506,7 +715,7 @@ impl warehouse_liquidity_mining::Config<XYKLiquidityMiningInstance> for Runtime
 	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
 	type AssetRegistry = AssetRegistry;
 	type NonDustableWhitelistHandler = Duster;
-	type PriceAdjustment = warehouse_liquidity_mining::DefaultPriceAdjustment;
+	type PriceAdjustment = LiquidityMiningPriceAdjustment;
 	type TreasuryAccountId = TreasuryAccount;
 }
 
@@ -573,6 +782,11 @@ impl RouterWeightInfo {
 				.proof_size(),
 		)
 	}
+
+	// Stableswap trade feeds the oracle with the price of every asset in the pool.
+	pub fn stableswap_hooks_weight() -> Weight {
+		<Runtime as pallet_stableswap::Config>::Hooks::on_trade_weight(pallet_stableswap::MAX_ASSETS_IN_POOL as usize)
+	}
 }
 
 impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
@@ -585,14 +799,18 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 		for trade in route {
 			weight.saturating_accrue(Self::sell_and_calculate_sell_trade_amounts_overhead_weight(0, 1));
 
-			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_sell(c, e);
+			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
+				.saturating_add(adapter::LbpOracleFeed::on_trade_weight());
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
+				.saturating_add(Self::stableswap_hooks_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -609,14 +827,18 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 		for trade in route {
 			weight.saturating_accrue(Self::buy_and_calculate_buy_trade_amounts_overhead_weight(0, 1));
 
-			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_buy(c, e);
+			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
+				.saturating_add(adapter::LbpOracleFeed::on_trade_weight());
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
+				.saturating_add(Self::stableswap_hooks_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -633,14 +855,18 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 		for trade in route {
 			weight.saturating_accrue(Self::buy_and_calculate_buy_trade_amounts_overhead_weight(1, 0));
 
-			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_buy(c, e);
+			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
+				.saturating_add(adapter::LbpOracleFeed::on_trade_weight());
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
+				.saturating_add(Self::stableswap_hooks_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -657,14 +883,18 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 		for trade in route {
 			weight.saturating_accrue(Self::sell_and_calculate_sell_trade_amounts_overhead_weight(1, 1));
 
-			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_sell(c, e);
+			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
+				.saturating_add(adapter::LbpOracleFeed::on_trade_weight());
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
+				.saturating_add(Self::stableswap_hooks_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -681,14 +911,18 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 		for trade in route {
 			weight.saturating_accrue(Self::buy_and_calculate_buy_trade_amounts_overhead_weight(2, 1));
 
-			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_buy(c, e);
+			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
+				.saturating_add(adapter::LbpOracleFeed::on_trade_weight());
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
+				.saturating_add(Self::stableswap_hooks_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -714,13 +948,15 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 
 		let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_sell(1, 0);
 		let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_sell(1, 0);
+		let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_sell(1, 0);
 
 		//Calculate sell amounts for the new route
 		for trade in route {
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -730,7 +966,8 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -757,12 +994,14 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 
 		let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::calculate_spot_price_with_fee();
 		let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::calculate_spot_price_with_fee();
+		let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::calculate_spot_price_with_fee();
 
 		for trade in route {
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -778,6 +1017,8 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 parameter_types! {
 	pub DefaultRoutePoolType: PoolType<AssetId> = PoolType::XYK;
 	pub const RouteValidationOraclePeriod: OraclePeriod = OraclePeriod::Hour;
+	// Max. number of trades of the routes searched by the `RouterApi`.
+	pub const MaxRouteSearchHops: u32 = 3;
 }
 
 pub struct RefundAndLockedEdCalculator;
@@ -797,7 +1038,7 @@ impl pallet_route_executor::Config for Runtime {
 	type NativeAssetId = NativeAssetId;
 	type Currency = FungibleCurrencies<Runtime>;
 	type InspectRegistry = AssetRegistry;
-	type AMM = (XYK, LBP);
+	type AMM = (XYK, adapter::LbpOracleFeed, Stableswap);
 	type EdToRefundCalculator = RefundAndLockedEdCalculator;
 	type OraclePriceProvider = adapter::OraclePriceProvider<AssetId, EmaOracle>;
 	type OraclePeriod = RouteValidationOraclePeriod;
@@ -806,13 +1047,170 @@ impl pallet_route_executor::Config for Runtime {
 	type WeightInfo = RouterWeightInfo;
 }
 
+parameter_types! {
+	pub MinBudgetInNativeCurrency: Balance = 100 * UNITS;
+	pub MaxSchedulesPerBlock: u32 = 20;
+	pub MaxPriceDifference: Permill = Permill::from_rational(15u32, 1000u32);
+	pub MaxConfigurablePriceDifference: Permill = Permill::from_percent(5);
+	pub MinimalPeriod: u32 = 5;
+	pub BumpChance: Percent = Percent::from_percent(17);
+	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
+	pub MaxNumberOfRetriesOnError: u8 = 3;
+}
+
+impl pallet_dca::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currencies = Currencies;
+	type RelayChainBlockHashProvider = RelayChainBlockHashProvider<Runtime>;
+	type RandomnessProvider = DCA;
+	type OraclePriceProvider = adapter::OraclePriceProvider<AssetId, EmaOracle>;
+	type RouteExecutor = Router;
+	type RouteProvider = Router;
+	type MaxPriceDifferenceBetweenBlocks = MaxPriceDifference;
+	type MaxConfigurablePriceDifferenceBetweenBlocks = MaxConfigurablePriceDifference;
+	type MaxSchedulePerBlock = MaxSchedulesPerBlock;
+	type MaxNumberOfRetriesOnError = MaxNumberOfRetriesOnError;
+	type NativeAssetId = NativeAssetId;
+	type MinBudgetInNativeCurrency = MinBudgetInNativeCurrency;
+	type MinimumTradingLimit = MinTradingLimit;
+	type FeeReceiver = TreasuryAccount;
+	type NativePriceOracle = MultiTransactionPayment;
+	type NamedReserveId = NamedReserveId;
+	type WeightToFee = WeightToFee;
+	type AmmTradeWeights = RouterWeightInfo;
+	type MinimalPeriod = MinimalPeriod;
+	type BumpChance = BumpChance;
+	type TerminateOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type PolkadotNativeAssetId = RelayChainAssetId;
+	type SwappablePaymentAssetSupport = XykPaymentAssetSupport;
+	type InspectRegistry = AssetRegistry;
+	type RetryOnError = ();
+	type WeightInfo = weights::pallet_dca::BasiliskWeight<Runtime>;
+}
+
+/// Limit orders are triggered by the spot price of the XYK pool and executed by the router.
+pub struct LimitOrderSettlement;
+
+impl LimitOrderSettlement {
+	/// Worst case route with max number of trades in the most expensive pool.
+	fn worst_case_route() -> Vec<Trade<AssetId>> {
+		vec![
+			Trade {
+				pool: PoolType::LBP,
+				asset_in: CORE_ASSET_ID,
+				asset_out: CORE_ASSET_ID,
+			};
+			pallet_route_executor::MAX_NUMBER_OF_TRADES as usize
+		]
+	}
+}
+
+impl pallet_limit_orders::OrderSettlement<AccountId, AssetId, Balance> for LimitOrderSettlement {
+	fn calculate_sell(asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Option<Balance> {
+		let route = Router::get_route(RouterAssetPair::new(asset_in, asset_out));
+
+		Router::calculate_sell_trade_amounts(&route, amount_in)
+			.ok()
+			.and_then(|amounts| amounts.last().map(|amounts| amounts.amount_out))
+	}
+
+	fn sell(
+		who: &AccountId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	) -> Result<Balance, DispatchError> {
+		let route = Router::get_route(RouterAssetPair::new(asset_in, asset_out));
+		let balance_before = Currencies::free_balance(asset_out, who);
+
+		Router::sell(
+			RuntimeOrigin::signed(who.clone()),
+			asset_in,
+			asset_out,
+			amount_in,
+			min_amount_out,
+			route,
+		)?;
+
+		Ok(Currencies::free_balance(asset_out, who).saturating_sub(balance_before))
+	}
+
+	fn calculate_sell_weight() -> Weight {
+		let route = Self::worst_case_route();
+
+		// the quote is the additional calculation of the trade amounts on top of the sell
+		RouterWeightInfo::sell_and_calculate_sell_trade_amounts_weight(&route)
+			.saturating_sub(RouterWeightInfo::sell_weight(&route))
+			.saturating_add(weights::pallet_route_executor::BasiliskWeight::<Runtime>::get_route())
+	}
+
+	fn sell_weight() -> Weight {
+		RouterWeightInfo::sell_weight(&Self::worst_case_route())
+			.saturating_add(weights::pallet_route_executor::BasiliskWeight::<Runtime>::get_route())
+	}
+}
+
+parameter_types! {
+	pub LimitOrderKeeperFee: Permill = Permill::from_rational(1u32, 1000u32);
+	pub LimitOrdersNamedReserveId: NamedReserveIdentifier = *b"limitord";
+}
+
+impl pallet_limit_orders::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type Settlement = LimitOrderSettlement;
+	type KeeperFee = LimitOrderKeeperFee;
+	type MinOrderAmount = MinTradingLimit;
+	type NamedReserveId = LimitOrdersNamedReserveId;
+	type WeightInfo = weights::pallet_limit_orders::BasiliskWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const OtcExistentialDepositMultiplier: u8 = 5;
+	pub OtcFee: Permill = Permill::from_rational(1u32, 1_000u32); // 0.1%
+}
+
+impl pallet_otc::Config for Runtime {
+	type AssetId = AssetId;
+	type AssetRegistry = AssetRegistry;
+	type Currency = Currencies;
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposits = AssetRegistry;
+	type ExistentialDepositMultiplier = OtcExistentialDepositMultiplier;
+	type Fee = OtcFee;
+	type FeeReceiver = TreasuryAccount;
+	// weights generated by the benchmarks of `pallet_otc`
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<{ pallet_ema_oracle::MAX_PERIODS }>> = BoundedVec::truncate_from(
 		vec![OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::Hour, OraclePeriod::Day, OraclePeriod::Week]
 	);
-	// There are currently only a few pools, so the number of entries per block is limited.
-	// NOTE: Needs to be updated once the number of pools grows.
+	// Only whitelisted pairs are tracked by the oracle, see `pallet_oracle_whitelist`.
 	pub MaxUniqueOracleEntries: u32 = 30;
+	// Entries reserved for the fee payment pairs, the hops of the stored fee payment routes, the pairs
+	// pricing the liquidity mining farms in the `Oracle` mode and the pairs whitelisted with high
+	// priority.
+	pub const ReservedPriorityOracleEntries: u32 = 10;
+	pub const MaxWhitelistedOraclePairs: u32 = 100;
+	// hops of the stored fee payment routes of the accepted currencies
+	pub const MaxDerivedPriorityOraclePairs: u32 = 50;
+}
+
+impl pallet_oracle_whitelist::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type UpdateOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type PriorityPairs = (adapter::FeePaymentOraclePairs, adapter::FarmPricingOraclePairs);
+	type DerivedPriorityPairs = adapter::FeePaymentRouteOraclePairs;
+	type Accumulator = adapter::EmaOracleAccumulator;
+	type MaxEntries = MaxUniqueOracleEntries;
+	type ReservedPriorityEntries = ReservedPriorityOracleEntries;
+	type MaxWhitelistedPairs = MaxWhitelistedOraclePairs;
+	type MaxDerivedPriorityPairs = MaxDerivedPriorityOraclePairs;
+	type WeightInfo = weights::pallet_oracle_whitelist::BasiliskWeight<Runtime>;
 }
 
 impl pallet_ema_oracle::Config for Runtime {
@@ -821,7 +1219,7 @@ impl pallet_ema_oracle::Config for Runtime {
 	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
 	type SupportedPeriods = SupportedPeriods;
-	type OracleWhitelist = Everything;
+	type OracleWhitelist = OracleWhitelist;
 	type MaxUniqueEntries = MaxUniqueOracleEntries;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = benchmarking::BenchmarkHelper;
@@ -841,8 +1239,109 @@ impl pallet_nft::Config for Runtime {
 	type ReserveCollectionIdUpTo = ReserveCollectionIdUpTo;
 }
 
+parameter_types! {
+	pub const FeePaymentRouteOraclePeriod: OraclePeriod = OraclePeriod::Short;
+	// Max. difference between the price of the fee payment route and its oracle price.
+	pub FeePaymentRouteMaxPriceDifference: Permill = Permill::from_percent(2);
+}
+
+/// Swaps of the fee payment assets. Assets are swapped in the direct XYK pool if it exists,
+/// otherwise the route stored in the router is used. Routes are protected by the oracle price, the
+/// amounts calculated for the route must not differ from the amounts at the oracle price by more
+/// than `FeePaymentRouteMaxPriceDifference`.
 pub struct XykPaymentAssetSupport;
 
+type RouterAmm = <Runtime as pallet_route_executor::Config>::AMM;
+
+impl XykPaymentAssetSupport {
+	/// Route stored in the router for the pair without a direct XYK pool.
+	pub(crate) fn stored_route(asset_in: AssetId, asset_out: AssetId) -> Option<Vec<Trade<AssetId>>> {
+		if XYK::exists(pallet_xyk::types::AssetPair::new(asset_in, asset_out)) {
+			return None;
+		}
+
+		let route = Router::get_route(RouterAssetPair::new(asset_in, asset_out));
+		let default_route = [Trade {
+			pool: DefaultRoutePoolType::get(),
+			asset_in,
+			asset_out,
+		}];
+
+		(route != default_route).then_some(route)
+	}
+
+	/// Amount of `asset_in` of the `route` corresponding to the `amount_out` at the oracle price.
+	fn oracle_amount_in(route: &[Trade<AssetId>], amount_out: Balance) -> Result<Balance, DispatchError> {
+		let price = <Runtime as pallet_route_executor::Config>::OraclePriceProvider::price(
+			route,
+			FeePaymentRouteOraclePeriod::get(),
+		)
+		.ok_or(pallet_route_executor::Error::<Runtime>::RouteHasNoOracle)?;
+
+		sp_runtime::helpers_128bit::multiply_by_rational_with_rounding(
+			amount_out,
+			price.n,
+			price.d,
+			sp_runtime::Rounding::Up,
+		)
+		.ok_or(ArithmeticError::Overflow.into())
+	}
+
+	/// Max. amount in of buying `amount_out` through the `route` allowed by the oracle price.
+	fn max_amount_in(route: &[Trade<AssetId>], amount_out: Balance) -> Result<Balance, DispatchError> {
+		let oracle_amount_in = Self::oracle_amount_in(route, amount_out)?;
+
+		Ok(oracle_amount_in.saturating_add(FeePaymentRouteMaxPriceDifference::get().mul_ceil(oracle_amount_in)))
+	}
+
+	fn calculate_route_buy(route: &[Trade<AssetId>], amount_out: Balance) -> Result<Balance, DispatchError> {
+		let mut amount = amount_out;
+		for trade in route.iter().rev() {
+			amount = <RouterAmm as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_buy(
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				amount,
+			)
+			.map_err(Self::executor_error)?;
+		}
+
+		ensure!(
+			amount <= Self::max_amount_in(route, amount_out)?,
+			pallet_route_executor::Error::<Runtime>::TradingLimitReached
+		);
+
+		Ok(amount)
+	}
+
+	fn calculate_route_sell(route: &[Trade<AssetId>], amount_in: Balance) -> Result<Balance, DispatchError> {
+		let mut amount = amount_in;
+		for trade in route.iter() {
+			amount = <RouterAmm as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				amount,
+			)
+			.map_err(Self::executor_error)?;
+		}
+
+		ensure!(
+			Self::max_amount_in(route, amount)? >= amount_in,
+			pallet_route_executor::Error::<Runtime>::TradingLimitReached
+		);
+
+		Ok(amount)
+	}
+
+	fn executor_error(error: ExecutorError<DispatchError>) -> DispatchError {
+		match error {
+			ExecutorError::NotSupported => pallet_route_executor::Error::<Runtime>::PoolNotSupported.into(),
+			ExecutorError::Error(error) => error,
+		}
+	}
+}
+
 impl InspectTransactionFeeCurrency<AssetId> for XykPaymentAssetSupport {
 	fn is_transaction_fee_currency(asset: AssetId) -> bool {
 		asset == CORE_ASSET_ID || MultiTransactionPayment::contains(&asset)
@@ -851,7 +1350,7 @@ impl InspectTransactionFeeCurrency<AssetId> for XykPaymentAssetSupport {
 
 impl SwappablePaymentAssetTrader<AccountId, AssetId, Balance> for XykPaymentAssetSupport {
 	fn is_trade_supported(from: AssetId, into: AssetId) -> bool {
-		XYK::exists(pallet_xyk::types::AssetPair::new(from, into))
+		XYK::exists(pallet_xyk::types::AssetPair::new(from, into)) || Self::stored_route(from, into).is_some()
 	}
 
 	fn calculate_fee_amount(swap_amount: Balance) -> Result<Balance, DispatchError> {
@@ -866,9 +1365,13 @@ impl SwappablePaymentAssetTrader<AccountId, AssetId, Balance> for XykPaymentAsse
 		asset_out: AssetId,
 		asset_out_amount: Balance,
 	) -> Result<Balance, DispatchError> {
+		if let Some(route) = Self::stored_route(insuff_asset_id, asset_out) {
+			return Self::calculate_route_buy(&route, asset_out_amount);
+		}
+
 		let asset_pair_account = XYK::get_pair_id(pallet_xyk::types::AssetPair::new(insuff_asset_id, asset_out));
 		let out_reserve = Currencies::free_balance(asset_out, &asset_pair_account);
-		let in_reserve = Currencies::free_balance(insuff_asset_id, &asset_pair_account.clone());
+		let in_reserve = Currencies::free_balance(insuff_asset_id, &asset_pair_account);
 
 		hydra_dx_math::xyk::calculate_in_given_out(out_reserve, in_reserve, asset_out_amount)
 			.map_err(|_err| ArithmeticError::Overflow.into())
@@ -879,8 +1382,12 @@ impl SwappablePaymentAssetTrader<AccountId, AssetId, Balance> for XykPaymentAsse
 		asset_out: AssetId,
 		asset_in_amount: Balance,
 	) -> Result<Balance, DispatchError> {
+		if let Some(route) = Self::stored_route(asset_in, asset_out) {
+			return Self::calculate_route_sell(&route, asset_in_amount);
+		}
+
 		let asset_pair_account = XYK::get_pair_id(pallet_xyk::types::AssetPair::new(asset_in, asset_out));
-		let in_reserve = Currencies::free_balance(asset_in, &asset_pair_account.clone());
+		let in_reserve = Currencies::free_balance(asset_in, &asset_pair_account);
 		let out_reserve = Currencies::free_balance(asset_out, &asset_pair_account);
 
 		hydra_dx_math::xyk::calculate_out_given_in(in_reserve, out_reserve, asset_in_amount)
@@ -895,6 +1402,24 @@ impl SwappablePaymentAssetTrader<AccountId, AssetId, Balance> for XykPaymentAsse
 		max_limit: Balance,
 		dest: &AccountId,
 	) -> DispatchResult {
+		if let Some(route) = Self::stored_route(asset_in, asset_out) {
+			let max_limit = max_limit.min(Self::max_amount_in(&route, amount)?);
+			Router::buy(
+				RuntimeOrigin::signed(origin.clone()),
+				asset_in,
+				asset_out,
+				amount,
+				max_limit,
+				route,
+			)?;
+
+			if origin != dest {
+				<Currencies as MultiCurrency<AccountId>>::transfer(asset_out, origin, dest, amount)?;
+			}
+
+			return Ok(());
+		}
+
 		XYK::buy_for(
 			origin,
 			pallet_xyk::types::AssetPair { asset_in, asset_out },
@@ -0,0 +1,228 @@
+// This file is part of Basilisk-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{AccountId, AssetId, Balance, Currencies, EmaOracle, NamedReserveId, Runtime, System, DCA};
+
+use super::*;
+use frame_benchmarking::{account, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{Get, OnFinalize, OnInitialize},
+};
+use frame_system::RawOrigin;
+use hydradx_traits::router::{PoolType, Trade};
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::{MultiCurrencyExtended, NamedMultiReservableCurrency};
+use pallet_dca::types::{Order, Schedule, ScheduleId};
+use primitives::{constants::currency::UNITS, BlockNumber};
+use sp_runtime::Permill;
+use sp_std::vec;
+
+pub const INITIAL_BALANCE: Balance = 10_000_000 * UNITS;
+
+const BSX: AssetId = 0;
+
+fn funded_account(name: &'static str, index: u32, assets: &[AssetId]) -> AccountId {
+	let account: AccountId = account(name, index, 0);
+
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(BSX, &account, INITIAL_BALANCE as i128).unwrap();
+	for asset in assets {
+		assert_ok!(<Currencies as MultiCurrencyExtended<_>>::update_balance(
+			*asset,
+			&account,
+			INITIAL_BALANCE as i128,
+		));
+	}
+
+	account
+}
+
+fn set_period(to: u32) {
+	while System::block_number() < Into::<BlockNumber>::into(to) {
+		let b = System::block_number();
+
+		System::on_finalize(b);
+		EmaOracle::on_finalize(b);
+
+		System::on_initialize(b + 1_u32);
+		EmaOracle::on_initialize(b + 1_u32);
+
+		System::set_block_number(b + 1_u32);
+	}
+}
+
+fn schedule_fake(owner: AccountId, order: Order<AssetId>) -> Schedule<AccountId, AssetId, BlockNumber> {
+	Schedule {
+		owner,
+		period: 5u32,
+		total_amount: 1000 * UNITS,
+		max_retries: None,
+		stability_threshold: None,
+		slippage: Some(Permill::from_percent(15)),
+		order,
+	}
+}
+
+fn sell_order(asset_in: AssetId, asset_out: AssetId) -> Order<AssetId> {
+	Order::Sell {
+		asset_in,
+		asset_out,
+		amount_in: 10 * UNITS,
+		min_amount_out: Balance::MIN,
+		route: route(asset_in, asset_out).try_into().unwrap(),
+	}
+}
+
+fn buy_order(asset_in: AssetId, asset_out: AssetId) -> Order<AssetId> {
+	Order::Buy {
+		asset_in,
+		asset_out,
+		amount_out: 10 * UNITS,
+		max_amount_in: Balance::MAX,
+		route: route(asset_in, asset_out).try_into().unwrap(),
+	}
+}
+
+fn route(asset_in: AssetId, asset_out: AssetId) -> Vec<Trade<AssetId>> {
+	vec![Trade {
+		pool: PoolType::XYK,
+		asset_in,
+		asset_out,
+	}]
+}
+
+fn fill_schedules_per_block(execution_block: BlockNumber) {
+	// the worst case is when the planned block is full and the schedule has to be moved to the next blocks
+	for i in 0..<Runtime as pallet_dca::Config>::MaxSchedulePerBlock::get() {
+		pallet_dca::ScheduleIdsPerBlock::<Runtime>::mutate(execution_block, |ids| {
+			let _ = ids.try_push(ScheduleId::MAX - i);
+		});
+	}
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_dca}
+
+	on_initialize_with_buy_trade {
+		let asset_out = register_asset(b"AS1".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		create_xyk_pool(BSX, asset_out);
+		set_period(100);
+
+		let owner: AccountId = funded_account("owner", 0, &[asset_out]);
+		let schedule = schedule_fake(owner.clone(), buy_order(BSX, asset_out));
+		let execution_block = 105u32;
+
+		assert_ok!(DCA::schedule(RawOrigin::Signed(owner.clone()).into(), schedule, Some(execution_block)));
+		fill_schedules_per_block(execution_block + 5);
+
+		let reserved_before = Currencies::reserved_balance_named(&NamedReserveId::get(), BSX, &owner);
+	}: {
+		DCA::on_initialize(execution_block);
+	}
+	verify {
+		let reserved_after = Currencies::reserved_balance_named(&NamedReserveId::get(), BSX, &owner);
+		assert!(reserved_after < reserved_before);
+	}
+
+	on_initialize_with_sell_trade {
+		let asset_out = register_asset(b"AS1".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		create_xyk_pool(BSX, asset_out);
+		set_period(100);
+
+		let owner: AccountId = funded_account("owner", 0, &[asset_out]);
+		let schedule = schedule_fake(owner.clone(), sell_order(BSX, asset_out));
+		let execution_block = 105u32;
+
+		assert_ok!(DCA::schedule(RawOrigin::Signed(owner.clone()).into(), schedule, Some(execution_block)));
+		fill_schedules_per_block(execution_block + 5);
+
+		let reserved_before = Currencies::reserved_balance_named(&NamedReserveId::get(), BSX, &owner);
+	}: {
+		DCA::on_initialize(execution_block);
+	}
+	verify {
+		let reserved_after = Currencies::reserved_balance_named(&NamedReserveId::get(), BSX, &owner);
+		assert!(reserved_after < reserved_before);
+	}
+
+	on_initialize_with_empty_block {
+		let execution_block = 100u32;
+	}: {
+		DCA::on_initialize(execution_block);
+	}
+	verify {
+		assert!(pallet_dca::ScheduleIdsPerBlock::<Runtime>::get(execution_block).is_empty());
+	}
+
+	schedule {
+		let asset_out = register_asset(b"AS1".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		create_xyk_pool(BSX, asset_out);
+		set_period(100);
+
+		let owner: AccountId = funded_account("owner", 0, &[asset_out]);
+		let schedule = schedule_fake(owner.clone(), sell_order(BSX, asset_out));
+		let execution_block = 105u32;
+
+		// the worst case is when the planned block is full
+		fill_schedules_per_block(execution_block);
+	}: _(RawOrigin::Signed(owner.clone()), schedule, Some(execution_block))
+	verify {
+		assert!(pallet_dca::Schedules::<Runtime>::get::<ScheduleId>(0).is_some());
+	}
+
+	terminate {
+		let asset_out = register_asset(b"AS1".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		create_xyk_pool(BSX, asset_out);
+		set_period(100);
+
+		let owner: AccountId = funded_account("owner", 0, &[asset_out]);
+		let schedule = schedule_fake(owner.clone(), sell_order(BSX, asset_out));
+		let execution_block = 105u32;
+
+		assert_ok!(DCA::schedule(RawOrigin::Signed(owner).into(), schedule, Some(execution_block)));
+	}: _(RawOrigin::Root, 0, None)
+	verify {
+		assert!(pallet_dca::Schedules::<Runtime>::get::<ScheduleId>(0).is_none());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<crate::Runtime> {
+			registered_assets: vec![],
+			native_asset_name: b"BSX".to_vec(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
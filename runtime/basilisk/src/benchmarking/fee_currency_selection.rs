@@ -0,0 +1,92 @@
+// This file is part of Basilisk-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{FeeCurrencySelection, MaxFallbackFeeCurrencies, Runtime};
+
+use super::*;
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::FixedU128;
+
+runtime_benchmarks! {
+	{Runtime, pallet_fee_currency_selection}
+
+	set_fallback_currencies {
+		let n in 0 .. MaxFallbackFeeCurrencies::get();
+
+		let mut currencies = vec![];
+		for i in 0..n {
+			let name = [b"FC".to_vec(), i.to_le_bytes().to_vec()].concat();
+			let asset = register_asset(name, 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+			assert_ok!(MultiTransactionPayment::add_currency(RawOrigin::Root.into(), asset, FixedU128::from(1)));
+			currencies.push(asset);
+		}
+	}: _(RawOrigin::Root, currencies.clone())
+	verify {
+		assert_eq!(FeeCurrencySelection::fallback_currencies().into_inner(), currencies);
+	}
+
+	select_fee_currency {
+		let n in 1 .. MaxFallbackFeeCurrencies::get();
+
+		let caller: AccountId = account("caller", 0, 1);
+		let fee: Balance = 10 * BSX;
+
+		let account_currency = register_asset(b"AFC".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		assert_ok!(MultiTransactionPayment::add_currency(RawOrigin::Root.into(), account_currency, FixedU128::from(1)));
+		pallet_transaction_multi_payment::pallet::AcceptedCurrencyPrice::<Runtime>::insert(account_currency, FixedU128::from(1));
+		update_balance(account_currency, &caller, BSX);
+		assert_ok!(MultiTransactionPayment::set_currency(RawOrigin::Signed(caller.clone()).into(), account_currency));
+
+		// only the last fallback currency can pay the fee, so all of them are checked
+		let mut currencies = vec![];
+		for i in 0..n {
+			let name = [b"FC".to_vec(), i.to_le_bytes().to_vec()].concat();
+			let asset = register_asset(name, 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+			assert_ok!(MultiTransactionPayment::add_currency(RawOrigin::Root.into(), asset, FixedU128::from(1)));
+			pallet_transaction_multi_payment::pallet::AcceptedCurrencyPrice::<Runtime>::insert(asset, FixedU128::from(1));
+			currencies.push(asset);
+		}
+		let last = *currencies.last().ok_or(BenchmarkError::Stop("No fallback currency"))?;
+		update_balance(last, &caller, 100 * BSX);
+		assert_ok!(FeeCurrencySelection::set_fallback_currencies(RawOrigin::Root.into(), currencies));
+
+		let mut selected = (None, 0);
+	}: {
+		selected = FeeCurrencySelection::select_fee_currency(&caller, fee);
+	}
+	verify {
+		assert_eq!(selected, (Some(last), n));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
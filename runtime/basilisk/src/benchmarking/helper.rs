@@ -2,6 +2,7 @@ use crate::AssetRegistry;
 use frame_support::dispatch::DispatchResult;
 use pallet_asset_registry::traits::InspectRegistry;
 use pallet_ema_oracle::BenchmarkHelper as EmaOracleBenchmarkHelper;
+use pallet_stableswap::BenchmarkHelper as StableswapBenchmarkHelper;
 use pallet_treasury::ArgumentsFactory;
 use primitives::{AccountId, AssetId};
 
@@ -34,3 +35,13 @@ impl EmaOracleBenchmarkHelper<AssetId> for BenchmarkHelper {
 		Ok(())
 	}
 }
+
+// Stableswap helper
+impl StableswapBenchmarkHelper<AssetId> for BenchmarkHelper {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		<Self as EmaOracleBenchmarkHelper<AssetId>>::register_asset(asset_id)?;
+
+		let name = asset_id.to_ne_bytes().to_vec();
+		AssetRegistry::set_metadata(frame_system::RawOrigin::Root.into(), asset_id, name, decimals)
+	}
+}
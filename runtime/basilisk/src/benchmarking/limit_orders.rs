@@ -0,0 +1,113 @@
+// This file is part of Basilisk-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{AccountId, AssetId, Balance, Currencies, LimitOrders, LimitOrdersNamedReserveId, Runtime};
+
+use super::*;
+use frame_benchmarking::{account, BenchmarkError};
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::{MultiCurrency, MultiCurrencyExtended, NamedMultiReservableCurrency};
+use primitives::constants::currency::UNITS;
+
+pub const INITIAL_BALANCE: Balance = 10_000_000 * UNITS;
+
+const BSX: AssetId = 0;
+
+fn funded_account(name: &'static str, index: u32, assets: &[AssetId]) -> AccountId {
+	let account: AccountId = account(name, index, 0);
+
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(BSX, &account, INITIAL_BALANCE as i128).unwrap();
+	for asset in assets {
+		assert_ok!(<Currencies as MultiCurrencyExtended<_>>::update_balance(
+			*asset,
+			&account,
+			INITIAL_BALANCE as i128,
+		));
+	}
+
+	account
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_limit_orders}
+
+	place_order {
+		let asset_in = register_asset(b"AS1".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let owner: AccountId = funded_account("owner", 0, &[asset_in]);
+	}: _(RawOrigin::Signed(owner.clone()), asset_in, BSX, 10 * UNITS, 9 * UNITS, Some(100u32))
+	verify {
+		assert!(pallet_limit_orders::Orders::<Runtime>::get(0).is_some());
+		assert_eq!(Currencies::reserved_balance_named(&LimitOrdersNamedReserveId::get(), asset_in, &owner), 10 * UNITS);
+	}
+
+	fill_order {
+		let asset_in = register_asset(b"AS1".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		create_xyk_pool(asset_in, BSX);
+
+		let owner: AccountId = funded_account("owner", 0, &[asset_in]);
+		let keeper: AccountId = funded_account("keeper", 1, &[]);
+
+		assert_ok!(LimitOrders::place_order(RawOrigin::Signed(owner.clone()).into(), asset_in, BSX, 10 * UNITS, 9 * UNITS, None));
+
+		let owner_bsx_balance = Currencies::free_balance(BSX, &owner);
+	}: _(RawOrigin::Signed(keeper), 0, 5 * UNITS)
+	verify {
+		// order is quoted and sold along the stored route of the pair
+		assert!(Currencies::free_balance(BSX, &owner) > owner_bsx_balance);
+		assert_eq!(Currencies::reserved_balance_named(&LimitOrdersNamedReserveId::get(), asset_in, &owner), 5 * UNITS);
+	}
+
+	cancel_order {
+		let asset_in = register_asset(b"AS1".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let owner: AccountId = funded_account("owner", 0, &[asset_in]);
+
+		assert_ok!(LimitOrders::place_order(RawOrigin::Signed(owner.clone()).into(), asset_in, BSX, 10 * UNITS, 9 * UNITS, None));
+	}: _(RawOrigin::Signed(owner.clone()), 0)
+	verify {
+		assert!(pallet_limit_orders::Orders::<Runtime>::get(0).is_none());
+		assert_eq!(Currencies::reserved_balance_named(&LimitOrdersNamedReserveId::get(), asset_in, &owner), 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<crate::Runtime> {
+			registered_assets: vec![],
+			native_asset_name: b"BSX".to_vec(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
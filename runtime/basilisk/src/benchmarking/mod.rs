@@ -1,12 +1,18 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 pub mod currencies;
+pub mod dca;
 pub mod duster;
+pub mod fee_currency_selection;
 mod helper;
+pub mod limit_orders;
 pub mod marketplace;
 pub mod multi_payment;
+pub mod oracle_whitelist;
+pub mod otc;
 pub mod route_executor;
 pub mod tokens;
+pub mod trade_stats;
 pub mod vesting;
 pub mod xyk;
 
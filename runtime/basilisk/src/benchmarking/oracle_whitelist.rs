@@ -0,0 +1,73 @@
+// This file is part of Basilisk-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{AccountId, AssetId, MaxWhitelistedOraclePairs, OracleWhitelist, Runtime, XYKOracleSourceIdentifier};
+
+use super::*;
+use frame_benchmarking::account;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use pallet_oracle_whitelist::PairPriority;
+
+runtime_benchmarks! {
+	{Runtime, pallet_oracle_whitelist}
+
+	whitelist_pair {
+		let source = XYKOracleSourceIdentifier::get();
+		for i in 1..MaxWhitelistedOraclePairs::get() {
+			assert_ok!(OracleWhitelist::whitelist_pair(RawOrigin::Root.into(), source, i as AssetId, (i + 1_000) as AssetId, PairPriority::Normal));
+		}
+	}: _(RawOrigin::Root, source, 0, 1_000, PairPriority::High)
+	verify {
+		assert_eq!(OracleWhitelist::whitelisted_pairs((source, (0, 1_000))), Some(PairPriority::High));
+	}
+
+	remove_pair {
+		let source = XYKOracleSourceIdentifier::get();
+		assert_ok!(OracleWhitelist::whitelist_pair(RawOrigin::Root.into(), source, 0, 1_000, PairPriority::High));
+	}: _(RawOrigin::Root, source, 1_000, 0)
+	verify {
+		assert_eq!(OracleWhitelist::whitelisted_pairs((source, (0, 1_000))), None);
+	}
+
+	// weight of deriving the pairs is charged by `DerivedPriorityPairs::derive_weight`
+	refresh_priority_pairs {
+		let caller: AccountId = account("caller", 0, 0);
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert!(OracleWhitelist::stored_priority_pairs().is_empty());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
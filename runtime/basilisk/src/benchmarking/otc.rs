@@ -0,0 +1,127 @@
+// This file is part of Basilisk-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{AccountId, AssetId, Balance, Currencies, Runtime, OTC};
+
+use super::*;
+use frame_benchmarking::{account, BenchmarkError};
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiReservableCurrency};
+use primitives::constants::currency::UNITS;
+
+pub const INITIAL_BALANCE: Balance = 10_000_000 * UNITS;
+
+fn funded_account(name: &'static str, index: u32, assets: &[AssetId]) -> AccountId {
+	let account: AccountId = account(name, index, 0);
+
+	for asset in assets {
+		assert_ok!(<Currencies as MultiCurrencyExtended<_>>::update_balance(
+			*asset,
+			&account,
+			INITIAL_BALANCE as i128,
+		));
+	}
+
+	account
+}
+
+fn setup_order(partially_fillable: bool) -> Result<(AssetId, AssetId, AccountId), BenchmarkError> {
+	let asset_in = register_asset(b"AS1".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+	let asset_out = register_asset(b"AS2".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+	let owner: AccountId = funded_account("owner", 0, &[asset_in, asset_out]);
+
+	assert_ok!(OTC::place_order(
+		RawOrigin::Signed(owner.clone()).into(),
+		asset_in,
+		asset_out,
+		100 * UNITS,
+		200 * UNITS,
+		partially_fillable
+	));
+
+	Ok((asset_in, asset_out, owner))
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_otc}
+
+	place_order {
+		let asset_in = register_asset(b"AS1".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let asset_out = register_asset(b"AS2".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let owner: AccountId = funded_account("owner", 0, &[asset_in, asset_out]);
+	}: _(RawOrigin::Signed(owner.clone()), asset_in, asset_out, 100 * UNITS, 200 * UNITS, true)
+	verify {
+		assert!(pallet_otc::Orders::<Runtime>::get(0).is_some());
+		assert_eq!(Currencies::reserved_balance(asset_out, &owner), 200 * UNITS);
+	}
+
+	partial_fill_order {
+		let (asset_in, asset_out, owner) = setup_order(true)?;
+		let filler: AccountId = funded_account("filler", 1, &[asset_in, asset_out]);
+	}: _(RawOrigin::Signed(filler.clone()), 0, 10 * UNITS)
+	verify {
+		assert_eq!(Currencies::reserved_balance(asset_out, &owner), 180 * UNITS);
+		assert_eq!(Currencies::free_balance(asset_in, &filler), INITIAL_BALANCE - 10 * UNITS);
+	}
+
+	fill_order {
+		let (asset_in, asset_out, owner) = setup_order(false)?;
+		let filler: AccountId = funded_account("filler", 1, &[asset_in, asset_out]);
+	}: _(RawOrigin::Signed(filler.clone()), 0)
+	verify {
+		assert!(pallet_otc::Orders::<Runtime>::get(0).is_none());
+		assert_eq!(Currencies::reserved_balance(asset_out, &owner), 0);
+		assert_eq!(Currencies::free_balance(asset_in, &filler), INITIAL_BALANCE - 100 * UNITS);
+	}
+
+	cancel_order {
+		let (_asset_in, asset_out, owner) = setup_order(true)?;
+	}: _(RawOrigin::Signed(owner.clone()), 0)
+	verify {
+		assert!(pallet_otc::Orders::<Runtime>::get(0).is_none());
+		assert_eq!(Currencies::reserved_balance(asset_out, &owner), 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<crate::Runtime> {
+			registered_assets: vec![],
+			native_asset_name: b"BSX".to_vec(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
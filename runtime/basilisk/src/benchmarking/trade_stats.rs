@@ -0,0 +1,72 @@
+// This file is part of Basilisk-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{AssetId, MaxTradeStatsBuckets, Runtime, TradeStats};
+
+use super::*;
+use orml_benchmarking::runtime_benchmarks;
+use pallet_trade_stats::Swap;
+
+const ASSET_IN: AssetId = 1;
+const ASSET_OUT: AssetId = 1_000;
+
+fn swap() -> Swap {
+	Swap {
+		asset_in: ASSET_IN,
+		asset_out: ASSET_OUT,
+		amount_in: 10 * BSX,
+		amount_out: 5 * BSX,
+		fees: vec![(ASSET_OUT, BSX / 100)],
+	}
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_trade_stats}
+
+	note_swap {
+		// pair has all the older buckets, so the oldest one is dropped
+		let max_buckets = MaxTradeStatsBuckets::get();
+		for bucket_id in 0..max_buckets {
+			TradeStats::record_swap(bucket_id, &swap());
+		}
+	}: {
+		TradeStats::record_swap(max_buckets, &swap());
+	}
+	verify {
+		let buckets = TradeStats::buckets((ASSET_IN, ASSET_OUT));
+		assert_eq!(buckets.len() as u32, max_buckets);
+		assert_eq!(buckets.last().map(|(id, _)| *id), Some(max_buckets));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -39,6 +39,8 @@ mod adapter;
 pub mod apis;
 mod assets;
 mod governance;
+pub mod oracle_api;
+pub mod router_api;
 mod system;
 pub mod xcm;
 
@@ -55,7 +57,7 @@ use frame_support::sp_runtime::{
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use primitives::{
 	constants::time::SLOT_DURATION, AccountId, Amount, AssetId, Balance, BlockNumber, CollectionId, Hash, Index,
-	ItemId, Price, Signature,
+	ItemId, NamedReserveIdentifier, Price, Signature,
 };
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::ConstU32;
@@ -100,7 +102,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("basilisk"),
 	impl_name: create_runtime_str!("basilisk"),
 	authoring_version: 1,
-	spec_version: 127,
+	spec_version: 152,
 	impl_version: 0,
 	apis: apis::RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -222,8 +224,15 @@ construct_runtime!(
 		CollatorRewards: pallet_collator_rewards = 114,
 		// Note: 115 was used by rate limiter which is now removed
 		Broadcast: pallet_broadcast = 116,
+		DCA: pallet_dca = 117,
+		LimitOrders: pallet_limit_orders = 118,
+		OTC: pallet_otc = 119,
 
 		EmaOracle: pallet_ema_oracle = 120,
+		Stableswap: pallet_stableswap = 121,
+		TradeStats: pallet_trade_stats = 123,
+		OracleWhitelist: pallet_oracle_whitelist = 124,
+		FeeCurrencySelection: pallet_fee_currency_selection = 125,
 
 		// ORML related modules - runtime module index for orml starts at 150
 		Currencies: pallet_currencies = 150,
@@ -272,12 +281,142 @@ pub type Executive = frame_executive::Executive<
 
 pub mod migrations {
 	use super::*;
+	use frame_support::traits::Get;
+	use sp_runtime::Saturating;
 
 	impl cumulus_pallet_xcmp_queue::migration::v5::V5Config for Runtime {
 		type ChannelList = ParachainSystem;
 	}
 
-	pub type Migrations = (cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,);
+	/// Reserve identifier of `Balances` and `Tokens` was changed from `()` to `NamedReserveIdentifier`.
+	/// Named reserves were never created with the `()` identifier so there is nothing to migrate.
+	pub struct EnsureNoNamedReserves;
+	impl frame_support::traits::OnRuntimeUpgrade for EnsureNoNamedReserves {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			frame_support::ensure!(
+				pallet_balances::Reserves::<Runtime>::iter_keys().next().is_none(),
+				"Balances named reserves exist"
+			);
+			frame_support::ensure!(
+				orml_tokens::Reserves::<Runtime>::iter_keys().next().is_none(),
+				"Tokens named reserves exist"
+			);
+
+			Ok(Vec::new())
+		}
+	}
+
+	/// Oracle entries are tracked only for the whitelisted pairs. Pairs already tracked by the oracle
+	/// are whitelisted with normal priority so their entries keep being updated. Pairs tracked with
+	/// priority by `PriorityPairs` don't need to be whitelisted.
+	///
+	/// Pairs which don't fit into `MaxWhitelistedPairs` are logged and have to be whitelisted by
+	/// the governance, `try-runtime` checks fail in that case.
+	pub struct WhitelistTrackedOraclePairs;
+	impl WhitelistTrackedOraclePairs {
+		/// Pairs tracked by the oracle which are neither whitelisted nor tracked with priority and
+		/// the number of reads.
+		fn tracked_pairs() -> (Vec<(hydradx_traits::Source, (AssetId, AssetId))>, u64) {
+			let mut reads: u64 = 0;
+			let mut pairs = Vec::new();
+			for (source, pair, period) in pallet_ema_oracle::Oracles::<Runtime>::iter_keys() {
+				reads.saturating_inc();
+				if period != hydradx_traits::OraclePeriod::LastBlock {
+					continue;
+				}
+				reads.saturating_accrue(3);
+				if OracleWhitelist::pair_priority(source, pair.0, pair.1).is_none() {
+					pairs.push((source, pair));
+				}
+			}
+
+			(pairs, reads)
+		}
+	}
+
+	impl frame_support::traits::OnRuntimeUpgrade for WhitelistTrackedOraclePairs {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			use codec::Encode;
+
+			if pallet_oracle_whitelist::WhitelistedPairs::<Runtime>::count() > 0 {
+				return Ok(None::<u32>.encode());
+			}
+
+			let (pairs, _) = Self::tracked_pairs();
+			frame_support::ensure!(
+				pairs.len() <= <Runtime as pallet_oracle_whitelist::Config>::MaxWhitelistedPairs::get() as usize,
+				"Tracked oracle pairs don't fit into MaxWhitelistedPairs"
+			);
+
+			Ok(Some(pairs.len() as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if pallet_oracle_whitelist::WhitelistedPairs::<Runtime>::count() > 0 {
+				return <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+			}
+
+			let (pairs, reads) = Self::tracked_pairs();
+			let max_pairs = <Runtime as pallet_oracle_whitelist::Config>::MaxWhitelistedPairs::get() as usize;
+			if pairs.len() > max_pairs {
+				log::error!(
+					target: "runtime::migrations",
+					"WhitelistTrackedOraclePairs: {} of {} tracked oracle pairs don't fit into MaxWhitelistedPairs and are not whitelisted: {:?}",
+					pairs.len().saturating_sub(max_pairs),
+					pairs.len(),
+					&pairs[max_pairs..],
+				);
+			}
+
+			let mut writes: u64 = 0;
+			for key in pairs.into_iter().take(max_pairs) {
+				pallet_oracle_whitelist::WhitelistedPairs::<Runtime>::insert(
+					key,
+					pallet_oracle_whitelist::PairPriority::Normal,
+				);
+				writes.saturating_inc();
+			}
+
+			log::info!(
+				target: "runtime::migrations",
+				"WhitelistTrackedOraclePairs: {} oracle pairs whitelisted",
+				writes,
+			);
+
+			<Runtime as frame_system::Config>::DbWeight::get()
+				.reads_writes(reads.saturating_add(1), writes.saturating_mul(2))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			use codec::Decode;
+
+			let Some(whitelisted) = Option::<u32>::decode(&mut &state[..])
+				.map_err(|_| sp_runtime::TryRuntimeError::Other("Failed to decode the pre upgrade state"))?
+			else {
+				return Ok(());
+			};
+
+			frame_support::ensure!(
+				pallet_oracle_whitelist::WhitelistedPairs::<Runtime>::count() == whitelisted,
+				"Unexpected number of the whitelisted oracle pairs"
+			);
+			frame_support::ensure!(
+				Self::tracked_pairs().0.is_empty(),
+				"Tracked oracle pairs are not whitelisted"
+			);
+
+			Ok(())
+		}
+	}
+
+	pub type Migrations = (
+		cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+		EnsureNoNamedReserves,
+		WhitelistTrackedOraclePairs,
+	);
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -292,6 +431,7 @@ mod benches {
 		[pallet_xyk_liquidity_mining, XYKLiquidityMiningBench::<Runtime>]
 		[pallet_transaction_pause, TransactionPause]
 		[pallet_ema_oracle, EmaOracle]
+		[pallet_stableswap, Stableswap]
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
@@ -0,0 +1,68 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the `EmaOracleApi` - prices tracked by the EMA oracle.
+
+use crate::{adapter::OraclePriceProvider, AssetId, Balance, BlockNumber, EmaOracle, Runtime};
+use basilisk_ema_oracle_runtime_api::{OracleEntry, OraclePrice, Price};
+use hydradx_traits::{router::Trade, AggregatedOracle, AggregatedPriceOracle, OraclePeriod, PriceOracle, Source};
+use sp_std::vec::Vec;
+
+/// Price of the `asset_a` denominated in the `asset_b` tracked by the oracle of the `source`.
+pub fn get_price(
+	asset_a: AssetId,
+	asset_b: AssetId,
+	period: OraclePeriod,
+	source: Source,
+) -> Option<OraclePrice<Balance, BlockNumber>> {
+	let (price, oracle_age) = EmaOracle::get_price(asset_a, asset_b, period, source).ok()?;
+
+	Some(OraclePrice {
+		price: Price { n: price.n, d: price.d },
+		oracle_age,
+	})
+}
+
+/// Oracle price of the `route` calculated the same way as the router does.
+pub fn get_route_price(route: Vec<Trade<AssetId>>, period: OraclePeriod) -> Option<Price<Balance>> {
+	let price = OraclePriceProvider::<AssetId, EmaOracle>::price(&route, period)?;
+
+	Some(Price { n: price.n, d: price.d })
+}
+
+/// All entries tracked by the oracle updated to the current block.
+pub fn oracle_entries() -> Vec<OracleEntry<AssetId, Balance, BlockNumber>> {
+	pallet_ema_oracle::Oracles::<Runtime>::iter_keys()
+		.filter_map(|(source, (asset_a, asset_b), period)| {
+			let entry = EmaOracle::get_entry(asset_a, asset_b, period, source).ok()?;
+
+			Some(OracleEntry {
+				source,
+				asset_a,
+				asset_b,
+				period,
+				price: Price {
+					n: entry.price.n,
+					d: entry.price.d,
+				},
+				liquidity_a: entry.liquidity.a,
+				liquidity_b: entry.liquidity.b,
+				oracle_age: entry.oracle_age,
+			})
+		})
+		.collect()
+}
@@ -0,0 +1,264 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the `RouterApi` - quotes of the routes executed by the router and search of
+//! the best route between two assets across all XYK, LBP and stableswap pools.
+
+use crate::{AccountId, AssetId, Balance, MaxRouteSearchHops, Runtime, RuntimeOrigin, XYKExchangeFee, LBP};
+use basilisk_router_runtime_api::{RouteQuote, TradeQuote};
+use frame_support::traits::Get;
+use hydradx_traits::router::{PoolType, Trade, TradeExecution};
+use sp_runtime::{traits::Zero, FixedPointNumber, FixedU128, Permill};
+use sp_std::vec::Vec;
+
+type Amm = <Runtime as pallet_route_executor::Config>::AMM;
+
+/// Quote selling `amount_in` through the `route`.
+pub fn quote_sell(route: Vec<Trade<AssetId>>, amount_in: Balance) -> Option<RouteQuote<AssetId, Balance>> {
+	if route.is_empty() || amount_in.is_zero() {
+		return None;
+	}
+
+	let mut trades = Vec::with_capacity(route.len());
+	let mut amount = amount_in;
+	for trade in route.iter() {
+		let amount_out = <Amm as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+			trade.pool,
+			trade.asset_in,
+			trade.asset_out,
+			amount,
+		)
+		.ok()?;
+
+		trades.push(trade_quote(trade, amount, amount_out)?);
+		amount = amount_out;
+	}
+
+	let spot_price = route_spot_price(&trades)?;
+	let amount_out = amount;
+
+	// amount out if the whole route was traded at the spot price
+	let ideal_amount_out = spot_price.reciprocal()?.checked_mul_int(amount_in)?;
+	let price_impact = if ideal_amount_out > amount_out {
+		FixedU128::checked_from_rational(ideal_amount_out - amount_out, ideal_amount_out)?
+	} else {
+		FixedU128::zero()
+	};
+
+	Some(RouteQuote {
+		route,
+		amount_in,
+		amount_out,
+		trades,
+		price_impact,
+	})
+}
+
+/// Quote buying `amount_out` through the `route`.
+pub fn quote_buy(route: Vec<Trade<AssetId>>, amount_out: Balance) -> Option<RouteQuote<AssetId, Balance>> {
+	if route.is_empty() || amount_out.is_zero() {
+		return None;
+	}
+
+	let mut trades = Vec::with_capacity(route.len());
+	let mut amount = amount_out;
+	for trade in route.iter().rev() {
+		let amount_in = <Amm as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_buy(
+			trade.pool,
+			trade.asset_in,
+			trade.asset_out,
+			amount,
+		)
+		.ok()?;
+
+		trades.push(trade_quote(trade, amount_in, amount)?);
+		amount = amount_in;
+	}
+	trades.reverse();
+
+	let spot_price = route_spot_price(&trades)?;
+	let amount_in = amount;
+
+	// amount in if the whole route was traded at the spot price
+	let ideal_amount_in = spot_price.checked_mul_int(amount_out)?;
+	let price_impact = if amount_in > ideal_amount_in {
+		FixedU128::checked_from_rational(amount_in - ideal_amount_in, amount_in)?
+	} else {
+		FixedU128::zero()
+	};
+
+	Some(RouteQuote {
+		route,
+		amount_in,
+		amount_out,
+		trades,
+		price_impact,
+	})
+}
+
+/// Search for the route with the biggest amount out of selling `amount_in`.
+pub fn best_sell_route(
+	asset_in: AssetId,
+	asset_out: AssetId,
+	amount_in: Balance,
+	max_hops: u32,
+) -> Option<RouteQuote<AssetId, Balance>> {
+	find_routes(asset_in, asset_out, max_hops)
+		.into_iter()
+		.filter_map(|route| quote_sell(route, amount_in))
+		.max_by_key(|quote| quote.amount_out)
+}
+
+/// Search for the route with the smallest amount in of buying `amount_out`.
+pub fn best_buy_route(
+	asset_in: AssetId,
+	asset_out: AssetId,
+	amount_out: Balance,
+	max_hops: u32,
+) -> Option<RouteQuote<AssetId, Balance>> {
+	find_routes(asset_in, asset_out, max_hops)
+		.into_iter()
+		.filter_map(|route| quote_buy(route, amount_out))
+		.min_by_key(|quote| quote.amount_in)
+}
+
+fn trade_quote(
+	trade: &Trade<AssetId>,
+	amount_in: Balance,
+	amount_out: Balance,
+) -> Option<TradeQuote<AssetId, Balance>> {
+	let spot_price_with_fee =
+		<Amm as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+			trade.pool,
+			trade.asset_in,
+			trade.asset_out,
+		)
+		.ok()?;
+
+	Some(TradeQuote {
+		pool: trade.pool,
+		asset_in: trade.asset_in,
+		asset_out: trade.asset_out,
+		amount_in,
+		amount_out,
+		spot_price_with_fee,
+		fee: pool_fee(trade)?,
+	})
+}
+
+/// Spot price of the route is product of the spot prices of its trades.
+fn route_spot_price(trades: &[TradeQuote<AssetId, Balance>]) -> Option<FixedU128> {
+	trades.iter().try_fold(FixedU128::one(), |acc, trade| {
+		acc.checked_mul(&trade.spot_price_with_fee)
+	})
+}
+
+fn pool_fee(trade: &Trade<AssetId>) -> Option<Permill> {
+	let (numerator, denominator) = match trade.pool {
+		PoolType::XYK => XYKExchangeFee::get(),
+		PoolType::LBP => {
+			let pool_id = LBP::get_pair_id(pallet_lbp::AssetPair {
+				asset_in: trade.asset_in,
+				asset_out: trade.asset_out,
+			});
+
+			pallet_lbp::PoolData::<Runtime>::get(pool_id)?.fee
+		}
+		PoolType::Stableswap(pool_id) => return Some(pallet_stableswap::Pools::<Runtime>::get(pool_id)?.fee),
+		_ => return None,
+	};
+
+	Some(Permill::from_rational(numerator, denominator))
+}
+
+/// Return all routes from the `asset_in` to the `asset_out` with at most `max_hops` trades which
+/// don't trade any asset more than once.
+fn find_routes(asset_in: AssetId, asset_out: AssetId, max_hops: u32) -> Vec<Vec<Trade<AssetId>>> {
+	let max_hops = max_hops.min(MaxRouteSearchHops::get()) as usize;
+	if asset_in == asset_out || max_hops.is_zero() {
+		return Vec::new();
+	}
+
+	let pools = pools();
+	let mut routes = Vec::new();
+	let mut route = Vec::with_capacity(max_hops);
+	search_routes(&pools, asset_in, asset_out, max_hops, &mut route, &mut routes);
+
+	routes
+}
+
+fn search_routes(
+	pools: &[(PoolType<AssetId>, AssetId, AssetId)],
+	asset_in: AssetId,
+	asset_out: AssetId,
+	max_hops: usize,
+	route: &mut Vec<Trade<AssetId>>,
+	routes: &mut Vec<Vec<Trade<AssetId>>>,
+) {
+	if route.len() >= max_hops {
+		return;
+	}
+
+	for (pool, asset_a, asset_b) in pools.iter() {
+		let next_asset = if *asset_a == asset_in {
+			*asset_b
+		} else if *asset_b == asset_in {
+			*asset_a
+		} else {
+			continue;
+		};
+
+		let already_visited = next_asset == route.first().map_or(asset_in, |trade| trade.asset_in)
+			|| route.iter().any(|trade| trade.asset_out == next_asset);
+		if already_visited {
+			continue;
+		}
+
+		route.push(Trade {
+			pool: *pool,
+			asset_in,
+			asset_out: next_asset,
+		});
+
+		if next_asset == asset_out {
+			routes.push(route.clone());
+		} else {
+			search_routes(pools, next_asset, asset_out, max_hops, route, routes);
+		}
+
+		route.pop();
+	}
+}
+
+/// All XYK, LBP and stableswap pools with their assets. Stableswap pool is listed once for every
+/// pair of its assets.
+fn pools() -> Vec<(PoolType<AssetId>, AssetId, AssetId)> {
+	let mut pools: Vec<_> = pallet_xyk::PoolAssets::<Runtime>::iter_values()
+		.map(|(asset_a, asset_b)| (PoolType::XYK, asset_a, asset_b))
+		.chain(pallet_lbp::PoolData::<Runtime>::iter_values().map(|pool| (PoolType::LBP, pool.assets.0, pool.assets.1)))
+		.collect();
+
+	for (pool_id, pool) in pallet_stableswap::Pools::<Runtime>::iter() {
+		for (idx, asset_a) in pool.assets.iter().enumerate() {
+			for asset_b in pool.assets.iter().skip(idx + 1) {
+				pools.push((PoolType::Stableswap(pool_id), *asset_a, *asset_b));
+			}
+		}
+	}
+
+	pools
+}
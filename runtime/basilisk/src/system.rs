@@ -245,7 +245,10 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = TransferFees<Currencies, DepositAll<Runtime>, TreasuryAccount>;
+	type OnChargeTransaction = pallet_fee_currency_selection::FallbackFeeCurrency<
+		Runtime,
+		TransferFees<Currencies, DepositAll<Runtime>, TreasuryAccount>,
+	>;
 	type OperationalFeeMultiplier = ();
 	type WeightToFee = WeightToFee;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
@@ -307,6 +310,22 @@ impl pallet_transaction_multi_payment::Config for Runtime {
 	type SwappablePaymentAssetSupport = assets::XykPaymentAssetSupport;
 }
 
+parameter_types! {
+	pub const MaxFallbackFeeCurrencies: u32 = 10;
+}
+
+impl pallet_fee_currency_selection::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type UpdateOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type Currencies = Currencies;
+	type AccountCurrency = adapter::MultiPaymentAccountCurrency;
+	type PaymentAssetSupport = assets::XykPaymentAssetSupport;
+	type NativePriceOracle = MultiTransactionPayment;
+	type NativeAssetId = NativeAssetId;
+	type MaxFallbackCurrencies = MaxFallbackFeeCurrencies;
+	type WeightInfo = weights::pallet_fee_currency_selection::BasiliskWeight<Runtime>;
+}
+
 /// The type used to represent the kinds of proxying allowed.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub enum ProxyType {
@@ -335,7 +354,16 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					| RuntimeCall::Treasury(..)
 					| RuntimeCall::Utility(..)
 			),
-			ProxyType::Exchange => matches!(c, RuntimeCall::XYK(..) | RuntimeCall::LBP(..) | RuntimeCall::NFT(..)),
+			ProxyType::Exchange => matches!(
+				c,
+				RuntimeCall::XYK(..)
+					| RuntimeCall::LBP(..)
+					| RuntimeCall::NFT(..)
+					| RuntimeCall::OTC(..)
+					| RuntimeCall::Router(..)
+					| RuntimeCall::DCA(..)
+					| RuntimeCall::LimitOrders(..)
+			),
 			// Transfer group doesn't include cross-chain transfers
 			ProxyType::Transfer => matches!(
 				c,
@@ -9,22 +9,28 @@ pub mod pallet_collator_selection;
 pub mod pallet_collective;
 pub mod pallet_conviction_voting;
 pub mod pallet_currencies;
+pub mod pallet_dca;
 pub mod pallet_democracy;
 pub mod pallet_duster;
 pub mod pallet_ema_oracle;
+pub mod pallet_fee_currency_selection;
 pub mod pallet_identity;
 pub mod pallet_lbp;
+pub mod pallet_limit_orders;
 pub mod pallet_marketplace;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_nft;
+pub mod pallet_oracle_whitelist;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
 pub mod pallet_referenda;
 pub mod pallet_route_executor;
 pub mod pallet_scheduler;
+pub mod pallet_stableswap;
 pub mod pallet_state_trie_migration;
 pub mod pallet_timestamp;
+pub mod pallet_trade_stats;
 pub mod pallet_transaction_multi_payment;
 pub mod pallet_transaction_pause;
 pub mod pallet_treasury;
@@ -0,0 +1,107 @@
+// This file is part of Basilisk.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Placeholder weights for `pallet_limit_orders`
+//!
+//! THESE WEIGHTS ARE NOT BENCHMARKED. They are estimated from the storage accesses of the
+//! extrinsics and the weights of comparable benchmarked extrinsics, and have to be regenerated
+//! with the command below on the reference hardware before the runtime is released.
+
+// Command to regenerate:
+// ./target/release/basilisk
+// benchmark
+// pallet
+// --wasm-execution=compiled
+// --pallet
+// pallet-limit-orders
+// --extrinsic
+// *
+// --heap-pages
+// 4096
+// --steps
+// 50
+// --repeat
+// 20
+// --template=scripts/pallet-weight-template.hbs
+// --output
+// runtime/basilisk/src/weights/pallet_limit_orders.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_limit_orders`.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+/// Weights for `pallet_limit_orders` using the Basilisk node and recommended hardware.
+pub struct BasiliskWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_limit_orders::WeightInfo for BasiliskWeight<T> {
+	/// Storage: `LimitOrders::NextOrderId` (r:1 w:1)
+	/// Proof: `LimitOrders::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LimitOrders::Orders` (r:0 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	fn place_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1077`
+		//  Estimated: `4726`
+		// Minimum execution time: 54_318_000 picoseconds.
+		Weight::from_parts(55_024_000, 4726)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `LimitOrders::Orders` (r:1 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1544`
+		//  Estimated: `6156`
+		// Minimum execution time: 78_906_000 picoseconds.
+		Weight::from_parts(79_633_000, 6156)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `LimitOrders::Orders` (r:1 w:1)
+	/// Proof: `LimitOrders::Orders` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Reserves` (r:1 w:1)
+	/// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1301`
+		//  Estimated: `4726`
+		// Minimum execution time: 49_127_000 picoseconds.
+		Weight::from_parts(49_802_000, 4726)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
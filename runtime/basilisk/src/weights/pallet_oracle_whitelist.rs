@@ -0,0 +1,89 @@
+// This file is part of Basilisk.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Placeholder weights for `pallet_oracle_whitelist`
+//!
+//! THESE WEIGHTS ARE NOT BENCHMARKED. They are estimated from the storage accesses of the
+//! extrinsics and the weights of comparable benchmarked extrinsics, and have to be regenerated
+//! with the command below on the reference hardware before the runtime is released.
+
+// Command to regenerate:
+// ./target/release/basilisk
+// benchmark
+// pallet
+// --wasm-execution=compiled
+// --pallet
+// pallet-oracle-whitelist
+// --extrinsic
+// *
+// --heap-pages
+// 4096
+// --steps
+// 50
+// --repeat
+// 20
+// --template=scripts/pallet-weight-template.hbs
+// --output
+// runtime/basilisk/src/weights/pallet_oracle_whitelist.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_oracle_whitelist`.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+/// Weights for `pallet_oracle_whitelist` using the Basilisk node and recommended hardware.
+pub struct BasiliskWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_oracle_whitelist::weights::WeightInfo for BasiliskWeight<T> {
+	/// Storage: `OracleWhitelist::WhitelistedPairs` (r:1 w:1)
+	/// Proof: `OracleWhitelist::WhitelistedPairs` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `OracleWhitelist::CounterForWhitelistedPairs` (r:1 w:1)
+	/// Proof: `OracleWhitelist::CounterForWhitelistedPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn whitelist_pair() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3506`
+		// Minimum execution time: 17_612_000 picoseconds.
+		Weight::from_parts(18_104_000, 3506)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleWhitelist::WhitelistedPairs` (r:1 w:1)
+	/// Proof: `OracleWhitelist::WhitelistedPairs` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `OracleWhitelist::CounterForWhitelistedPairs` (r:1 w:1)
+	/// Proof: `OracleWhitelist::CounterForWhitelistedPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn remove_pair() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `115`
+		//  Estimated: `3506`
+		// Minimum execution time: 19_331_000 picoseconds.
+		Weight::from_parts(19_858_000, 3506)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// NOTE: not generated by the `refresh_priority_pairs` benchmark yet, bounded by the weight of
+	/// `whitelist_pair` which reads and writes more storage items.
+	fn refresh_priority_pairs() -> Weight {
+		Self::whitelist_pair()
+	}
+}
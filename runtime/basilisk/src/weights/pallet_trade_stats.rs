@@ -0,0 +1,68 @@
+// This file is part of Basilisk.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Placeholder weights for `pallet_trade_stats`
+//!
+//! THESE WEIGHTS ARE NOT BENCHMARKED. They are estimated from the storage accesses of the
+//! extrinsics and the weights of comparable benchmarked extrinsics, and have to be regenerated
+//! with the command below on the reference hardware before the runtime is released.
+
+// Command to regenerate:
+// ./target/release/basilisk
+// benchmark
+// pallet
+// --wasm-execution=compiled
+// --pallet
+// pallet-trade-stats
+// --extrinsic
+// *
+// --heap-pages
+// 4096
+// --steps
+// 50
+// --repeat
+// 20
+// --template=scripts/pallet-weight-template.hbs
+// --output
+// runtime/basilisk/src/weights/pallet_trade_stats.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_trade_stats`.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+/// Weights for `pallet_trade_stats` using the Basilisk node and recommended hardware.
+pub struct BasiliskWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_trade_stats::weights::WeightInfo for BasiliskWeight<T> {
+	/// Storage: `TradeStats::PairStats` (r:1 w:1)
+	/// Proof: `TradeStats::PairStats` (`max_values`: None, `max_size`: Some(1773), added: 4248, mode: `MaxEncodedLen`)
+	fn note_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1768`
+		//  Estimated: `5238`
+		Weight::from_parts(33_898_000, 5238)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
@@ -324,6 +324,43 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for Basili
 			.saturating_add(T::DbWeight::get().reads(19_u64))
 			.saturating_add(T::DbWeight::get().writes(15_u64))
 	}
+	/// Storage: `Uniques::Asset` (r:1 w:1)
+	/// Proof: `Uniques::Asset` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::Deposit` (r:1 w:1)
+	/// Proof: `XYKWarehouseLM::Deposit` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::YieldFarm` (r:1 w:1)
+	/// Proof: `XYKWarehouseLM::YieldFarm` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `XYKWarehouseLM::GlobalFarm` (r:1 w:1)
+	/// Proof: `XYKWarehouseLM::GlobalFarm` (`max_values`: None, `max_size`: Some(205), added: 2680, mode: `MaxEncodedLen`)
+	/// Storage: `XYKLiquidityMining::PoolTypes` (r:1 w:0)
+	/// Proof: `XYKLiquidityMining::PoolTypes` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolAssets` (r:1 w:0)
+	/// Proof: `XYK::PoolAssets` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ShareToken` (r:1 w:0)
+	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Class` (r:1 w:1)
+	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `XYKLiquidityMining::AutoCompound` (r:0 w:1)
+	/// Proof: `XYKLiquidityMining::AutoCompound` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `XYKLiquidityMining::RewardBeneficiaries` (r:0 w:1)
+	/// Proof: `XYKLiquidityMining::RewardBeneficiaries` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::Account` (r:0 w:1)
+	/// Proof: `Uniques::Account` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Uniques::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Uniques::ItemPriceOf` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `NFT::Items` (r:0 w:1)
+	/// Proof: `NFT::Items` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	fn emergency_withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2214`
+		//  Estimated: `7156`
+		// Minimum execution time: 131_402_000 picoseconds.
+		Weight::from_parts(132_817_000, 7156)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
 	/// Storage: `XYK::ShareToken` (r:1 w:0)
 	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `XYKWarehouseLM::ActiveYieldFarm` (r:1 w:1)
@@ -345,4 +382,14 @@ impl<T: frame_system::Config> pallet_xyk_liquidity_mining::WeightInfo for Basili
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
-}
\ No newline at end of file
+	/// NOTE: not generated by the `split_deposit` benchmark yet, bounded by the weights of
+	/// entering the deposit into `MaxEntriesPerDeposit` (5) yield farms.
+	fn split_deposit() -> Weight {
+		Self::deposit_shares().saturating_add(Self::redeposit_shares().saturating_mul(4_u64))
+	}
+	/// NOTE: not generated by the `merge_deposits` benchmark yet, bounded by the weights of
+	/// destroying the deposit and updating its `MaxEntriesPerDeposit` (5) yield farm entries.
+	fn merge_deposits() -> Weight {
+		Self::withdraw_shares().saturating_add(Self::redeposit_shares().saturating_mul(4_u64))
+	}
+}
@@ -551,6 +551,7 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 				| pallet_identity::Call::quit_sub { .. },
 			)
 			| RuntimeCall::XYK(..)
+			| RuntimeCall::OTC(..)
 			| RuntimeCall::NFT(..)
 			| RuntimeCall::MultiTransactionPayment(..)
 			| RuntimeCall::XYKLiquidityMining(..)